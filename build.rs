@@ -13,7 +13,11 @@ fn main() {
         println!("cargo:rustc-link-arg=-sEXPORT_NAME=createModule");
         println!("cargo:rustc-link-arg=-sENVIRONMENT=web");
         println!("cargo:rustc-link-arg=-sUSE_GLFW=3");
-        println!("cargo:rustc-link-arg=-sASYNCIFY");
+
+        // NOTE: Only needed by blocking main loops, `run_main_loop()` builds can disable the default `asyncify` feature
+        if env::var("CARGO_FEATURE_ASYNCIFY").is_ok() {
+            println!("cargo:rustc-link-arg=-sASYNCIFY");
+        }
     } else {
         let raylib_lib_dir = "external/raylib/amd64/lib";
 
@@ -0,0 +1,43 @@
+use blob_raylib::{
+    App, AppRunner, Color, Vector2, clear_background, close_window, draw_circle_v, draw_text, init_window,
+    set_target_fps,
+};
+
+struct Bouncer {
+    previous: Vector2,
+    position: Vector2,
+    velocity: Vector2,
+}
+
+impl App for Bouncer {
+    fn fixed_update(&mut self, timestep: f32) {
+        self.previous = self.position;
+        self.position += self.velocity * timestep;
+        if self.position.x < 32.0 || self.position.x > 768.0 {
+            self.velocity.x = -self.velocity.x;
+        }
+        if self.position.y < 32.0 || self.position.y > 418.0 {
+            self.velocity.y = -self.velocity.y;
+        }
+    }
+
+    fn draw(&mut self, alpha: f32) {
+        clear_background(Color::RAY_WHITE);
+        draw_circle_v(self.previous.lerp(self.position, alpha), 32.0, Color::MAROON);
+        draw_text("Simulated at 30 Hz, drawn interpolated", 10, 10, 20, Color::DARK_GRAY);
+    }
+}
+
+fn main() {
+    init_window(800, 450, "raylib [core] example - fixed timestep");
+    set_target_fps(144);
+
+    let mut app = Bouncer {
+        previous: Vector2::new(400.0, 225.0),
+        position: Vector2::new(400.0, 225.0),
+        velocity: Vector2::new(300.0, 200.0),
+    };
+    AppRunner::new().timestep(1.0 / 30.0).run(&mut app);
+
+    close_window();
+}
@@ -1273,7 +1273,6 @@ unsafe extern "C" {
     pub(crate) fn UpdateModelAnimation(model: Model, anim: ModelAnimation, frame: c_int);
     pub(crate) fn UpdateModelAnimationBones(model: Model, anim: ModelAnimation, frame: c_int);
     pub(crate) fn UnloadModelAnimation(anim: ModelAnimation);
-    pub(crate) fn UnloadModelAnimations(animations: *mut ModelAnimation, animCount: c_int);
     pub(crate) fn IsModelAnimationValid(model: Model, anim: ModelAnimation) -> bool;
 
     // Collision detection functions
@@ -1405,4 +1404,248 @@ unsafe extern "C" {
 
     pub(crate) fn AttachAudioMixedProcessor(processor: AudioCallback);
     pub(crate) fn DetachAudioMixedProcessor(processor: AudioCallback);
-}
\ No newline at end of file
+}
+
+// rlgl (OpenGL abstraction layer) functions
+unsafe extern "C" {
+    // Matrix operations
+    pub(crate) fn rlMatrixMode(mode: c_int);
+    pub(crate) fn rlPushMatrix();
+    pub(crate) fn rlPopMatrix();
+    pub(crate) fn rlLoadIdentity();
+    pub(crate) fn rlTranslatef(x: c_float, y: c_float, z: c_float);
+    pub(crate) fn rlRotatef(angle: c_float, x: c_float, y: c_float, z: c_float);
+    pub(crate) fn rlScalef(x: c_float, y: c_float, z: c_float);
+    pub(crate) fn rlMultMatrixf(matf: *const c_float);
+    pub(crate) fn rlFrustum(left: c_double, right: c_double, bottom: c_double, top: c_double, znear: c_double, zfar: c_double);
+    pub(crate) fn rlOrtho(left: c_double, right: c_double, bottom: c_double, top: c_double, znear: c_double, zfar: c_double);
+    pub(crate) fn rlViewport(x: c_int, y: c_int, width: c_int, height: c_int);
+    pub(crate) fn rlSetClipPlanes(nearPlane: c_double, farPlane: c_double);
+    pub(crate) fn rlGetCullDistanceNear() -> c_double;
+    pub(crate) fn rlGetCullDistanceFar() -> c_double;
+
+    // Vertex level operations
+    pub(crate) fn rlBegin(mode: c_int);
+    pub(crate) fn rlEnd();
+    pub(crate) fn rlVertex2i(x: c_int, y: c_int);
+    pub(crate) fn rlVertex2f(x: c_float, y: c_float);
+    pub(crate) fn rlVertex3f(x: c_float, y: c_float, z: c_float);
+    pub(crate) fn rlTexCoord2f(x: c_float, y: c_float);
+    pub(crate) fn rlNormal3f(x: c_float, y: c_float, z: c_float);
+    pub(crate) fn rlColor4ub(r: c_uchar, g: c_uchar, b: c_uchar, a: c_uchar);
+    pub(crate) fn rlColor3f(x: c_float, y: c_float, z: c_float);
+    pub(crate) fn rlColor4f(x: c_float, y: c_float, z: c_float, w: c_float);
+
+    // Vertex buffers state
+    pub(crate) fn rlEnableVertexArray(vaoId: c_uint) -> bool;
+    pub(crate) fn rlDisableVertexArray();
+    pub(crate) fn rlEnableVertexBuffer(id: c_uint);
+    pub(crate) fn rlDisableVertexBuffer();
+    pub(crate) fn rlEnableVertexBufferElement(id: c_uint);
+    pub(crate) fn rlDisableVertexBufferElement();
+    pub(crate) fn rlEnableVertexAttribute(index: c_uint);
+    pub(crate) fn rlDisableVertexAttribute(index: c_uint);
+
+    // Textures state
+    pub(crate) fn rlActiveTextureSlot(slot: c_int);
+    pub(crate) fn rlEnableTexture(id: c_uint);
+    pub(crate) fn rlDisableTexture();
+    pub(crate) fn rlEnableTextureCubemap(id: c_uint);
+    pub(crate) fn rlDisableTextureCubemap();
+
+    // Shader state
+    pub(crate) fn rlEnableShader(id: c_uint);
+    pub(crate) fn rlDisableShader();
+
+    // Framebuffer state
+    pub(crate) fn rlEnableFramebuffer(id: c_uint);
+    pub(crate) fn rlDisableFramebuffer();
+    pub(crate) fn rlGetActiveFramebuffer() -> c_uint;
+    pub(crate) fn rlActiveDrawBuffers(count: c_int);
+    pub(crate) fn rlBlitFramebuffer(
+        srcX: c_int,
+        srcY: c_int,
+        srcWidth: c_int,
+        srcHeight: c_int,
+        dstX: c_int,
+        dstY: c_int,
+        dstWidth: c_int,
+        dstHeight: c_int,
+        bufferMask: c_int,
+    );
+    pub(crate) fn rlBindFramebuffer(target: c_uint, framebuffer: c_uint);
+
+    // General render state
+    pub(crate) fn rlEnableColorBlend();
+    pub(crate) fn rlDisableColorBlend();
+    pub(crate) fn rlEnableDepthTest();
+    pub(crate) fn rlDisableDepthTest();
+    pub(crate) fn rlEnableDepthMask();
+    pub(crate) fn rlDisableDepthMask();
+    pub(crate) fn rlEnableBackfaceCulling();
+    pub(crate) fn rlDisableBackfaceCulling();
+    pub(crate) fn rlColorMask(r: bool, g: bool, b: bool, a: bool);
+    pub(crate) fn rlSetCullFace(mode: c_int);
+    pub(crate) fn rlEnableScissorTest();
+    pub(crate) fn rlDisableScissorTest();
+    pub(crate) fn rlScissor(x: c_int, y: c_int, width: c_int, height: c_int);
+    pub(crate) fn rlEnableWireMode();
+    pub(crate) fn rlEnablePointMode();
+    pub(crate) fn rlDisableWireMode();
+    pub(crate) fn rlSetLineWidth(width: c_float);
+    pub(crate) fn rlGetLineWidth() -> c_float;
+    pub(crate) fn rlEnableSmoothLines();
+    pub(crate) fn rlDisableSmoothLines();
+    pub(crate) fn rlEnableStereoRender();
+    pub(crate) fn rlDisableStereoRender();
+    pub(crate) fn rlIsStereoRenderEnabled() -> bool;
+    pub(crate) fn rlClearColor(r: c_uchar, g: c_uchar, b: c_uchar, a: c_uchar);
+    pub(crate) fn rlClearScreenBuffers();
+    pub(crate) fn rlCheckErrors();
+    pub(crate) fn rlSetBlendMode(mode: c_int);
+    pub(crate) fn rlSetBlendFactors(glSrcFactor: c_int, glDstFactor: c_int, glEquation: c_int);
+    pub(crate) fn rlSetBlendFactorsSeparate(
+        glSrcRGB: c_int,
+        glDstRGB: c_int,
+        glSrcAlpha: c_int,
+        glDstAlpha: c_int,
+        glEqRGB: c_int,
+        glEqAlpha: c_int,
+    );
+
+    // rlgl state
+    pub(crate) fn rlGetVersion() -> c_int;
+    pub(crate) fn rlSetFramebufferWidth(width: c_int);
+    pub(crate) fn rlGetFramebufferWidth() -> c_int;
+    pub(crate) fn rlSetFramebufferHeight(height: c_int);
+    pub(crate) fn rlGetFramebufferHeight() -> c_int;
+    pub(crate) fn rlGetTextureIdDefault() -> c_uint;
+    pub(crate) fn rlGetShaderIdDefault() -> c_uint;
+
+    // Render batch management
+    pub(crate) fn rlDrawRenderBatchActive();
+    pub(crate) fn rlCheckRenderBatchLimit(vCount: c_int) -> bool;
+    pub(crate) fn rlSetTexture(id: c_uint);
+
+    // Vertex buffers management
+    pub(crate) fn rlLoadVertexArray() -> c_uint;
+    pub(crate) fn rlLoadVertexBuffer(buffer: *const c_void, size: c_int, dynamic: bool) -> c_uint;
+    pub(crate) fn rlLoadVertexBufferElement(buffer: *const c_void, size: c_int, dynamic: bool) -> c_uint;
+    pub(crate) fn rlUpdateVertexBuffer(bufferId: c_uint, data: *const c_void, dataSize: c_int, offset: c_int);
+    pub(crate) fn rlUpdateVertexBufferElements(id: c_uint, data: *const c_void, dataSize: c_int, offset: c_int);
+    pub(crate) fn rlUnloadVertexArray(vaoId: c_uint);
+    pub(crate) fn rlUnloadVertexBuffer(vboId: c_uint);
+    pub(crate) fn rlSetVertexAttribute(
+        index: c_uint,
+        compSize: c_int,
+        type_: c_int,
+        normalized: bool,
+        stride: c_int,
+        offset: c_int,
+    );
+    pub(crate) fn rlSetVertexAttributeDivisor(index: c_uint, divisor: c_int);
+    pub(crate) fn rlSetVertexAttributeDefault(locIndex: c_int, value: *const c_void, attribType: c_int, count: c_int);
+    pub(crate) fn rlDrawVertexArray(offset: c_int, count: c_int);
+    pub(crate) fn rlDrawVertexArrayElements(offset: c_int, count: c_int, buffer: *const c_void);
+    pub(crate) fn rlDrawVertexArrayInstanced(offset: c_int, count: c_int, instances: c_int);
+    pub(crate) fn rlDrawVertexArrayElementsInstanced(offset: c_int, count: c_int, buffer: *const c_void, instances: c_int);
+
+    // Textures management
+    pub(crate) fn rlLoadTexture(data: *const c_void, width: c_int, height: c_int, format: c_int, mipmapCount: c_int) -> c_uint;
+    pub(crate) fn rlLoadTextureDepth(width: c_int, height: c_int, useRenderBuffer: bool) -> c_uint;
+    pub(crate) fn rlLoadTextureCubemap(data: *const c_void, size: c_int, format: c_int, mipmapCount: c_int) -> c_uint;
+    pub(crate) fn rlUpdateTexture(
+        id: c_uint,
+        offsetX: c_int,
+        offsetY: c_int,
+        width: c_int,
+        height: c_int,
+        format: c_int,
+        data: *const c_void,
+    );
+    pub(crate) fn rlUnloadTexture(id: c_uint);
+    pub(crate) fn rlGetGlTextureFormats(
+        format: c_int,
+        glInternalFormat: *mut c_uint,
+        glFormat: *mut c_uint,
+        glType: *mut c_uint,
+    );
+
+    // Framebuffer management (fbo)
+    pub(crate) fn rlLoadFramebuffer() -> c_uint;
+    pub(crate) fn rlFramebufferAttach(fboId: c_uint, texId: c_uint, attachType: c_int, texType: c_int, mipLevel: c_int);
+    pub(crate) fn rlFramebufferComplete(id: c_uint) -> bool;
+    pub(crate) fn rlUnloadFramebuffer(id: c_uint);
+
+    // Shaders management
+    pub(crate) fn rlLoadShaderCode(vsCode: *const c_char, fsCode: *const c_char) -> c_uint;
+    pub(crate) fn rlCompileShader(shaderCode: *const c_char, type_: c_int) -> c_uint;
+    pub(crate) fn rlLoadShaderProgram(vShaderId: c_uint, fShaderId: c_uint) -> c_uint;
+    pub(crate) fn rlUnloadShaderProgram(id: c_uint);
+    pub(crate) fn rlGetLocationUniform(shaderId: c_uint, uniformName: *const c_char) -> c_int;
+    pub(crate) fn rlGetLocationAttrib(shaderId: c_uint, attribName: *const c_char) -> c_int;
+    pub(crate) fn rlSetUniform(locIndex: c_int, value: *const c_void, uniformType: c_int, count: c_int);
+    pub(crate) fn rlSetUniformMatrix(locIndex: c_int, mat: Matrix);
+    pub(crate) fn rlSetUniformMatrices(locIndex: c_int, mat: *const Matrix, count: c_int);
+    pub(crate) fn rlSetUniformSampler(locIndex: c_int, textureId: c_uint);
+
+    // Compute shader management
+    pub(crate) fn rlLoadComputeShaderProgram(shaderId: c_uint) -> c_uint;
+    pub(crate) fn rlComputeShaderDispatch(groupX: c_uint, groupY: c_uint, groupZ: c_uint);
+
+    // Shader buffer storage object management (ssbo)
+    pub(crate) fn rlLoadShaderBuffer(size: c_uint, data: *const c_void, usageHint: c_int) -> c_uint;
+    pub(crate) fn rlUnloadShaderBuffer(ssboId: c_uint);
+    pub(crate) fn rlUpdateShaderBuffer(id: c_uint, data: *const c_void, dataSize: c_uint, offset: c_uint);
+    pub(crate) fn rlBindShaderBuffer(id: c_uint, index: c_uint);
+    pub(crate) fn rlReadShaderBuffer(id: c_uint, dest: *mut c_void, count: c_uint, offset: c_uint);
+    pub(crate) fn rlCopyShaderBuffer(destId: c_uint, srcId: c_uint, destOffset: c_uint, srcOffset: c_uint, count: c_uint);
+    pub(crate) fn rlGetShaderBufferSize(id: c_uint) -> c_uint;
+
+    // Buffer management
+    pub(crate) fn rlBindImageTexture(id: c_uint, index: c_uint, format: c_int, readonly: bool);
+
+    // Matrix state management
+    pub(crate) fn rlGetMatrixModelview() -> Matrix;
+    pub(crate) fn rlGetMatrixProjection() -> Matrix;
+    pub(crate) fn rlGetMatrixTransform() -> Matrix;
+    pub(crate) fn rlGetMatrixProjectionStereo(eye: c_int) -> Matrix;
+    pub(crate) fn rlGetMatrixViewOffsetStereo(eye: c_int) -> Matrix;
+    pub(crate) fn rlSetMatrixProjection(proj: Matrix);
+    pub(crate) fn rlSetMatrixModelview(view: Matrix);
+    pub(crate) fn rlSetMatrixProjectionStereo(right: Matrix, left: Matrix);
+    pub(crate) fn rlSetMatrixViewOffsetStereo(right: Matrix, left: Matrix);
+
+    // Quick and dirty cube/quad buffers load->draw->unload
+    pub(crate) fn rlLoadDrawCube();
+    pub(crate) fn rlLoadDrawQuad();
+}
+
+// OpenGL function pointers loaded at runtime by raylib (GLAD), `None` if not supported by the driver.
+// Used for what rlgl does not expose (multisample renderbuffers), desktop only.
+#[cfg(not(target_arch = "wasm32"))]
+unsafe extern "C" {
+    pub(crate) static glad_glGenRenderbuffers: Option<unsafe extern "system" fn(n: c_int, renderbuffers: *mut c_uint)>;
+    pub(crate) static glad_glDeleteRenderbuffers: Option<unsafe extern "system" fn(n: c_int, renderbuffers: *const c_uint)>;
+    pub(crate) static glad_glBindRenderbuffer: Option<unsafe extern "system" fn(target: c_uint, renderbuffer: c_uint)>;
+    pub(crate) static glad_glRenderbufferStorageMultisample: Option<
+        unsafe extern "system" fn(target: c_uint, samples: c_int, internalformat: c_uint, width: c_int, height: c_int),
+    >;
+    pub(crate) static glad_glReadBuffer: Option<unsafe extern "system" fn(src: c_uint)>;
+    pub(crate) static glad_glDrawBuffers: Option<unsafe extern "system" fn(n: c_int, bufs: *const c_uint)>;
+}
+
+/// Emscripten: Main loop callback receiving the registered argument
+#[cfg(target_arch = "wasm32")]
+pub type EmArgCallbackFunc = Option<unsafe extern "C" fn(arg: *mut c_void)>;
+
+// Emscripten runtime, web only
+#[cfg(target_arch = "wasm32")]
+unsafe extern "C" {
+    pub(crate) fn emscripten_set_main_loop_arg(
+        func: EmArgCallbackFunc,
+        arg: *mut c_void,
+        fps: c_int,
+        simulate_infinite_loop: c_int,
+    );
+}
@@ -1,4 +1,7 @@
 mod bindings;
+mod json;
+mod xml;
+pub mod rlgl;
 mod types;
 mod wrappers;
 
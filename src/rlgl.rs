@@ -0,0 +1,1304 @@
+//! Low-level access to rlgl, the OpenGL abstraction layer raylib is built on.
+//!
+//! Functions mirror `rlgl.h` without the `rl` prefix (`rlPushMatrix()` is `rlgl::push_matrix()`).
+//! Everything here requires an initialized window (OpenGL context), and state changes made here
+//! are not tracked by raylib: restore what you change before calling back into the high-level API.
+
+use crate::bindings;
+use crate::{
+    BlendMode, Color, Matrix, PixelFormat, Quaternion, Rectangle, ShaderAttributeDataType, ShaderUniformDataType,
+    Transform, Vector2, Vector3, Vector4, get_pixel_data_size,
+};
+use std::ffi::{CString, c_char, c_float, c_int, c_uint, c_void};
+use std::ptr::null;
+
+/// Matrix modes (equivalent to OpenGL)
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixMode {
+    /// GL_MODELVIEW
+    ModelView = 0x1700,
+    /// GL_PROJECTION
+    Projection = 0x1701,
+    /// GL_TEXTURE
+    Texture = 0x1702,
+}
+
+/// Primitive assembly draw modes
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawMode {
+    /// GL_LINES
+    Lines = 0x0001,
+    /// GL_TRIANGLES
+    Triangles = 0x0004,
+    /// GL_QUADS
+    Quads = 0x0007,
+}
+
+/// GL equivalent data types
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    /// GL_UNSIGNED_BYTE
+    UnsignedByte = 0x1401,
+    /// GL_FLOAT
+    Float = 0x1406,
+}
+
+/// GL buffer usage hint
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferUsage {
+    /// GL_STREAM_DRAW
+    StreamDraw = 0x88E0,
+    /// GL_STREAM_READ
+    StreamRead = 0x88E1,
+    /// GL_STREAM_COPY
+    StreamCopy = 0x88E2,
+    /// GL_STATIC_DRAW
+    StaticDraw = 0x88E4,
+    /// GL_STATIC_READ
+    StaticRead = 0x88E5,
+    /// GL_STATIC_COPY
+    StaticCopy = 0x88E6,
+    /// GL_DYNAMIC_DRAW
+    DynamicDraw = 0x88E8,
+    /// GL_DYNAMIC_READ
+    DynamicRead = 0x88E9,
+    /// GL_DYNAMIC_COPY
+    DynamicCopy = 0x88EA,
+}
+
+/// GL shader type
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderType {
+    /// GL_FRAGMENT_SHADER
+    Fragment = 0x8B30,
+    /// GL_VERTEX_SHADER
+    Vertex = 0x8B31,
+    /// GL_COMPUTE_SHADER
+    Compute = 0x91B9,
+}
+
+/// GL blending factors
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendFactor {
+    /// GL_ZERO
+    Zero = 0,
+    /// GL_ONE
+    One = 1,
+    /// GL_SRC_COLOR
+    SrcColor = 0x0300,
+    /// GL_ONE_MINUS_SRC_COLOR
+    OneMinusSrcColor = 0x0301,
+    /// GL_SRC_ALPHA
+    SrcAlpha = 0x0302,
+    /// GL_ONE_MINUS_SRC_ALPHA
+    OneMinusSrcAlpha = 0x0303,
+    /// GL_DST_ALPHA
+    DstAlpha = 0x0304,
+    /// GL_ONE_MINUS_DST_ALPHA
+    OneMinusDstAlpha = 0x0305,
+    /// GL_DST_COLOR
+    DstColor = 0x0306,
+    /// GL_ONE_MINUS_DST_COLOR
+    OneMinusDstColor = 0x0307,
+    /// GL_SRC_ALPHA_SATURATE
+    SrcAlphaSaturate = 0x0308,
+    /// GL_CONSTANT_COLOR
+    ConstantColor = 0x8001,
+    /// GL_ONE_MINUS_CONSTANT_COLOR
+    OneMinusConstantColor = 0x8002,
+    /// GL_CONSTANT_ALPHA
+    ConstantAlpha = 0x8003,
+    /// GL_ONE_MINUS_CONSTANT_ALPHA
+    OneMinusConstantAlpha = 0x8004,
+}
+
+/// GL blending functions/equations
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendEquation {
+    /// GL_FUNC_ADD
+    Add = 0x8006,
+    /// GL_MIN
+    Min = 0x8007,
+    /// GL_MAX
+    Max = 0x8008,
+    /// GL_FUNC_SUBTRACT
+    Subtract = 0x800A,
+    /// GL_FUNC_REVERSE_SUBTRACT
+    ReverseSubtract = 0x800B,
+}
+
+/// Framebuffer binding target
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FramebufferTarget {
+    /// GL_READ_FRAMEBUFFER
+    Read = 0x8CA8,
+    /// GL_DRAW_FRAMEBUFFER
+    Draw = 0x8CA9,
+}
+
+/// Buffers copied by `blit_framebuffer()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlitMask(i32);
+
+impl BlitMask {
+    /// GL_COLOR_BUFFER_BIT
+    pub const COLOR: BlitMask = BlitMask(0x00004000);
+    /// GL_DEPTH_BUFFER_BIT
+    pub const DEPTH: BlitMask = BlitMask(0x00000100);
+    /// GL_STENCIL_BUFFER_BIT
+    pub const STENCIL: BlitMask = BlitMask(0x00000400);
+}
+
+impl std::ops::BitOr for BlitMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        return Self(self.0 | rhs.0);
+    }
+}
+
+/// OpenGL version
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlVersion {
+    /// OpenGL 1.1
+    OpenGl11 = 1,
+    /// OpenGL 2.1 (GLSL 120)
+    OpenGl21,
+    /// OpenGL 3.3 (GLSL 330)
+    OpenGl33,
+    /// OpenGL 4.3 (using GLSL 330)
+    OpenGl43,
+    /// OpenGL ES 2.0 (GLSL 100)
+    OpenGlEs20,
+    /// OpenGL ES 3.0 (GLSL 300 es)
+    OpenGlEs30,
+}
+
+/// Framebuffer attachment type
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FramebufferAttachType {
+    ColorChannel0 = 0,
+    ColorChannel1 = 1,
+    ColorChannel2 = 2,
+    ColorChannel3 = 3,
+    ColorChannel4 = 4,
+    ColorChannel5 = 5,
+    ColorChannel6 = 6,
+    ColorChannel7 = 7,
+    Depth = 100,
+    Stencil = 200,
+}
+
+/// Framebuffer texture attachment type
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FramebufferAttachTextureType {
+    CubemapPositiveX = 0,
+    CubemapNegativeX = 1,
+    CubemapPositiveY = 2,
+    CubemapNegativeY = 3,
+    CubemapPositiveZ = 4,
+    CubemapNegativeZ = 5,
+    Texture2D = 100,
+    Renderbuffer = 200,
+}
+
+/// Face culling mode
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CullMode {
+    Front = 0,
+    Back,
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Plain data copied to and from GPU buffers byte for byte: numbers, math types, colors and arrays of them.
+/// Sealed, implemented only for types without padding where every bit pattern is a valid value.
+pub trait Pod: sealed::Sealed + Copy {}
+
+impl sealed::Sealed for u8 {}
+impl Pod for u8 {}
+impl sealed::Sealed for i8 {}
+impl Pod for i8 {}
+impl sealed::Sealed for u16 {}
+impl Pod for u16 {}
+impl sealed::Sealed for i16 {}
+impl Pod for i16 {}
+impl sealed::Sealed for u32 {}
+impl Pod for u32 {}
+impl sealed::Sealed for i32 {}
+impl Pod for i32 {}
+impl sealed::Sealed for u64 {}
+impl Pod for u64 {}
+impl sealed::Sealed for i64 {}
+impl Pod for i64 {}
+impl sealed::Sealed for f32 {}
+impl Pod for f32 {}
+impl sealed::Sealed for f64 {}
+impl Pod for f64 {}
+impl sealed::Sealed for Vector2 {}
+impl Pod for Vector2 {}
+impl sealed::Sealed for Vector3 {}
+impl Pod for Vector3 {}
+impl sealed::Sealed for Vector4 {}
+impl Pod for Vector4 {}
+impl sealed::Sealed for Quaternion {}
+impl Pod for Quaternion {}
+impl sealed::Sealed for Matrix {}
+impl Pod for Matrix {}
+impl sealed::Sealed for Rectangle {}
+impl Pod for Rectangle {}
+impl sealed::Sealed for Transform {}
+impl Pod for Transform {}
+impl sealed::Sealed for Color {}
+impl Pod for Color {}
+impl<T: Pod, const N: usize> sealed::Sealed for [T; N] {}
+impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+// ---------------------------------------------------------------------------------
+// Matrix operations
+// ---------------------------------------------------------------------------------
+
+/// Choose the current matrix to be transformed
+pub fn matrix_mode(mode: MatrixMode) {
+    unsafe { bindings::rlMatrixMode(mode as c_int) }
+}
+
+/// Push the current matrix to stack
+pub fn push_matrix() {
+    unsafe { bindings::rlPushMatrix() }
+}
+
+/// Pop latest inserted matrix from stack
+pub fn pop_matrix() {
+    unsafe { bindings::rlPopMatrix() }
+}
+
+/// Reset current matrix to identity matrix
+pub fn load_identity() {
+    unsafe { bindings::rlLoadIdentity() }
+}
+
+/// Multiply the current matrix by a translation matrix
+pub fn translate(x: f32, y: f32, z: f32) {
+    unsafe { bindings::rlTranslatef(x, y, z) }
+}
+
+/// Multiply the current matrix by a rotation matrix, `angle` in degrees
+pub fn rotate(angle: f32, x: f32, y: f32, z: f32) {
+    unsafe { bindings::rlRotatef(angle, x, y, z) }
+}
+
+/// Multiply the current matrix by a scaling matrix
+pub fn scale(x: f32, y: f32, z: f32) {
+    unsafe { bindings::rlScalef(x, y, z) }
+}
+
+/// Multiply the current matrix by another matrix
+pub fn mult_matrix(matrix: Matrix) {
+    let m = matrix;
+    let values: [c_float; 16] = [
+        m.m0, m.m1, m.m2, m.m3, m.m4, m.m5, m.m6, m.m7, m.m8, m.m9, m.m10, m.m11, m.m12, m.m13, m.m14, m.m15,
+    ];
+    unsafe { bindings::rlMultMatrixf(values.as_ptr()) }
+}
+
+/// Multiply the current matrix by a perspective matrix
+pub fn frustum(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) {
+    unsafe { bindings::rlFrustum(left, right, bottom, top, near, far) }
+}
+
+/// Multiply the current matrix by an orthographic matrix
+pub fn ortho(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) {
+    unsafe { bindings::rlOrtho(left, right, bottom, top, near, far) }
+}
+
+/// Set the viewport area
+pub fn viewport(x: i32, y: i32, width: i32, height: i32) {
+    unsafe { bindings::rlViewport(x as c_int, y as c_int, width as c_int, height as c_int) }
+}
+
+/// Set clip planes distances (used by `begin_mode_3d()`)
+pub fn set_clip_planes(near: f64, far: f64) {
+    unsafe { bindings::rlSetClipPlanes(near, far) }
+}
+
+/// Get cull plane distance near
+pub fn get_cull_distance_near() -> f64 {
+    return unsafe { bindings::rlGetCullDistanceNear() };
+}
+
+/// Get cull plane distance far
+pub fn get_cull_distance_far() -> f64 {
+    return unsafe { bindings::rlGetCullDistanceFar() };
+}
+
+// ---------------------------------------------------------------------------------
+// Vertex level operations
+// ---------------------------------------------------------------------------------
+
+/// Initialize drawing mode (how to organize vertex)
+pub fn begin(mode: DrawMode) {
+    unsafe { bindings::rlBegin(mode as c_int) }
+}
+
+/// Finish vertex providing
+pub fn end() {
+    unsafe { bindings::rlEnd() }
+}
+
+/// Define one vertex (position) - 2 int
+pub fn vertex2i(x: i32, y: i32) {
+    unsafe { bindings::rlVertex2i(x as c_int, y as c_int) }
+}
+
+/// Define one vertex (position) - 2 float
+pub fn vertex2f(x: f32, y: f32) {
+    unsafe { bindings::rlVertex2f(x, y) }
+}
+
+/// Define one vertex (position) - 3 float
+pub fn vertex3f(x: f32, y: f32, z: f32) {
+    unsafe { bindings::rlVertex3f(x, y, z) }
+}
+
+/// Define one vertex (texture coordinate) - 2 float
+pub fn tex_coord2f(x: f32, y: f32) {
+    unsafe { bindings::rlTexCoord2f(x, y) }
+}
+
+/// Define one vertex (normal) - 3 float
+pub fn normal3f(x: f32, y: f32, z: f32) {
+    unsafe { bindings::rlNormal3f(x, y, z) }
+}
+
+/// Define one vertex (color) - 4 byte
+pub fn color4ub(r: u8, g: u8, b: u8, a: u8) {
+    unsafe { bindings::rlColor4ub(r, g, b, a) }
+}
+
+/// Define one vertex (color) - 3 float
+pub fn color3f(r: f32, g: f32, b: f32) {
+    unsafe { bindings::rlColor3f(r, g, b) }
+}
+
+/// Define one vertex (color) - 4 float
+pub fn color4f(r: f32, g: f32, b: f32, a: f32) {
+    unsafe { bindings::rlColor4f(r, g, b, a) }
+}
+
+/// Define one vertex (color) from a `Color`
+pub fn color(color: Color) {
+    color4ub(color.r, color.g, color.b, color.a);
+}
+
+// ---------------------------------------------------------------------------------
+// Vertex buffers state
+// ---------------------------------------------------------------------------------
+
+/// Enable vertex array (VAO, if supported), returns false if VAOs are not supported
+pub fn enable_vertex_array(vao_id: u32) -> bool {
+    return unsafe { bindings::rlEnableVertexArray(vao_id as c_uint) };
+}
+
+/// Disable vertex array (VAO, if supported)
+pub fn disable_vertex_array() {
+    unsafe { bindings::rlDisableVertexArray() }
+}
+
+/// Enable vertex buffer (VBO)
+pub fn enable_vertex_buffer(id: u32) {
+    unsafe { bindings::rlEnableVertexBuffer(id as c_uint) }
+}
+
+/// Disable vertex buffer (VBO)
+pub fn disable_vertex_buffer() {
+    unsafe { bindings::rlDisableVertexBuffer() }
+}
+
+/// Enable vertex buffer element (VBO element)
+pub fn enable_vertex_buffer_element(id: u32) {
+    unsafe { bindings::rlEnableVertexBufferElement(id as c_uint) }
+}
+
+/// Disable vertex buffer element (VBO element)
+pub fn disable_vertex_buffer_element() {
+    unsafe { bindings::rlDisableVertexBufferElement() }
+}
+
+/// Enable vertex attribute index
+pub fn enable_vertex_attribute(index: u32) {
+    unsafe { bindings::rlEnableVertexAttribute(index as c_uint) }
+}
+
+/// Disable vertex attribute index
+pub fn disable_vertex_attribute(index: u32) {
+    unsafe { bindings::rlDisableVertexAttribute(index as c_uint) }
+}
+
+// ---------------------------------------------------------------------------------
+// Textures and shader state
+// ---------------------------------------------------------------------------------
+
+/// Select and active a texture slot
+pub fn active_texture_slot(slot: i32) {
+    unsafe { bindings::rlActiveTextureSlot(slot as c_int) }
+}
+
+/// Enable texture
+pub fn enable_texture(id: u32) {
+    unsafe { bindings::rlEnableTexture(id as c_uint) }
+}
+
+/// Disable texture
+pub fn disable_texture() {
+    unsafe { bindings::rlDisableTexture() }
+}
+
+/// Enable texture cubemap
+pub fn enable_texture_cubemap(id: u32) {
+    unsafe { bindings::rlEnableTextureCubemap(id as c_uint) }
+}
+
+/// Disable texture cubemap
+pub fn disable_texture_cubemap() {
+    unsafe { bindings::rlDisableTextureCubemap() }
+}
+
+/// Enable shader program
+pub fn enable_shader(id: u32) {
+    unsafe { bindings::rlEnableShader(id as c_uint) }
+}
+
+/// Disable shader program
+pub fn disable_shader() {
+    unsafe { bindings::rlDisableShader() }
+}
+
+// ---------------------------------------------------------------------------------
+// Framebuffer state
+// ---------------------------------------------------------------------------------
+
+/// Enable render texture (fbo)
+pub fn enable_framebuffer(id: u32) {
+    unsafe { bindings::rlEnableFramebuffer(id as c_uint) }
+}
+
+/// Disable render texture (fbo), return to default framebuffer
+pub fn disable_framebuffer() {
+    unsafe { bindings::rlDisableFramebuffer() }
+}
+
+/// Get the currently active render texture (fbo), 0 for default framebuffer
+pub fn get_active_framebuffer() -> u32 {
+    return unsafe { bindings::rlGetActiveFramebuffer() };
+}
+
+/// Activate multiple draw color buffers (up to 8)
+pub fn active_draw_buffers(count: i32) {
+    unsafe { bindings::rlActiveDrawBuffers(count as c_int) }
+}
+
+/// Blit active framebuffer to main framebuffer, areas given as (x, y, width, height)
+pub fn blit_framebuffer(src: (i32, i32, i32, i32), dst: (i32, i32, i32, i32), mask: BlitMask) {
+    unsafe { bindings::rlBlitFramebuffer(src.0, src.1, src.2, src.3, dst.0, dst.1, dst.2, dst.3, mask.0) }
+}
+
+/// Bind framebuffer (FBO) to a target
+pub fn bind_framebuffer(target: FramebufferTarget, framebuffer: u32) {
+    unsafe { bindings::rlBindFramebuffer(target as c_uint, framebuffer as c_uint) }
+}
+
+// ---------------------------------------------------------------------------------
+// General render state
+// ---------------------------------------------------------------------------------
+
+/// Enable color blending
+pub fn enable_color_blend() {
+    unsafe { bindings::rlEnableColorBlend() }
+}
+
+/// Disable color blending
+pub fn disable_color_blend() {
+    unsafe { bindings::rlDisableColorBlend() }
+}
+
+/// Enable depth test
+pub fn enable_depth_test() {
+    unsafe { bindings::rlEnableDepthTest() }
+}
+
+/// Disable depth test
+pub fn disable_depth_test() {
+    unsafe { bindings::rlDisableDepthTest() }
+}
+
+/// Enable depth write
+pub fn enable_depth_mask() {
+    unsafe { bindings::rlEnableDepthMask() }
+}
+
+/// Disable depth write
+pub fn disable_depth_mask() {
+    unsafe { bindings::rlDisableDepthMask() }
+}
+
+/// Enable backface culling
+pub fn enable_backface_culling() {
+    unsafe { bindings::rlEnableBackfaceCulling() }
+}
+
+/// Disable backface culling
+pub fn disable_backface_culling() {
+    unsafe { bindings::rlDisableBackfaceCulling() }
+}
+
+/// Color mask control
+pub fn color_mask(r: bool, g: bool, b: bool, a: bool) {
+    unsafe { bindings::rlColorMask(r, g, b, a) }
+}
+
+/// Set face culling mode
+pub fn set_cull_face(mode: CullMode) {
+    unsafe { bindings::rlSetCullFace(mode as c_int) }
+}
+
+/// Enable scissor test
+pub fn enable_scissor_test() {
+    unsafe { bindings::rlEnableScissorTest() }
+}
+
+/// Disable scissor test
+pub fn disable_scissor_test() {
+    unsafe { bindings::rlDisableScissorTest() }
+}
+
+/// Scissor test
+pub fn scissor(x: i32, y: i32, width: i32, height: i32) {
+    unsafe { bindings::rlScissor(x as c_int, y as c_int, width as c_int, height as c_int) }
+}
+
+/// Enable wire mode
+pub fn enable_wire_mode() {
+    unsafe { bindings::rlEnableWireMode() }
+}
+
+/// Enable point mode
+pub fn enable_point_mode() {
+    unsafe { bindings::rlEnablePointMode() }
+}
+
+/// Disable wire (and point) mode
+pub fn disable_wire_mode() {
+    unsafe { bindings::rlDisableWireMode() }
+}
+
+/// Set the line drawing width
+pub fn set_line_width(width: f32) {
+    unsafe { bindings::rlSetLineWidth(width) }
+}
+
+/// Get the line drawing width
+pub fn get_line_width() -> f32 {
+    return unsafe { bindings::rlGetLineWidth() };
+}
+
+/// Enable line aliasing
+pub fn enable_smooth_lines() {
+    unsafe { bindings::rlEnableSmoothLines() }
+}
+
+/// Disable line aliasing
+pub fn disable_smooth_lines() {
+    unsafe { bindings::rlDisableSmoothLines() }
+}
+
+/// Enable stereo rendering
+pub fn enable_stereo_render() {
+    unsafe { bindings::rlEnableStereoRender() }
+}
+
+/// Disable stereo rendering
+pub fn disable_stereo_render() {
+    unsafe { bindings::rlDisableStereoRender() }
+}
+
+/// Check if stereo render is enabled
+pub fn is_stereo_render_enabled() -> bool {
+    return unsafe { bindings::rlIsStereoRenderEnabled() };
+}
+
+/// Clear color buffer with color
+pub fn clear_color(color: Color) {
+    unsafe { bindings::rlClearColor(color.r, color.g, color.b, color.a) }
+}
+
+/// Clear used screen buffers (color and depth)
+pub fn clear_screen_buffers() {
+    unsafe { bindings::rlClearScreenBuffers() }
+}
+
+/// Check and log OpenGL error codes
+pub fn check_errors() {
+    unsafe { bindings::rlCheckErrors() }
+}
+
+/// Set blending mode
+pub fn set_blend_mode(mode: BlendMode) {
+    unsafe { bindings::rlSetBlendMode(mode as c_int) }
+}
+
+/// Set blending mode factor and equation, used by `BlendMode::Custom`
+pub fn set_blend_factors(src: BlendFactor, dst: BlendFactor, equation: BlendEquation) {
+    unsafe { bindings::rlSetBlendFactors(src as c_int, dst as c_int, equation as c_int) }
+}
+
+/// Set blending mode factors and equations separately for rgb and alpha, used by `BlendMode::CustomSeparate`
+pub fn set_blend_factors_separate(
+    src_rgb: BlendFactor,
+    dst_rgb: BlendFactor,
+    src_alpha: BlendFactor,
+    dst_alpha: BlendFactor,
+    equation_rgb: BlendEquation,
+    equation_alpha: BlendEquation,
+) {
+    unsafe {
+        bindings::rlSetBlendFactorsSeparate(
+            src_rgb as c_int,
+            dst_rgb as c_int,
+            src_alpha as c_int,
+            dst_alpha as c_int,
+            equation_rgb as c_int,
+            equation_alpha as c_int,
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------------
+// rlgl state and render batch
+// ---------------------------------------------------------------------------------
+
+/// Get current OpenGL version, `None` if rlgl is not initialized
+pub fn get_version() -> Option<GlVersion> {
+    match unsafe { bindings::rlGetVersion() } {
+        1 => return Some(GlVersion::OpenGl11),
+        2 => return Some(GlVersion::OpenGl21),
+        3 => return Some(GlVersion::OpenGl33),
+        4 => return Some(GlVersion::OpenGl43),
+        5 => return Some(GlVersion::OpenGlEs20),
+        6 => return Some(GlVersion::OpenGlEs30),
+        _ => return None,
+    }
+}
+
+/// Set current framebuffer width
+pub fn set_framebuffer_width(width: i32) {
+    unsafe { bindings::rlSetFramebufferWidth(width as c_int) }
+}
+
+/// Get default framebuffer width
+pub fn get_framebuffer_width() -> i32 {
+    return unsafe { bindings::rlGetFramebufferWidth() };
+}
+
+/// Set current framebuffer height
+pub fn set_framebuffer_height(height: i32) {
+    unsafe { bindings::rlSetFramebufferHeight(height as c_int) }
+}
+
+/// Get default framebuffer height
+pub fn get_framebuffer_height() -> i32 {
+    return unsafe { bindings::rlGetFramebufferHeight() };
+}
+
+/// Get default texture id (1x1 white texture)
+pub fn get_texture_id_default() -> u32 {
+    return unsafe { bindings::rlGetTextureIdDefault() };
+}
+
+/// Get default shader id
+pub fn get_shader_id_default() -> u32 {
+    return unsafe { bindings::rlGetShaderIdDefault() };
+}
+
+/// Update and draw internal render batch, required before changing GL state outside of the batch
+pub fn draw_render_batch_active() {
+    unsafe { bindings::rlDrawRenderBatchActive() }
+}
+
+/// Check internal buffer overflow for a given number of vertex, flushes the batch if required
+pub fn check_render_batch_limit(vertex_count: i32) -> bool {
+    return unsafe { bindings::rlCheckRenderBatchLimit(vertex_count as c_int) };
+}
+
+/// Set current texture for render batch and check buffers limits
+pub fn set_texture(id: u32) {
+    unsafe { bindings::rlSetTexture(id as c_uint) }
+}
+
+// ---------------------------------------------------------------------------------
+// Vertex buffers management
+// ---------------------------------------------------------------------------------
+
+/// Load vertex array (vao) if supported
+pub fn load_vertex_array() -> u32 {
+    return unsafe { bindings::rlLoadVertexArray() };
+}
+
+/// Load a vertex buffer object
+pub fn load_vertex_buffer<T: Pod>(data: &[T], dynamic: bool) -> u32 {
+    return unsafe { bindings::rlLoadVertexBuffer(data.as_ptr() as *const c_void, size_of_val(data) as c_int, dynamic) };
+}
+
+/// Load a vertex buffer object of `size` bytes with uninitialized contents
+pub fn load_vertex_buffer_empty(size: usize, dynamic: bool) -> u32 {
+    return unsafe { bindings::rlLoadVertexBuffer(null(), size as c_int, dynamic) };
+}
+
+/// Load vertex buffer elements object
+pub fn load_vertex_buffer_element<T: Pod>(data: &[T], dynamic: bool) -> u32 {
+    return unsafe {
+        bindings::rlLoadVertexBufferElement(data.as_ptr() as *const c_void, size_of_val(data) as c_int, dynamic)
+    };
+}
+
+/// Update vertex buffer object data on GPU buffer, `offset` in bytes
+pub fn update_vertex_buffer<T: Pod>(id: u32, data: &[T], offset: usize) {
+    unsafe {
+        bindings::rlUpdateVertexBuffer(
+            id as c_uint,
+            data.as_ptr() as *const c_void,
+            size_of_val(data) as c_int,
+            offset as c_int,
+        )
+    }
+}
+
+/// Update vertex buffer elements data on GPU buffer, `offset` in bytes
+pub fn update_vertex_buffer_elements<T: Pod>(id: u32, data: &[T], offset: usize) {
+    unsafe {
+        bindings::rlUpdateVertexBufferElements(
+            id as c_uint,
+            data.as_ptr() as *const c_void,
+            size_of_val(data) as c_int,
+            offset as c_int,
+        )
+    }
+}
+
+/// Unload vertex array (vao)
+pub fn unload_vertex_array(vao_id: u32) {
+    unsafe { bindings::rlUnloadVertexArray(vao_id as c_uint) }
+}
+
+/// Unload vertex buffer object
+pub fn unload_vertex_buffer(vbo_id: u32) {
+    unsafe { bindings::rlUnloadVertexBuffer(vbo_id as c_uint) }
+}
+
+/// Set vertex attribute data configuration of the enabled vertex buffer, `stride` and `offset` in bytes
+pub fn set_vertex_attribute(
+    index: u32,
+    components: i32,
+    data_type: DataType,
+    normalized: bool,
+    stride: i32,
+    offset: i32,
+) {
+    unsafe {
+        bindings::rlSetVertexAttribute(
+            index as c_uint,
+            components as c_int,
+            data_type as c_int,
+            normalized,
+            stride as c_int,
+            offset as c_int,
+        )
+    }
+}
+
+/// Set vertex attribute data divisor (0 per vertex, 1 per instance)
+pub fn set_vertex_attribute_divisor(index: u32, divisor: i32) {
+    unsafe { bindings::rlSetVertexAttributeDivisor(index as c_uint, divisor as c_int) }
+}
+
+/// Set vertex attribute value used while the attribute array is disabled
+pub fn set_vertex_attribute_default(index: u32, value: &[f32], attribute_type: ShaderAttributeDataType) {
+    assert!(value.len() > attribute_type as usize, "not enough components for the attribute type");
+    unsafe {
+        bindings::rlSetVertexAttributeDefault(
+            index as c_int,
+            value.as_ptr() as *const c_void,
+            attribute_type as c_int,
+            1,
+        )
+    }
+}
+
+/// Draw vertex array (currently active vao)
+pub fn draw_vertex_array(offset: i32, count: i32) {
+    unsafe { bindings::rlDrawVertexArray(offset as c_int, count as c_int) }
+}
+
+/// Draw vertex array elements (16-bit indices of the enabled element buffer)
+pub fn draw_vertex_array_elements(offset: i32, count: i32) {
+    unsafe { bindings::rlDrawVertexArrayElements(offset as c_int, count as c_int, null()) }
+}
+
+/// Draw vertex array (currently active vao) with instancing
+pub fn draw_vertex_array_instanced(offset: i32, count: i32, instances: i32) {
+    unsafe { bindings::rlDrawVertexArrayInstanced(offset as c_int, count as c_int, instances as c_int) }
+}
+
+/// Draw vertex array elements (16-bit indices of the enabled element buffer) with instancing
+pub fn draw_vertex_array_elements_instanced(offset: i32, count: i32, instances: i32) {
+    unsafe {
+        bindings::rlDrawVertexArrayElementsInstanced(offset as c_int, count as c_int, null(), instances as c_int)
+    }
+}
+
+// ---------------------------------------------------------------------------------
+// Textures management
+// ---------------------------------------------------------------------------------
+
+/// Load texture data, returns the texture id (0 on failure). Without data the texture is left uninitialized.
+/// `data` holds every mipmap level one after the other, panics if it is too short.
+pub fn load_texture(data: Option<&[u8]>, width: i32, height: i32, format: PixelFormat, mipmap_count: i32) -> u32 {
+    if let Some(data) = data {
+        let size = mipmaps_data_size(width, height, format, mipmap_count);
+        assert!(data.len() >= size, "texture data is {} bytes, {} expected", data.len(), size);
+    }
+    let data = data.map_or(null(), |data| data.as_ptr() as *const c_void);
+    return unsafe { bindings::rlLoadTexture(data, width, height, format as c_int, mipmap_count) };
+}
+
+/// Load depth texture/renderbuffer (to be attached to fbo)
+pub fn load_texture_depth(width: i32, height: i32, use_render_buffer: bool) -> u32 {
+    return unsafe { bindings::rlLoadTextureDepth(width as c_int, height as c_int, use_render_buffer) };
+}
+
+/// Load texture cubemap data (6 faces of `size` x `size`), without data the faces are left uninitialized.
+/// `data` holds the 6 faces of every mipmap level one level after the other, panics if it is too short.
+pub fn load_texture_cubemap(data: Option<&[u8]>, size: i32, format: PixelFormat, mipmap_count: i32) -> u32 {
+    if let Some(data) = data {
+        let expected = 6 * mipmaps_data_size(size, size, format, mipmap_count);
+        assert!(data.len() >= expected, "cubemap data is {} bytes, {} expected", data.len(), expected);
+    }
+    let data = data.map_or(null(), |data| data.as_ptr() as *const c_void);
+    return unsafe { bindings::rlLoadTextureCubemap(data, size, format as c_int, mipmap_count) };
+}
+
+/// Update texture with new data on GPU, panics if `data` is too short for the updated area
+pub fn update_texture(id: u32, x: i32, y: i32, width: i32, height: i32, format: PixelFormat, data: &[u8]) {
+    let size = get_pixel_data_size(width, height, format).max(0) as usize;
+    assert!(data.len() >= size, "texture data is {} bytes, {} expected", data.len(), size);
+    unsafe { bindings::rlUpdateTexture(id, x, y, width, height, format as c_int, data.as_ptr() as *const c_void) }
+}
+
+/// Unload texture from GPU memory
+pub fn unload_texture(id: u32) {
+    unsafe { bindings::rlUnloadTexture(id as c_uint) }
+}
+
+/// Size in bytes of a texture and its mipmap levels (each level halves the previous one, down to 1 pixel)
+fn mipmaps_data_size(width: i32, height: i32, format: PixelFormat, mipmap_count: i32) -> usize {
+    let (mut width, mut height, mut size) = (width, height, 0);
+    for _ in 0..mipmap_count {
+        size += get_pixel_data_size(width, height, format).max(0) as usize;
+        width = (width / 2).max(1);
+        height = (height / 2).max(1);
+    }
+    return size;
+}
+
+/// Get OpenGL internal format, format and type of a pixel format (zeros if not supported)
+pub fn get_gl_texture_formats(format: PixelFormat) -> (u32, u32, u32) {
+    let (mut internal_format, mut gl_format, mut gl_type) = (0, 0, 0);
+    unsafe { bindings::rlGetGlTextureFormats(format as c_int, &mut internal_format, &mut gl_format, &mut gl_type) };
+    return (internal_format, gl_format, gl_type);
+}
+
+// ---------------------------------------------------------------------------------
+// Framebuffer management (fbo)
+// ---------------------------------------------------------------------------------
+
+/// Load an empty framebuffer
+pub fn load_framebuffer() -> u32 {
+    return unsafe { bindings::rlLoadFramebuffer() };
+}
+
+/// Attach texture/renderbuffer to a framebuffer
+pub fn framebuffer_attach(
+    fbo_id: u32,
+    texture_id: u32,
+    attach_type: FramebufferAttachType,
+    texture_type: FramebufferAttachTextureType,
+    mip_level: i32,
+) {
+    unsafe {
+        bindings::rlFramebufferAttach(
+            fbo_id as c_uint,
+            texture_id as c_uint,
+            attach_type as c_int,
+            texture_type as c_int,
+            mip_level as c_int,
+        )
+    }
+}
+
+/// Verify framebuffer is complete
+pub fn framebuffer_complete(id: u32) -> bool {
+    return unsafe { bindings::rlFramebufferComplete(id as c_uint) };
+}
+
+/// Delete framebuffer from GPU (attached depth textures/renderbuffers are unloaded too)
+pub fn unload_framebuffer(id: u32) {
+    unsafe { bindings::rlUnloadFramebuffer(id as c_uint) }
+}
+
+// ---------------------------------------------------------------------------------
+// Shaders management
+// ---------------------------------------------------------------------------------
+
+/// Load shader from code strings, `None` uses the default vertex/fragment shader
+pub fn load_shader_code(vs_code: Option<&str>, fs_code: Option<&str>) -> u32 {
+    let c_vs = vs_code.map(|code| CString::new(code).unwrap());
+    let c_fs = fs_code.map(|code| CString::new(code).unwrap());
+    let ptr = |code: &Option<CString>| code.as_ref().map_or(null(), |code| code.as_ptr()) as *const c_char;
+    return unsafe { bindings::rlLoadShaderCode(ptr(&c_vs), ptr(&c_fs)) };
+}
+
+/// Compile custom shader and return shader id (0 on failure)
+pub fn compile_shader(code: &str, shader_type: ShaderType) -> u32 {
+    let c_code = CString::new(code).unwrap();
+    return unsafe { bindings::rlCompileShader(c_code.as_ptr(), shader_type as c_int) };
+}
+
+/// Load custom shader program
+pub fn load_shader_program(vertex_shader_id: u32, fragment_shader_id: u32) -> u32 {
+    return unsafe { bindings::rlLoadShaderProgram(vertex_shader_id as c_uint, fragment_shader_id as c_uint) };
+}
+
+/// Unload shader program
+pub fn unload_shader_program(id: u32) {
+    unsafe { bindings::rlUnloadShaderProgram(id as c_uint) }
+}
+
+/// Get shader location uniform, -1 if not found
+pub fn get_location_uniform(shader_id: u32, uniform_name: &str) -> i32 {
+    let c_name = CString::new(uniform_name).unwrap();
+    return unsafe { bindings::rlGetLocationUniform(shader_id as c_uint, c_name.as_ptr()) };
+}
+
+/// Get shader location attribute, -1 if not found
+pub fn get_location_attrib(shader_id: u32, attrib_name: &str) -> i32 {
+    let c_name = CString::new(attrib_name).unwrap();
+    return unsafe { bindings::rlGetLocationAttrib(shader_id as c_uint, c_name.as_ptr()) };
+}
+
+/// Set shader value uniform on the enabled shader. `value` holds whole elements of `uniform_type`
+/// (e.g. `&[Vector3]` or `&[f32]` with 3 values per element for `Vec3`), panics otherwise.
+pub fn set_uniform<T: Pod>(location: i32, value: &[T], uniform_type: ShaderUniformDataType) {
+    let count = uniform_count(size_of_val(value), uniform_type);
+    unsafe { bindings::rlSetUniform(location as c_int, value.as_ptr() as *const c_void, uniform_type as c_int, count) }
+}
+
+/// Number of `uniform_type` elements in `size` bytes
+fn uniform_count(size: usize, uniform_type: ShaderUniformDataType) -> c_int {
+    let element_size = match uniform_type {
+        ShaderUniformDataType::Float
+        | ShaderUniformDataType::Int
+        | ShaderUniformDataType::Uint
+        | ShaderUniformDataType::Sampler2d => 4,
+        ShaderUniformDataType::Vec2 | ShaderUniformDataType::Ivec2 | ShaderUniformDataType::Uivec2 => 8,
+        ShaderUniformDataType::Vec3 | ShaderUniformDataType::Ivec3 | ShaderUniformDataType::Uivec3 => 12,
+        ShaderUniformDataType::Vec4 | ShaderUniformDataType::Ivec4 | ShaderUniformDataType::Uivec4 => 16,
+    };
+    assert!(
+        size > 0 && size.is_multiple_of(element_size),
+        "uniform data is {} bytes, not a whole number of {:?} elements",
+        size,
+        uniform_type
+    );
+    return (size / element_size) as c_int;
+}
+
+/// Set shader value matrix on the enabled shader
+pub fn set_uniform_matrix(location: i32, matrix: Matrix) {
+    unsafe { bindings::rlSetUniformMatrix(location as c_int, matrix) }
+}
+
+/// Set shader value matrices on the enabled shader
+pub fn set_uniform_matrices(location: i32, matrices: &[Matrix]) {
+    unsafe { bindings::rlSetUniformMatrices(location as c_int, matrices.as_ptr(), matrices.len() as c_int) }
+}
+
+/// Set shader value sampler on the enabled shader
+pub fn set_uniform_sampler(location: i32, texture_id: u32) {
+    unsafe { bindings::rlSetUniformSampler(location as c_int, texture_id as c_uint) }
+}
+
+// ---------------------------------------------------------------------------------
+// Compute shaders and shader storage buffers (ssbo, OpenGL 4.3 only)
+// ---------------------------------------------------------------------------------
+
+/// Load compute shader program from a shader compiled with `ShaderType::Compute`
+pub fn load_compute_shader_program(shader_id: u32) -> u32 {
+    return unsafe { bindings::rlLoadComputeShaderProgram(shader_id as c_uint) };
+}
+
+/// Dispatch compute shader (equivalent to *draw* for graphics pipeline)
+pub fn compute_shader_dispatch(group_x: u32, group_y: u32, group_z: u32) {
+    unsafe { bindings::rlComputeShaderDispatch(group_x as c_uint, group_y as c_uint, group_z as c_uint) }
+}
+
+/// Load shader storage buffer object (SSBO) with initial data
+pub fn load_shader_buffer<T: Pod>(data: &[T], usage: BufferUsage) -> u32 {
+    return unsafe {
+        bindings::rlLoadShaderBuffer(size_of_val(data) as c_uint, data.as_ptr() as *const c_void, usage as c_int)
+    };
+}
+
+/// Load shader storage buffer object (SSBO) of `size` bytes, cleared to zero
+pub fn load_shader_buffer_empty(size: u32, usage: BufferUsage) -> u32 {
+    return unsafe { bindings::rlLoadShaderBuffer(size as c_uint, null(), usage as c_int) };
+}
+
+/// Unload shader storage buffer object (SSBO)
+pub fn unload_shader_buffer(id: u32) {
+    unsafe { bindings::rlUnloadShaderBuffer(id as c_uint) }
+}
+
+/// Update SSBO buffer data, `offset` in bytes
+pub fn update_shader_buffer<T: Pod>(id: u32, data: &[T], offset: u32) {
+    unsafe {
+        bindings::rlUpdateShaderBuffer(
+            id as c_uint,
+            data.as_ptr() as *const c_void,
+            size_of_val(data) as c_uint,
+            offset as c_uint,
+        )
+    }
+}
+
+/// Bind SSBO buffer to a binding point
+pub fn bind_shader_buffer(id: u32, index: u32) {
+    unsafe { bindings::rlBindShaderBuffer(id as c_uint, index as c_uint) }
+}
+
+/// Read SSBO buffer data (GPU->CPU) into `dest`, `offset` in bytes
+pub fn read_shader_buffer<T: Pod>(id: u32, dest: &mut [T], offset: u32) {
+    unsafe {
+        bindings::rlReadShaderBuffer(
+            id as c_uint,
+            dest.as_mut_ptr() as *mut c_void,
+            size_of_val(dest) as c_uint,
+            offset as c_uint,
+        )
+    }
+}
+
+/// Copy SSBO data between buffers, offsets and count in bytes
+pub fn copy_shader_buffer(dest_id: u32, src_id: u32, dest_offset: u32, src_offset: u32, count: u32) {
+    unsafe { bindings::rlCopyShaderBuffer(dest_id, src_id, dest_offset, src_offset, count) }
+}
+
+/// Get SSBO buffer size in bytes
+pub fn get_shader_buffer_size(id: u32) -> u32 {
+    return unsafe { bindings::rlGetShaderBufferSize(id as c_uint) };
+}
+
+/// Bind image texture to an image unit (compute shaders)
+pub fn bind_image_texture(id: u32, index: u32, format: PixelFormat, readonly: bool) {
+    unsafe { bindings::rlBindImageTexture(id as c_uint, index as c_uint, format as c_int, readonly) }
+}
+
+// ---------------------------------------------------------------------------------
+// Matrix state management
+// ---------------------------------------------------------------------------------
+
+/// Get internal modelview matrix
+pub fn get_matrix_modelview() -> Matrix {
+    return unsafe { bindings::rlGetMatrixModelview() };
+}
+
+/// Get internal projection matrix
+pub fn get_matrix_projection() -> Matrix {
+    return unsafe { bindings::rlGetMatrixProjection() };
+}
+
+/// Get internal accumulated transform matrix
+pub fn get_matrix_transform() -> Matrix {
+    return unsafe { bindings::rlGetMatrixTransform() };
+}
+
+/// Get internal projection matrix for stereo render (selected eye)
+pub fn get_matrix_projection_stereo(eye: i32) -> Matrix {
+    return unsafe { bindings::rlGetMatrixProjectionStereo(eye as c_int) };
+}
+
+/// Get internal view offset matrix for stereo render (selected eye)
+pub fn get_matrix_view_offset_stereo(eye: i32) -> Matrix {
+    return unsafe { bindings::rlGetMatrixViewOffsetStereo(eye as c_int) };
+}
+
+/// Set a custom projection matrix (replaces internal projection matrix)
+pub fn set_matrix_projection(projection: Matrix) {
+    unsafe { bindings::rlSetMatrixProjection(projection) }
+}
+
+/// Set a custom modelview matrix (replaces internal modelview matrix)
+pub fn set_matrix_modelview(view: Matrix) {
+    unsafe { bindings::rlSetMatrixModelview(view) }
+}
+
+/// Set eyes projection matrices for stereo rendering
+pub fn set_matrix_projection_stereo(right: Matrix, left: Matrix) {
+    unsafe { bindings::rlSetMatrixProjectionStereo(right, left) }
+}
+
+/// Set eyes view offsets matrices for stereo rendering
+pub fn set_matrix_view_offset_stereo(right: Matrix, left: Matrix) {
+    unsafe { bindings::rlSetMatrixViewOffsetStereo(right, left) }
+}
+
+/// Load and draw a cube (positions, normals and texcoords, no indices)
+pub fn load_draw_cube() {
+    unsafe { bindings::rlLoadDrawCube() }
+}
+
+/// Load and draw a quad (positions and texcoords, used for screen-space passes)
+pub fn load_draw_quad() {
+    unsafe { bindings::rlLoadDrawQuad() }
+}
+
+// ---------------------------------------------------------------------------------
+// Multisample renderbuffers (crate internal, raw OpenGL loaded by raylib, desktop only)
+// ---------------------------------------------------------------------------------
+
+const GL_RENDERBUFFER: c_uint = 0x8D41;
+const GL_COLOR_ATTACHMENT0: c_uint = 0x8CE0;
+/// GL_DEPTH_COMPONENT24, internal format of multisampled depth renderbuffers
+pub(crate) const GL_DEPTH_COMPONENT24: u32 = 0x81A6;
+
+/// Load a multisampled renderbuffer with an OpenGL internal format, 0 if not supported
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn load_renderbuffer_multisample(width: i32, height: i32, internal_format: u32, samples: i32) -> u32 {
+    unsafe {
+        let (Some(gen_renderbuffers), Some(bind_renderbuffer), Some(renderbuffer_storage)) = (
+            bindings::glad_glGenRenderbuffers,
+            bindings::glad_glBindRenderbuffer,
+            bindings::glad_glRenderbufferStorageMultisample,
+        ) else {
+            return 0;
+        };
+
+        let mut id = 0;
+        gen_renderbuffers(1, &mut id);
+        bind_renderbuffer(GL_RENDERBUFFER, id);
+        renderbuffer_storage(GL_RENDERBUFFER, samples, internal_format, width, height);
+        bind_renderbuffer(GL_RENDERBUFFER, 0);
+        return id;
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn load_renderbuffer_multisample(_width: i32, _height: i32, _internal_format: u32, _samples: i32) -> u32 {
+    return 0;
+}
+
+/// Unload a renderbuffer (depth renderbuffers attached to a framebuffer are unloaded with it)
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn unload_renderbuffer(id: u32) {
+    if let Some(delete_renderbuffers) = unsafe { bindings::glad_glDeleteRenderbuffers } {
+        unsafe { delete_renderbuffers(1, &id) };
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn unload_renderbuffer(_id: u32) {}
+
+/// Copy one color attachment between two framebuffers of the same size (resolves multisampling)
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn blit_color_attachment(src_fbo: u32, dst_fbo: u32, attachment: u32, width: i32, height: i32) {
+    let (Some(read_buffer), Some(draw_buffers)) = (unsafe { bindings::glad_glReadBuffer }, unsafe {
+        bindings::glad_glDrawBuffers
+    }) else {
+        return;
+    };
+
+    bind_framebuffer(FramebufferTarget::Read, src_fbo);
+    bind_framebuffer(FramebufferTarget::Draw, dst_fbo);
+    let buffer = GL_COLOR_ATTACHMENT0 + attachment;
+    unsafe {
+        read_buffer(buffer);
+        draw_buffers(1, &buffer);
+    }
+    blit_framebuffer((0, 0, width, height), (0, 0, width, height), BlitMask::COLOR);
+    unsafe { read_buffer(GL_COLOR_ATTACHMENT0) };
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn blit_color_attachment(_src_fbo: u32, _dst_fbo: u32, _attachment: u32, _width: i32, _height: i32) {}
+
+/// Render a framebuffer without color attachments (depth only)
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn disable_color_buffers(fbo: u32) {
+    const GL_NONE: c_uint = 0;
+    let (Some(read_buffer), Some(draw_buffers)) = (unsafe { bindings::glad_glReadBuffer }, unsafe {
+        bindings::glad_glDrawBuffers
+    }) else {
+        return;
+    };
+
+    enable_framebuffer(fbo);
+    unsafe {
+        read_buffer(GL_NONE);
+        draw_buffers(1, &GL_NONE);
+    }
+    disable_framebuffer();
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn disable_color_buffers(_fbo: u32) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mipmap_sizes() {
+        assert_eq!(mipmaps_data_size(4, 4, PixelFormat::UncompressedR8g8b8a8, 1), 64);
+        assert_eq!(mipmaps_data_size(4, 4, PixelFormat::UncompressedR8g8b8a8, 3), 64 + 16 + 4);
+        // NPOT levels stop at 1 pixel
+        assert_eq!(mipmaps_data_size(4, 1, PixelFormat::UncompressedGrayscale, 4), 4 + 2 + 1 + 1);
+        assert_eq!(mipmaps_data_size(4, 4, PixelFormat::UncompressedR8g8b8a8, 0), 0);
+    }
+
+    #[test]
+    fn uniform_counts() {
+        assert_eq!(uniform_count(size_of::<[f32; 6]>(), ShaderUniformDataType::Vec3), 2);
+        assert_eq!(uniform_count(size_of::<[Vector3; 4]>(), ShaderUniformDataType::Vec3), 4);
+        assert_eq!(uniform_count(size_of::<Vector4>(), ShaderUniformDataType::Float), 4);
+        assert_eq!(uniform_count(size_of::<i32>(), ShaderUniformDataType::Sampler2d), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a whole number of Vec4 elements")]
+    fn uniform_count_mismatch() {
+        uniform_count(size_of::<Vector3>(), ShaderUniformDataType::Vec4);
+    }
+}
@@ -0,0 +1,67 @@
+/// Named marker on an animation timeline
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationEvent {
+    pub name: String,
+    /// Frame the event fires at (fractional frames are allowed)
+    pub frame: f32,
+}
+
+/// Event reached by a track during `AnimationPlayer::update()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiredAnimationEvent {
+    pub name: String,
+    /// Index of the animation in the player
+    pub animation: usize,
+    pub layer: usize,
+    /// Weight of the track when the event fired, useful to ignore tracks fading out
+    pub weight: f32,
+}
+
+/// Number of times `marker` is passed when playback moves from frame `from` to frame `to`.
+/// The start frame only counts when `include_start` is set (first update of a track).
+pub(crate) fn marker_crossings(from: f32, to: f32, marker: f32, period: Option<f32>, include_start: bool) -> usize {
+    // NOTE: Backwards playback is handled as forward playback on a mirrored timeline
+    if to < from {
+        return marker_crossings(-from, -to, -marker, period, include_start);
+    }
+
+    let from = if include_start { from - f32::EPSILON.max(from.abs() * f32::EPSILON) } else { from };
+    match period {
+        Some(period) if period > 0.0 => {
+            let crossings = ((to - marker) / period).floor() - ((from - marker) / period).floor();
+            return crossings.max(0.0) as usize;
+        }
+        _ => return (from < marker && marker <= to) as usize,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossings_without_looping() {
+        assert_eq!(marker_crossings(0.0, 5.0, 3.0, None, false), 1);
+        assert_eq!(marker_crossings(0.0, 2.5, 3.0, None, false), 0);
+        assert_eq!(marker_crossings(2.0, 3.0, 3.0, None, false), 1);
+        assert_eq!(marker_crossings(5.0, 0.0, 3.0, None, false), 1);
+    }
+
+    #[test]
+    fn crossings_at_the_start_frame() {
+        assert_eq!(marker_crossings(3.0, 5.0, 3.0, None, false), 0);
+        assert_eq!(marker_crossings(3.0, 5.0, 3.0, None, true), 1);
+        assert_eq!(marker_crossings(0.0, 1.0, 0.0, Some(4.0), false), 0);
+        assert_eq!(marker_crossings(0.0, 1.0, 0.0, Some(4.0), true), 1);
+    }
+
+    #[test]
+    fn crossings_when_looping() {
+        assert_eq!(marker_crossings(3.0, 5.5, 1.0, Some(4.0), false), 1);
+        assert_eq!(marker_crossings(3.0, 9.0, 1.0, Some(4.0), false), 2);
+        // The first frame is reached again when wrapping around
+        assert_eq!(marker_crossings(0.0, 4.0, 0.0, Some(4.0), true), 2);
+        assert_eq!(marker_crossings(6.0, 1.0, 3.0, Some(4.0), false), 1);
+        assert_eq!(marker_crossings(1.0, -1.0, 3.0, Some(4.0), false), 1);
+    }
+}
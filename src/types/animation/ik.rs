@@ -0,0 +1,335 @@
+use crate::{BoneInfo, Pose, Quaternion, Transform, Vector3};
+
+// NOTE: Solvers work on a local pose (e.g. `AnimationPlayer::pose()`) and targets given in model space,
+// world positions must be brought into model space with the inverse of the model transform.
+
+/// Analytic two-bone IK (e.g. hip-knee-ankle or shoulder-elbow-wrist)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoBoneIk {
+    /// First bone of the chain (hip)
+    pub root: usize,
+    /// Middle joint (knee), a descendant of `root`
+    pub middle: usize,
+    /// End effector (ankle), a descendant of `middle`
+    pub end: usize,
+    /// Model space point the middle joint bends towards (keeps the current bend plane if not set)
+    pub pole: Option<Vector3>,
+}
+
+impl TwoBoneIk {
+    pub fn new(root: usize, middle: usize, end: usize) -> Self {
+        return Self { root, middle, end, pole: None };
+    }
+
+    /// Rotate the root and middle bones so the end bone reaches `target`.
+    /// Returns false when the target is out of reach (the chain is stretched towards it),
+    /// or when a bone is not part of the skeleton (the pose is left unchanged).
+    pub fn solve(&self, pose: &mut Pose, bones: &[BoneInfo], target: Vector3) -> bool {
+        if !is_valid(pose, bones, &[self.root, self.middle, self.end]) {
+            return false;
+        }
+
+        let mut globals = pose.to_model_space(bones);
+        let a = globals[self.root].translation;
+        let b = globals[self.middle].translation;
+        let c = globals[self.end].translation;
+
+        let length_ab = (b - a).length();
+        let length_cb = (c - b).length();
+        let distance = (target - a).length();
+        let length_at = distance.clamp(0.001, (length_ab + length_cb) * 0.9999);
+
+        // Interior angles of the current triangle and of the solved one (law of cosines)
+        let ac_ab_0 = angle_between(c - a, b - a);
+        let ba_bc_0 = angle_between(a - b, c - b);
+        let ac_at_0 = angle_between(c - a, target - a);
+        let ac_ab_1 = safe_acos(
+            (length_cb * length_cb - length_ab * length_ab - length_at * length_at) / (-2.0 * length_ab * length_at),
+        );
+        let ba_bc_1 = safe_acos(
+            (length_at * length_at - length_ab * length_ab - length_cb * length_cb) / (-2.0 * length_ab * length_cb),
+        );
+
+        let mut bend_axis = (c - a).cross(b - a).normalize();
+        if bend_axis.length_sqr() == 0.0 {
+            bend_axis = perpendicular(c - a);
+        }
+        let target_axis = (c - a).cross(target - a).normalize();
+
+        rotate_bone(pose, bones, &mut globals, self.root, Quaternion::from_axis_angle(bend_axis, ac_ab_1 - ac_ab_0));
+        rotate_bone(pose, bones, &mut globals, self.middle, Quaternion::from_axis_angle(bend_axis, ba_bc_1 - ba_bc_0));
+        if target_axis.length_sqr() > 0.0 {
+            rotate_bone(pose, bones, &mut globals, self.root, Quaternion::from_axis_angle(target_axis, ac_at_0));
+        }
+
+        if let Some(pole) = self.pole {
+            // Twist the chain around the root-end axis so the middle joint points at the pole
+            let axis = (globals[self.end].translation - a).normalize();
+            let project = |v: Vector3| v - axis * axis.dot(v);
+            let current = project(globals[self.middle].translation - a);
+            let desired = project(pole - a);
+            if current.length_sqr() > 1e-8 && desired.length_sqr() > 1e-8 {
+                rotate_bone(pose, bones, &mut globals, self.root, Quaternion::from_to(current, desired));
+            }
+        }
+
+        return distance <= length_ab + length_cb;
+    }
+}
+
+/// Chain of bones from a root to a tip, solved iteratively with FABRIK or CCD
+#[derive(Debug, Clone, PartialEq)]
+pub struct IkChain {
+    /// Bones from the root to the tip, every bone is a descendant of the previous one
+    pub bones: Vec<usize>,
+    /// Maximum solver iterations
+    pub iterations: usize,
+    /// Distance to the target considered reached
+    pub tolerance: f32,
+}
+
+impl IkChain {
+    /// Chain following the parents of `tip` up to `root`, `None` if `root` is not an ancestor of `tip`
+    pub fn new(bones: &[BoneInfo], root: usize, tip: usize) -> Option<Self> {
+        if root >= bones.len() || tip >= bones.len() {
+            return None;
+        }
+
+        let mut chain = vec![tip];
+        let mut current = tip;
+        while current != root {
+            // NOTE: Also stops on parent cycles, a chain cannot be longer than the skeleton
+            if chain.len() > bones.len() {
+                return None;
+            }
+            current = bones.get(current)?.parent()?;
+            chain.push(current);
+        }
+        chain.reverse();
+        return Some(Self { bones: chain, iterations: 10, tolerance: 0.001 });
+    }
+
+    /// Solve with FABRIK (forward and backward reaching), returns true if the target was reached.
+    /// Returns false without changing the pose if a bone of the chain is not part of the skeleton.
+    pub fn solve_fabrik(&self, pose: &mut Pose, bones: &[BoneInfo], target: Vector3) -> bool {
+        let count = self.bones.len();
+        if count < 2 || !is_valid(pose, bones, &self.bones) {
+            return false;
+        }
+
+        let mut globals = pose.to_model_space(bones);
+        let mut positions: Vec<Vector3> = self.bones.iter().map(|&bone| globals[bone].translation).collect();
+        let lengths: Vec<f32> = positions.windows(2).map(|pair| (pair[1] - pair[0]).length()).collect();
+        let root = positions[0];
+
+        let reached = if (target - root).length() >= lengths.iter().sum::<f32>() {
+            // Out of reach: stretch the chain towards the target
+            for i in 0..count - 1 {
+                positions[i + 1] = positions[i] + (target - positions[i]).normalize() * lengths[i];
+            }
+            false
+        } else {
+            let mut reached = false;
+            for _ in 0..self.iterations {
+                positions[count - 1] = target;
+                for i in (0..count - 1).rev() {
+                    positions[i] = positions[i + 1] + (positions[i] - positions[i + 1]).normalize() * lengths[i];
+                }
+                positions[0] = root;
+                for i in 0..count - 1 {
+                    positions[i + 1] = positions[i] + (positions[i + 1] - positions[i]).normalize() * lengths[i];
+                }
+                if (positions[count - 1] - target).length() <= self.tolerance {
+                    reached = true;
+                    break;
+                }
+            }
+            reached
+        };
+
+        // Turn every bone towards the solved position of the next one
+        for i in 0..count - 1 {
+            let (bone, next) = (self.bones[i], self.bones[i + 1]);
+            let current = globals[next].translation - globals[bone].translation;
+            let desired = positions[i + 1] - globals[bone].translation;
+            rotate_bone(pose, bones, &mut globals, bone, Quaternion::from_to(current, desired));
+        }
+
+        return reached;
+    }
+
+    /// Solve with CCD (cyclic coordinate descent), returns true if the target was reached.
+    /// Returns false without changing the pose if a bone of the chain is not part of the skeleton.
+    pub fn solve_ccd(&self, pose: &mut Pose, bones: &[BoneInfo], target: Vector3) -> bool {
+        let count = self.bones.len();
+        if count < 2 || !is_valid(pose, bones, &self.bones) {
+            return false;
+        }
+
+        let tip = self.bones[count - 1];
+        let mut globals = pose.to_model_space(bones);
+        for _ in 0..self.iterations {
+            for &bone in self.bones[..count - 1].iter().rev() {
+                let joint = globals[bone].translation;
+                let rotation = Quaternion::from_to(globals[tip].translation - joint, target - joint);
+                rotate_bone(pose, bones, &mut globals, bone, rotation);
+            }
+            if (globals[tip].translation - target).length() <= self.tolerance {
+                return true;
+            }
+        }
+        return false;
+    }
+}
+
+/// Rotate a bone so one of its axes points at a target, within an angle limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LookAtConstraint {
+    pub bone: usize,
+    /// Bone space axis that should point at the target
+    pub forward: Vector3,
+    /// Maximum rotation away from the animated direction, in radians
+    pub max_angle: f32,
+    /// Constraint influence, from 0 to 1
+    pub weight: f32,
+}
+
+impl LookAtConstraint {
+    pub fn new(bone: usize, forward: Vector3, max_angle: f32) -> Self {
+        return Self { bone, forward, max_angle, weight: 1.0 };
+    }
+
+    /// Rotate the bone towards `target`, returns false if the rotation was limited
+    /// or if the bone is not part of the skeleton (the pose is left unchanged)
+    pub fn solve(&self, pose: &mut Pose, bones: &[BoneInfo], target: Vector3) -> bool {
+        if !is_valid(pose, bones, &[self.bone]) {
+            return false;
+        }
+
+        let mut globals = pose.to_model_space(bones);
+        let global = globals[self.bone];
+        let current = global.rotation.rotate(self.forward);
+        let desired = target - global.translation;
+        if desired.length_sqr() == 0.0 {
+            return true;
+        }
+
+        let (axis, angle) = Quaternion::from_to(current, desired).to_axis_angle();
+        let limited = angle.min(self.max_angle);
+        let rotation = Quaternion::from_axis_angle(axis, limited * self.weight.clamp(0.0, 1.0));
+        rotate_bone(pose, bones, &mut globals, self.bone, rotation);
+        return limited == angle;
+    }
+}
+
+/// Apply a model space rotation to a bone (around its own position) and update the model space transforms
+fn rotate_bone(pose: &mut Pose, bones: &[BoneInfo], globals: &mut Vec<Transform>, bone: usize, rotation: Quaternion) {
+    let rotation = rotation.multiply(globals[bone].rotation);
+    let parent_rotation = bones[bone].parent().map_or(Quaternion::identity(), |parent| globals[parent].rotation);
+    pose.transforms[bone].rotation = parent_rotation.invert().multiply(rotation).normalize();
+    *globals = pose.to_model_space(bones);
+}
+
+/// Whether the pose matches the skeleton and every bone index (and parent) is part of it
+fn is_valid(pose: &Pose, bones: &[BoneInfo], indices: &[usize]) -> bool {
+    let count = bones.len();
+    return pose.bone_count() == count
+        && indices.iter().all(|&bone| bone < count)
+        && bones.iter().all(|bone| bone.parent().is_none_or(|parent| parent < count));
+}
+
+fn safe_acos(value: f32) -> f32 {
+    return value.clamp(-1.0, 1.0).acos();
+}
+
+fn angle_between(a: Vector3, b: Vector3) -> f32 {
+    return safe_acos(a.normalize().dot(b.normalize()));
+}
+
+/// Any unit vector perpendicular to `v`
+fn perpendicular(v: Vector3) -> Vector3 {
+    let axis = v.cross(Vector3::new(0.0, 1.0, 0.0));
+    if axis.length_sqr() > 1e-8 {
+        return axis.normalize();
+    }
+    return v.cross(Vector3::new(1.0, 0.0, 0.0)).normalize();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::animation::tests::{assert_near, at, chain};
+
+    /// Straight chain of three bones pointing up, one unit apart
+    fn straight() -> (Vec<BoneInfo>, Pose) {
+        return (chain(3), Pose::new(vec![at(0.0, 0.0, 0.0), at(0.0, 1.0, 0.0), at(0.0, 1.0, 0.0)]));
+    }
+
+    fn tip(pose: &Pose, bones: &[BoneInfo]) -> Vector3 {
+        return pose.to_model_space(bones)[2].translation;
+    }
+
+    #[test]
+    fn two_bone_ik() {
+        let (bones, mut pose) = straight();
+        let ik = TwoBoneIk::new(0, 1, 2);
+        assert!(ik.solve(&mut pose, &bones, Vector3::new(1.0, 1.0, 0.0)));
+        assert_near(tip(&pose, &bones), Vector3::new(1.0, 1.0, 0.0));
+
+        // Unreachable targets stretch the chain towards them
+        let (bones, mut pose) = straight();
+        assert!(!ik.solve(&mut pose, &bones, Vector3::new(3.0, 0.0, 0.0)));
+        assert!(tip(&pose, &bones).distance(Vector3::new(2.0, 0.0, 0.0)) < 0.05);
+    }
+
+    #[test]
+    fn iterative_chains() {
+        let (bones, _) = straight();
+        let chain = IkChain::new(&bones, 0, 2).unwrap();
+        assert_eq!(chain.bones, [0, 1, 2]);
+
+        for solve in [IkChain::solve_fabrik, IkChain::solve_ccd] {
+            let (bones, mut pose) = straight();
+            assert!(solve(&chain, &mut pose, &bones, Vector3::new(1.0, 1.0, 0.0)));
+            assert!(tip(&pose, &bones).distance(Vector3::new(1.0, 1.0, 0.0)) < 0.01);
+
+            let (bones, mut pose) = straight();
+            assert!(!solve(&chain, &mut pose, &bones, Vector3::new(3.0, 0.0, 0.0)));
+            assert!(tip(&pose, &bones).normalize().distance(Vector3::new(1.0, 0.0, 0.0)) < 0.05);
+        }
+    }
+
+    #[test]
+    fn look_at_angle_limit() {
+        let up = Vector3::new(0.0, 1.0, 0.0);
+        let look_at = LookAtConstraint::new(2, up, std::f32::consts::FRAC_PI_6);
+
+        let (bones, mut pose) = straight();
+        assert!(!look_at.solve(&mut pose, &bones, Vector3::new(5.0, 2.0, 0.0)));
+        let forward = pose.to_model_space(&bones)[2].rotation.rotate(up);
+        assert!((angle_between(forward, up) - std::f32::consts::FRAC_PI_6).abs() < 1e-4);
+
+        let (bones, mut pose) = straight();
+        assert!(look_at.solve(&mut pose, &bones, Vector3::new(0.5, 4.0, 0.0)));
+        let forward = pose.to_model_space(&bones)[2].rotation.rotate(up);
+        assert_near(forward, Vector3::new(0.5, 2.0, 0.0).normalize());
+    }
+
+    #[test]
+    fn bones_out_of_range() {
+        let (bones, pose) = straight();
+        let target = Vector3::new(1.0, 1.0, 0.0);
+        assert!(IkChain::new(&bones, 2, 0).is_none());
+        assert!(IkChain::new(&bones, 0, 3).is_none());
+        assert!(IkChain::new(&bones, 3, 3).is_none());
+
+        let mut solved = pose.clone();
+        assert!(!TwoBoneIk::new(0, 1, 3).solve(&mut solved, &bones, target));
+        let chain = IkChain { bones: vec![0, 4], iterations: 10, tolerance: 0.001 };
+        assert!(!chain.solve_fabrik(&mut solved, &bones, target));
+        assert!(!chain.solve_ccd(&mut solved, &bones, target));
+        assert!(!LookAtConstraint::new(5, Vector3::new(0.0, 1.0, 0.0), 1.0).solve(&mut solved, &bones, target));
+        assert!(!TwoBoneIk::new(0, 1, 2).solve(&mut solved, &bones[..2], target));
+        assert_eq!(solved, pose);
+    }
+}
@@ -0,0 +1,207 @@
+mod events;
+mod ik;
+mod player;
+mod pose;
+mod root_motion;
+
+pub use events::*;
+pub use ik::*;
+pub use player::*;
+pub use pose::*;
+pub use root_motion::*;
+
+use crate::{
+    BoneInfo, Model, Transform, fixed_str, is_model_animation_valid, load_model_animations, raw_slice,
+    unload_model_animation, update_model_animation, update_model_animation_bones,
+};
+
+/// ModelAnimation
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ModelAnimation {
+    /// Number of bones
+    pub bone_count: i32,
+    /// Number of animation frames
+    pub frame_count: i32,
+    /// Bones information (skeleton)
+    pub bones: *mut BoneInfo,
+    /// Poses array by frame
+    pub frame_poses: *mut *mut Transform,
+    /// Animation name
+    pub name: [u8; 32],
+}
+
+impl ModelAnimation {
+    pub fn load(filename: &str) -> Vec<ModelAnimation> {
+        return load_model_animations(filename);
+    }
+
+    pub fn unload(self) {
+        unload_model_animation(self);
+    }
+
+    pub fn is_valid(&self, model: &Model) -> bool {
+        return is_model_animation_valid(model.shallow_copy(), *self);
+    }
+
+    pub fn name(&self) -> &str {
+        return fixed_str(&self.name);
+    }
+
+    pub fn bones(&self) -> &[BoneInfo] {
+        return unsafe { raw_slice(self.bones, self.bone_count) };
+    }
+
+    /// Model space bone transforms of a frame, `None` if the frame is out of range
+    pub fn frame_pose(&self, frame: usize) -> Option<&[Transform]> {
+        let frames = unsafe { raw_slice(self.frame_poses, self.frame_count) };
+        return frames.get(frame).map(|&transforms| unsafe { raw_slice(transforms, self.bone_count) });
+    }
+
+    /// Local pose at a fractional frame, interpolated between the two nearest frames.
+    /// Looping animations interpolate the last frame back into the first one.
+    pub fn sample(&self, frame: f32, looping: bool) -> Pose {
+        let identity = Pose::identity(self.bone_count.max(0) as usize);
+        let Some((first, next, amount)) = self.frame_pair(frame, looping) else {
+            return identity;
+        };
+        let (Some(first_pose), Some(next_pose)) = (self.frame_pose(first), self.frame_pose(next)) else {
+            return identity;
+        };
+
+        let mut pose = Pose::from_model_space(self.bones(), first_pose);
+        if next != first {
+            pose.blend(&Pose::from_model_space(self.bones(), next_pose), amount, None);
+        }
+        return pose;
+    }
+
+    /// Local transform of a single bone at a fractional frame, see `sample()`.
+    /// Returns `None` if the bone is out of range.
+    pub fn sample_bone(&self, frame: f32, looping: bool, bone: usize) -> Option<Transform> {
+        let parent = self.bones().get(bone)?.parent();
+        let Some((first, next, amount)) = self.frame_pair(frame, looping) else {
+            return Some(Transform::identity());
+        };
+
+        let local = |frame: usize| -> Option<Transform> {
+            let transforms = self.frame_pose(frame)?;
+            let transform = transforms.get(bone)?;
+            match parent {
+                Some(parent) => return Some(transform.relative_to(transforms.get(parent)?)),
+                None => return Some(*transform),
+            }
+        };
+        return Some(local(first)?.lerp(&local(next)?, amount));
+    }
+
+    /// Frames surrounding a fractional frame and the interpolation amount between them
+    fn frame_pair(&self, frame: f32, looping: bool) -> Option<(usize, usize, f32)> {
+        if self.frame_count <= 0 {
+            return None;
+        }
+
+        let frame_count = self.frame_count as usize;
+        let frame = match looping {
+            true => frame.rem_euclid(frame_count as f32),
+            false => frame.clamp(0.0, (frame_count - 1) as f32),
+        };
+        let first = (frame.floor() as usize).min(frame_count - 1);
+        let next = match first + 1 < frame_count {
+            true => first + 1,
+            false if looping => 0,
+            false => first,
+        };
+        return Some((first, next, frame - first as f32));
+    }
+
+    /// Pose the model meshes on the CPU at an integer frame
+    pub fn update(&self, model: &mut Model, frame: i32) {
+        update_model_animation(model.shallow_copy(), *self, frame);
+    }
+
+    /// Update the model bone matrices (GPU skinning) at an integer frame
+    pub fn update_bones(&self, model: &mut Model, frame: i32) {
+        update_model_animation_bones(model.shallow_copy(), *self, frame);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::{Matrix, Vector3};
+    use std::mem::ManuallyDrop;
+
+    /// Bones chained to each other, bone 0 is the root
+    pub(crate) fn chain(bone_count: usize) -> Vec<BoneInfo> {
+        return (0..bone_count).map(|index| BoneInfo { name: [0; 32], parent: index as i32 - 1 }).collect();
+    }
+
+    pub(crate) fn at(x: f32, y: f32, z: f32) -> Transform {
+        return Transform { translation: Vector3::new(x, y, z), ..Transform::identity() };
+    }
+
+    /// Animation over model space frames (the buffers are leaked)
+    pub(crate) fn animation(bones: &[BoneInfo], frames: Vec<Vec<Transform>>) -> ModelAnimation {
+        let poses: Vec<*mut Transform> = frames.into_iter().map(|frame| frame.leak().as_mut_ptr()).collect();
+        return ModelAnimation {
+            bone_count: bones.len() as i32,
+            frame_count: poses.len() as i32,
+            bones: bones.to_vec().leak().as_mut_ptr(),
+            frame_poses: poses.leak().as_mut_ptr(),
+            name: [0; 32],
+        };
+    }
+
+    /// Model holding only a skeleton, never unloaded since raylib did not allocate it
+    pub(crate) fn model(bones: &[BoneInfo], bind_pose: Vec<Transform>) -> ManuallyDrop<Model> {
+        return ManuallyDrop::new(Model {
+            transform: Matrix::identity(),
+            mesh_count: 0,
+            material_count: 0,
+            meshes: std::ptr::null_mut(),
+            materials: std::ptr::null_mut(),
+            mesh_material: std::ptr::null_mut(),
+            bone_count: bones.len() as i32,
+            bones: bones.to_vec().leak().as_mut_ptr(),
+            bind_pose: bind_pose.leak().as_mut_ptr(),
+        });
+    }
+
+    pub(crate) fn assert_near(actual: Vector3, expected: Vector3) {
+        assert!(actual.distance(expected) < 1e-4, "{:?} != {:?}", actual, expected);
+    }
+
+    /// Three bone chain moving its root by 2 along X and stretching its last bone from 1 to 2
+    fn moving_chain() -> ModelAnimation {
+        let frames = vec![
+            vec![at(0.0, 0.0, 0.0), at(0.0, 1.0, 0.0), at(0.0, 2.0, 0.0)],
+            vec![at(2.0, 0.0, 0.0), at(2.0, 1.0, 0.0), at(2.0, 3.0, 0.0)],
+        ];
+        return animation(&chain(3), frames);
+    }
+
+    #[test]
+    fn frame_pose_out_of_range() {
+        let animation = moving_chain();
+        assert_eq!(animation.frame_pose(1).map(|pose| pose.len()), Some(3));
+        assert!(animation.frame_pose(2).is_none());
+        assert!(ModelAnimation { frame_count: 0, ..animation }.frame_pose(0).is_none());
+    }
+
+    #[test]
+    fn sample_interpolates_local_transforms() {
+        let animation = moving_chain();
+        assert_near(animation.sample_bone(0.5, false, 0).unwrap().translation, Vector3::new(1.0, 0.0, 0.0));
+        assert_near(animation.sample_bone(0.5, false, 2).unwrap().translation, Vector3::new(0.0, 1.5, 0.0));
+        assert!(animation.sample_bone(0.5, false, 3).is_none());
+
+        // Looping playback interpolates the last frame back into the first one
+        let pose = animation.sample(1.25, true);
+        assert_near(pose.transforms[0].translation, Vector3::new(1.5, 0.0, 0.0));
+        assert_near(pose.transforms[2].translation, Vector3::new(0.0, 1.75, 0.0));
+
+        let pose = animation.sample(5.0, false);
+        assert_near(pose.transforms[0].translation, Vector3::new(2.0, 0.0, 0.0));
+    }
+}
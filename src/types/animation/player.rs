@@ -0,0 +1,489 @@
+use crate::{
+    AnimationEvent, BoneInfo, BoneMask, FiredAnimationEvent, Model, ModelAnimation, Pose, RootMotion, Transform,
+    marker_crossings,
+};
+use std::fmt;
+
+/// How an animation layer is combined with the layers below it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerBlendMode {
+    /// Blend towards the layer pose
+    Override,
+    /// Add the layer motion relative to the first frame of its animations
+    Additive,
+}
+
+/// Animation playing on a layer
+#[derive(Debug, Clone)]
+pub struct AnimationTrack {
+    /// Index of the animation in the player
+    pub animation: usize,
+    /// Playback time in seconds
+    pub time: f32,
+    /// Playback speed multiplier (negative plays backwards)
+    pub speed: f32,
+    pub looping: bool,
+    /// Blend weight relative to the other tracks of the layer
+    pub weight: f32,
+    fade_target: f32,
+    /// Weight change per second
+    fade_rate: f32,
+    /// Whether the track was updated at least once (events at the start time fire on the first update)
+    started: bool,
+}
+
+impl AnimationTrack {
+    fn new(animation: usize, weight: f32) -> Self {
+        return Self {
+            animation,
+            time: 0.0,
+            speed: 1.0,
+            looping: true,
+            weight,
+            fade_target: weight,
+            fade_rate: 0.0,
+            started: false,
+        };
+    }
+
+    /// Fade the weight to `target` over `duration` seconds
+    pub fn fade_to(&mut self, target: f32, duration: f32) {
+        self.fade_target = target;
+        if duration <= 0.0 {
+            self.weight = target;
+            self.fade_rate = 0.0;
+        } else {
+            self.fade_rate = (target - self.weight).abs() / duration;
+        }
+    }
+
+    pub fn is_fading_out(&self) -> bool {
+        return self.fade_target <= 0.0 && self.fade_rate > 0.0;
+    }
+
+    fn update_fade(&mut self, delta_time: f32) {
+        let step = self.fade_rate * delta_time;
+        if (self.fade_target - self.weight).abs() <= step {
+            self.weight = self.fade_target;
+            self.fade_rate = 0.0;
+        } else if self.fade_target > self.weight {
+            self.weight += step;
+        } else {
+            self.weight -= step;
+        }
+    }
+}
+
+/// Group of tracks blended together and combined with the layers below
+#[derive(Debug, Clone)]
+pub struct AnimationLayer {
+    pub mode: LayerBlendMode,
+    /// Layer influence, from 0 to 1
+    pub weight: f32,
+    /// Per bone influence (every bone if not set)
+    pub mask: Option<BoneMask>,
+    pub tracks: Vec<AnimationTrack>,
+}
+
+type EventCallback = Box<dyn FnMut(&FiredAnimationEvent)>;
+
+/// Skeletal animation player: advances time, interpolates frames, blends and cross-fades animations
+/// over layers and writes the resulting pose into a model.
+/// Layer 0 is the base layer, layers are applied in order on top of the model bind pose.
+pub struct AnimationPlayer {
+    animations: Vec<ModelAnimation>,
+    bones: Vec<BoneInfo>,
+    bind_pose: Pose,
+    frame_rate: f32,
+    layers: Vec<AnimationLayer>,
+    pose: Pose,
+    /// Events of every animation
+    events: Vec<Vec<AnimationEvent>>,
+    callbacks: Vec<(String, EventCallback)>,
+    fired_events: Vec<FiredAnimationEvent>,
+    root_motion: Option<RootMotion>,
+    root_motion_delta: Transform,
+}
+
+impl AnimationPlayer {
+    /// Player for the skeleton of `model`.
+    /// The animations must match the model skeleton and stay loaded while the player uses them.
+    pub fn new(model: &Model, animations: Vec<ModelAnimation>) -> Result<Self, String> {
+        let bone_count = model.bones().len();
+        if let Some(animation) = animations.iter().find(|animation| animation.bones().len() != bone_count) {
+            return Err(format!(
+                "animation: \"{}\" has {} bones, the model skeleton has {}",
+                animation.name(),
+                animation.bones().len(),
+                bone_count
+            ));
+        }
+
+        let bind_pose = Pose::bind(model);
+        let base_layer = AnimationLayer { mode: LayerBlendMode::Override, weight: 1.0, mask: None, tracks: Vec::new() };
+        return Ok(Self {
+            events: vec![Vec::new(); animations.len()],
+            animations,
+            bones: model.bones().to_vec(),
+            pose: bind_pose.clone(),
+            bind_pose,
+            frame_rate: 60.0,
+            layers: vec![base_layer],
+            callbacks: Vec::new(),
+            fired_events: Vec::new(),
+            root_motion: None,
+            root_motion_delta: Transform::identity(),
+        });
+    }
+
+    pub fn animations(&self) -> &[ModelAnimation] {
+        return &self.animations;
+    }
+
+    pub fn animation_index(&self, name: &str) -> Option<usize> {
+        return self.animations.iter().position(|animation| animation.name() == name);
+    }
+
+    pub fn bones(&self) -> &[BoneInfo] {
+        return &self.bones;
+    }
+
+    pub fn frame_rate(&self) -> f32 {
+        return self.frame_rate;
+    }
+
+    /// Animation frames per second (60 by default)
+    pub fn set_frame_rate(&mut self, frame_rate: f32) {
+        self.frame_rate = frame_rate;
+    }
+
+    /// Duration in seconds of a non-looping playback of an animation
+    pub fn duration(&self, animation: usize) -> f32 {
+        return (self.animations[animation].frame_count - 1).max(0) as f32 / self.frame_rate;
+    }
+
+    // Layers
+
+    pub fn add_layer(&mut self, mode: LayerBlendMode, mask: Option<BoneMask>) -> usize {
+        self.layers.push(AnimationLayer { mode, weight: 1.0, mask, tracks: Vec::new() });
+        return self.layers.len() - 1;
+    }
+
+    pub fn layers(&self) -> &[AnimationLayer] {
+        return &self.layers;
+    }
+
+    pub fn layer(&self, layer: usize) -> &AnimationLayer {
+        return &self.layers[layer];
+    }
+
+    pub fn layer_mut(&mut self, layer: usize) -> &mut AnimationLayer {
+        return &mut self.layers[layer];
+    }
+
+    // Playback
+
+    /// Play an animation on a layer from the start, replacing the tracks of the layer
+    pub fn play(&mut self, layer: usize, animation: usize) -> &mut AnimationTrack {
+        let tracks = &mut self.layers[layer].tracks;
+        tracks.clear();
+        tracks.push(AnimationTrack::new(animation, 1.0));
+        return tracks.last_mut().unwrap();
+    }
+
+    /// Start an animation on a layer and fade the tracks already playing out over `duration` seconds
+    pub fn cross_fade(&mut self, layer: usize, animation: usize, duration: f32) -> &mut AnimationTrack {
+        let tracks = &mut self.layers[layer].tracks;
+        for track in tracks.iter_mut() {
+            track.fade_to(0.0, duration);
+        }
+        tracks.retain(|track| track.weight > 0.0);
+
+        let mut track = AnimationTrack::new(animation, 0.0);
+        track.fade_to(1.0, duration);
+        tracks.push(track);
+        return tracks.last_mut().unwrap();
+    }
+
+    /// Play several animations at once with the given weights (e.g. walk/run blending).
+    /// Animations already playing keep their time, other tracks of the layer are removed.
+    pub fn blend(&mut self, layer: usize, weights: &[(usize, f32)]) {
+        let tracks = &mut self.layers[layer].tracks;
+        tracks.retain(|track| !track.is_fading_out() && weights.iter().any(|&(animation, _)| animation == track.animation));
+        for &(animation, weight) in weights {
+            match tracks.iter_mut().find(|track| track.animation == animation) {
+                Some(track) => track.fade_to(weight, 0.0),
+                None => tracks.push(AnimationTrack::new(animation, weight)),
+            }
+        }
+    }
+
+    /// Fade out every track of a layer over `duration` seconds
+    pub fn stop(&mut self, layer: usize, duration: f32) {
+        let tracks = &mut self.layers[layer].tracks;
+        for track in tracks.iter_mut() {
+            track.fade_to(0.0, duration);
+        }
+        tracks.retain(|track| track.weight > 0.0);
+    }
+
+    /// Whether a non-looping track reached its end
+    pub fn is_finished(&self, track: &AnimationTrack) -> bool {
+        if track.looping {
+            return false;
+        }
+        match track.speed < 0.0 {
+            true => return track.time <= 0.0,
+            false => return track.time >= self.duration(track.animation),
+        }
+    }
+
+    /// Advance playback and fades by `delta_time` seconds, fire the events reached and compute the new pose
+    pub fn update(&mut self, delta_time: f32) {
+        self.fired_events.clear();
+        let mut root_motion = RootMotionBlend::default();
+
+        for (layer_index, layer) in self.layers.iter_mut().enumerate() {
+            for track in &mut layer.tracks {
+                let animation = &self.animations[track.animation];
+                let from = track.time * self.frame_rate;
+                let mut to = (track.time + delta_time * track.speed) * self.frame_rate;
+                let period = match track.looping {
+                    true => Some(animation.frame_count.max(1) as f32),
+                    false => None,
+                };
+                if period.is_none() {
+                    to = to.clamp(0.0, (animation.frame_count - 1).max(0) as f32);
+                }
+
+                for event in &self.events[track.animation] {
+                    let crossings = marker_crossings(from, to, event.frame, period, !track.started);
+                    for _ in 0..crossings {
+                        self.fired_events.push(FiredAnimationEvent {
+                            name: event.name.clone(),
+                            animation: track.animation,
+                            layer: layer_index,
+                            weight: track.weight,
+                        });
+                    }
+                }
+
+                if layer_index == 0
+                    && let Some(settings) = &self.root_motion
+                {
+                    root_motion.add(settings.delta(animation, from, to, track.looping), track.weight);
+                }
+
+                track.time = match period {
+                    Some(period) => (to / self.frame_rate).rem_euclid(period / self.frame_rate),
+                    None => to / self.frame_rate,
+                };
+                track.started = true;
+                track.update_fade(delta_time);
+            }
+            layer.tracks.retain(|track| !(track.weight <= 0.0 && track.fade_target <= 0.0));
+        }
+
+        self.root_motion_delta = root_motion.finish(self.layers[0].weight);
+        self.pose = self.evaluate();
+
+        for event in &self.fired_events {
+            for (name, callback) in &mut self.callbacks {
+                if *name == event.name {
+                    callback(event);
+                }
+            }
+        }
+    }
+
+    /// Pose computed by the last `update()`
+    pub fn pose(&self) -> &Pose {
+        return &self.pose;
+    }
+
+    /// Replace the current pose, e.g. after post-processing it
+    pub fn set_pose(&mut self, pose: Pose) {
+        self.pose = pose;
+    }
+
+    /// Write the current pose into the bone matrices of the model (GPU skinning)
+    pub fn apply(&self, model: &mut Model) {
+        self.pose.apply(model);
+    }
+
+    /// Pose the model meshes on the CPU with the current pose
+    pub fn apply_cpu(&self, model: &mut Model) {
+        self.pose.apply_cpu(model);
+    }
+
+    fn sample(&self, track: &AnimationTrack) -> Pose {
+        return self.animations[track.animation].sample(track.time * self.frame_rate, track.looping);
+    }
+
+    // Events
+
+    /// Attach a named event to an animation at a (fractional) frame
+    pub fn add_event(&mut self, animation: usize, frame: f32, name: &str) {
+        self.events[animation].push(AnimationEvent { name: name.to_string(), frame });
+    }
+
+    /// Attach a named event to an animation at a time in seconds, converted with the current frame rate
+    pub fn add_event_at_time(&mut self, animation: usize, time: f32, name: &str) {
+        self.add_event(animation, time * self.frame_rate, name);
+    }
+
+    pub fn events(&self, animation: usize) -> &[AnimationEvent] {
+        return &self.events[animation];
+    }
+
+    pub fn clear_events(&mut self, animation: usize) {
+        self.events[animation].clear();
+    }
+
+    /// Call `callback` every time an event named `name` fires
+    pub fn on_event(&mut self, name: &str, callback: impl FnMut(&FiredAnimationEvent) + 'static) {
+        self.callbacks.push((name.to_string(), Box::new(callback)));
+    }
+
+    /// Events fired by the last `update()`, in firing order
+    pub fn fired_events(&self) -> &[FiredAnimationEvent] {
+        return &self.fired_events;
+    }
+
+    // Root motion
+
+    /// Extract root motion from the base layer (disabled with `None`)
+    pub fn set_root_motion(&mut self, root_motion: Option<RootMotion>) {
+        self.root_motion = root_motion;
+    }
+
+    pub fn root_motion(&self) -> Option<RootMotion> {
+        return self.root_motion;
+    }
+
+    /// Root bone motion during the last `update()`, relative to the previous root transform.
+    /// Apply it to the character with `character = character.combine(&delta)`.
+    pub fn root_motion_delta(&self) -> Transform {
+        return self.root_motion_delta;
+    }
+
+    fn evaluate(&self) -> Pose {
+        let mut pose = self.bind_pose.clone();
+        for (layer_index, layer) in self.layers.iter().enumerate() {
+            match layer.mode {
+                LayerBlendMode::Override => {
+                    // Weighted average of the tracks, accumulated one track at a time
+                    let mut blended: Option<Pose> = None;
+                    let mut total_weight = 0.0;
+                    for track in layer.tracks.iter().filter(|track| track.weight > 0.0) {
+                        let mut track_pose = self.sample(track);
+                        if layer_index == 0
+                            && let Some(root_motion) = &self.root_motion
+                        {
+                            root_motion.remove(&self.animations[track.animation], &mut track_pose);
+                        }
+                        total_weight += track.weight;
+                        match &mut blended {
+                            Some(blended) => blended.blend(&track_pose, track.weight / total_weight, None),
+                            None => blended = Some(track_pose),
+                        }
+                    }
+                    if let Some(blended) = blended {
+                        pose.blend(&blended, layer.weight * total_weight.min(1.0), layer.mask.as_ref());
+                    }
+                }
+                LayerBlendMode::Additive => {
+                    for track in layer.tracks.iter().filter(|track| track.weight > 0.0) {
+                        let reference = self.animations[track.animation].sample(0.0, false);
+                        pose.add(&self.sample(track), &reference, layer.weight * track.weight, layer.mask.as_ref());
+                    }
+                }
+            }
+        }
+        return pose;
+    }
+}
+
+impl fmt::Debug for AnimationPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f
+            .debug_struct("AnimationPlayer")
+            .field("animations", &self.animations)
+            .field("frame_rate", &self.frame_rate)
+            .field("layers", &self.layers)
+            .field("events", &self.events)
+            .field("callbacks", &self.callbacks.len())
+            .field("root_motion", &self.root_motion)
+            .finish_non_exhaustive();
+    }
+}
+
+/// Weighted average of the root motion of the base layer tracks
+#[derive(Default)]
+struct RootMotionBlend {
+    delta: Option<Transform>,
+    total_weight: f32,
+}
+
+impl RootMotionBlend {
+    fn add(&mut self, delta: Transform, weight: f32) {
+        if weight <= 0.0 {
+            return;
+        }
+        self.total_weight += weight;
+        match &mut self.delta {
+            Some(blended) => *blended = blended.lerp(&delta, weight / self.total_weight),
+            None => self.delta = Some(delta),
+        }
+    }
+
+    fn finish(self, layer_weight: f32) -> Transform {
+        let Some(delta) = self.delta else {
+            return Transform::identity();
+        };
+        return Transform::identity().lerp(&delta, layer_weight * self.total_weight.min(1.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector3;
+    use crate::types::animation::tests::{animation, assert_near, at, chain, model};
+
+    fn rest() -> Vec<Transform> {
+        return vec![at(0.0, 0.0, 0.0), at(0.0, 1.0, 0.0), at(0.0, 2.0, 0.0)];
+    }
+
+    #[test]
+    fn new_checks_the_skeleton() {
+        let model = model(&chain(3), rest());
+        assert!(AnimationPlayer::new(&model, vec![animation(&chain(3), vec![rest()])]).is_ok());
+
+        let error = AnimationPlayer::new(&model, vec![animation(&chain(2), vec![rest()[..2].to_vec()])]).unwrap_err();
+        assert!(error.contains("2 bones"), "{}", error);
+    }
+
+    #[test]
+    fn cross_fade_weights() {
+        let model = model(&chain(3), rest());
+        let idle = animation(&chain(3), vec![rest()]);
+        let moved = animation(&chain(3), vec![vec![at(4.0, 0.0, 0.0), at(4.0, 1.0, 0.0), at(4.0, 2.0, 0.0)]]);
+        let mut player = AnimationPlayer::new(&model, vec![idle, moved]).unwrap();
+
+        player.play(0, 0);
+        player.cross_fade(0, 1, 1.0);
+        player.update(0.25);
+        let weights: Vec<f32> = player.layer(0).tracks.iter().map(|track| track.weight).collect();
+        assert_eq!(weights, [0.75, 0.25]);
+        assert_near(player.pose().transforms[0].translation, Vector3::new(1.0, 0.0, 0.0));
+        assert_near(player.pose().transforms[1].translation, Vector3::new(0.0, 1.0, 0.0));
+
+        // The faded out track is removed once its weight reaches 0
+        player.update(0.75);
+        let tracks = &player.layer(0).tracks;
+        assert_eq!(tracks.len(), 1);
+        assert_eq!((tracks[0].animation, tracks[0].weight), (1, 1.0));
+        assert_near(player.pose().transforms[0].translation, Vector3::new(4.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{BoneInfo, Model, ModelAnimation, Quaternion, Transform, Vector3, update_model_animation, update_model_animation_bones};
+
+/// Skeleton pose, bone transforms relative to their parent bone
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pose {
+    pub transforms: Vec<Transform>,
+}
+
+impl Pose {
+    pub fn new(transforms: Vec<Transform>) -> Self {
+        return Self { transforms };
+    }
+
+    pub fn identity(bone_count: usize) -> Self {
+        return Self { transforms: vec![Transform::identity(); bone_count] };
+    }
+
+    /// Rest pose of a model
+    pub fn bind(model: &Model) -> Self {
+        return Self::from_model_space(model.bones(), model.bind_pose());
+    }
+
+    /// Local pose from model space bone transforms (as stored in `ModelAnimation` frames and `Model::bind_pose`)
+    pub fn from_model_space(bones: &[BoneInfo], transforms: &[Transform]) -> Self {
+        let local = transforms
+            .iter()
+            .zip(bones)
+            .map(|(transform, bone)| match bone.parent() {
+                Some(parent) => transform.relative_to(&transforms[parent]),
+                None => *transform,
+            })
+            .collect();
+        return Self { transforms: local };
+    }
+
+    /// Model space bone transforms
+    pub fn to_model_space(&self, bones: &[BoneInfo]) -> Vec<Transform> {
+        fn resolve(index: usize, pose: &Pose, bones: &[BoneInfo], result: &mut [Option<Transform>]) -> Transform {
+            if let Some(transform) = result[index] {
+                return transform;
+            }
+            let transform = match bones[index].parent() {
+                Some(parent) => resolve(parent, pose, bones, result).combine(&pose.transforms[index]),
+                None => pose.transforms[index],
+            };
+            result[index] = Some(transform);
+            return transform;
+        }
+
+        // NOTE: Bones are not guaranteed to be stored after their parent
+        let mut result = vec![None; self.transforms.len()];
+        return (0..self.transforms.len()).map(|index| resolve(index, self, bones, &mut result)).collect();
+    }
+
+    pub fn bone_count(&self) -> usize {
+        return self.transforms.len();
+    }
+
+    /// Move towards `other` by `weight` (0 keeps this pose, 1 copies `other`), scaled per bone by `mask`
+    pub fn blend(&mut self, other: &Pose, weight: f32, mask: Option<&BoneMask>) {
+        for (index, (transform, target)) in self.transforms.iter_mut().zip(&other.transforms).enumerate() {
+            let weight = weight * mask.map_or(1.0, |mask| mask.weight(index));
+            if weight >= 1.0 {
+                *transform = *target;
+            } else if weight > 0.0 {
+                *transform = transform.lerp(target, weight);
+            }
+        }
+    }
+
+    /// Add the difference between `additive` and `reference`, scaled by `weight` and per bone by `mask`
+    pub fn add(&mut self, additive: &Pose, reference: &Pose, weight: f32, mask: Option<&BoneMask>) {
+        let deltas = additive.transforms.iter().zip(&reference.transforms);
+        for (index, (transform, (additive, reference))) in self.transforms.iter_mut().zip(deltas).enumerate() {
+            let weight = weight * mask.map_or(1.0, |mask| mask.weight(index));
+            if weight <= 0.0 {
+                continue;
+            }
+
+            let rotation = reference.rotation.invert().multiply(additive.rotation);
+            let scale = Vector3::new(
+                additive.scale.x / reference.scale.x,
+                additive.scale.y / reference.scale.y,
+                additive.scale.z / reference.scale.z,
+            );
+            transform.translation += (additive.translation - reference.translation) * weight;
+            transform.rotation = transform.rotation.multiply(Quaternion::identity().slerp(rotation, weight)).normalize();
+            transform.scale = transform.scale.scale(Vector3::one().lerp(scale, weight));
+        }
+    }
+
+    /// Write the pose into the bone matrices of the model (GPU skinning)
+    pub fn apply(&self, model: &mut Model) {
+        self.update_model(model, update_model_animation_bones);
+    }
+
+    /// Pose the model meshes on the CPU
+    pub fn apply_cpu(&self, model: &mut Model) {
+        self.update_model(model, update_model_animation);
+    }
+
+    /// Hand the pose to raylib as a single frame animation
+    fn update_model(&self, model: &mut Model, update: fn(Model, ModelAnimation, i32)) {
+        assert_eq!(self.transforms.len(), model.bones().len(), "Pose bone count does not match the model skeleton");
+
+        let mut transforms = self.to_model_space(model.bones());
+        let mut frames = [transforms.as_mut_ptr()];
+        let animation = ModelAnimation {
+            bone_count: transforms.len() as i32,
+            frame_count: 1,
+            bones: model.bones,
+            frame_poses: frames.as_mut_ptr(),
+            name: [0; 32],
+        };
+        update(model.shallow_copy(), animation, 0);
+    }
+}
+
+/// Per bone blend weights, used to restrict layers to part of a skeleton
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoneMask {
+    weights: Vec<f32>,
+}
+
+impl BoneMask {
+    /// Mask including every bone
+    pub fn all(bone_count: usize) -> Self {
+        return Self { weights: vec![1.0; bone_count] };
+    }
+
+    /// Mask excluding every bone
+    pub fn none(bone_count: usize) -> Self {
+        return Self { weights: vec![0.0; bone_count] };
+    }
+
+    /// Mask including `bone` and all its descendants
+    pub fn subtree(bones: &[BoneInfo], bone: usize) -> Self {
+        let mut mask = Self::none(bones.len());
+        mask.set_subtree(bones, bone, 1.0);
+        return mask;
+    }
+
+    pub fn weight(&self, bone: usize) -> f32 {
+        return self.weights.get(bone).copied().unwrap_or(0.0);
+    }
+
+    pub fn set_weight(&mut self, bone: usize, weight: f32) {
+        self.weights[bone] = weight;
+    }
+
+    /// Set the weight of `bone` and all its descendants
+    pub fn set_subtree(&mut self, bones: &[BoneInfo], bone: usize, weight: f32) {
+        for index in 0..bones.len() {
+            let mut current = Some(index);
+            while let Some(ancestor) = current {
+                if ancestor == bone {
+                    self.weights[index] = weight;
+                    break;
+                }
+                current = bones[ancestor].parent();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::animation::tests::{assert_near, at, chain};
+
+    fn translated(x: f32, y: f32, z: f32) -> Pose {
+        return Pose::new(vec![at(x, y, z); 3]);
+    }
+
+    #[test]
+    fn blend_with_mask() {
+        let mut mask = BoneMask::all(3);
+        mask.set_weight(1, 0.5);
+        mask.set_weight(2, 0.0);
+
+        let mut pose = Pose::identity(3);
+        pose.blend(&translated(2.0, 0.0, 0.0), 1.0, Some(&mask));
+        assert_near(pose.transforms[0].translation, Vector3::new(2.0, 0.0, 0.0));
+        assert_near(pose.transforms[1].translation, Vector3::new(1.0, 0.0, 0.0));
+        assert_near(pose.transforms[2].translation, Vector3::zero());
+
+        let mut pose = Pose::identity(3);
+        pose.blend(&translated(2.0, 0.0, 0.0), 0.5, Some(&BoneMask::subtree(&chain(3), 1)));
+        assert_near(pose.transforms[0].translation, Vector3::zero());
+        assert_near(pose.transforms[1].translation, Vector3::new(1.0, 0.0, 0.0));
+        assert_near(pose.transforms[2].translation, Vector3::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn add_with_mask() {
+        let mut mask = BoneMask::all(3);
+        mask.set_weight(1, 0.5);
+        mask.set_weight(2, 0.0);
+
+        let turn = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), std::f32::consts::FRAC_PI_2);
+        let additive = Transform::new(Vector3::new(0.0, 2.0, 0.0), turn, Vector3::new(2.0, 2.0, 2.0));
+        let additive = Pose::new(vec![additive; 3]);
+        let reference = translated(0.0, 1.0, 0.0);
+
+        let mut pose = translated(1.0, 0.0, 0.0);
+        pose.add(&additive, &reference, 1.0, Some(&mask));
+
+        let expected = [
+            (1.0, std::f32::consts::FRAC_PI_2, 2.0),
+            (0.5, std::f32::consts::FRAC_PI_4, 1.5),
+            (0.0, 0.0, 1.0),
+        ];
+        for (transform, (offset, angle, scale)) in pose.transforms.iter().zip(expected) {
+            assert_near(transform.translation, Vector3::new(1.0, offset, 0.0));
+            assert!((transform.rotation.to_axis_angle().1 - angle).abs() < 1e-4);
+            assert_near(transform.scale, Vector3::new(scale, scale, scale));
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{ModelAnimation, Pose, Quaternion, Transform, Vector3};
+
+/// Root motion extraction settings: the motion of the root bone is removed from the pose
+/// and reported as a delta to move the character with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootMotion {
+    /// Bone carrying the motion (usually the skeleton root)
+    pub bone: usize,
+    /// Extract the bone translation
+    pub translation: bool,
+    /// Extract the bone rotation
+    pub rotation: bool,
+}
+
+impl RootMotion {
+    /// Extract the translation of `bone`, rotation stays in the pose
+    pub fn new(bone: usize) -> Self {
+        return Self { bone, translation: true, rotation: false };
+    }
+
+    /// Motion of the root bone from the first frame of the animation up to `frame` (none if the bone is out of range).
+    /// NOTE: Motion stops at the last frame, looping back to the first frame adds no motion.
+    pub fn motion(&self, animation: &ModelAnimation, frame: f32) -> Transform {
+        let last = (animation.frame_count - 1).max(0) as f32;
+        let (Some(extracted), Some(reference)) = (
+            animation.sample_bone(frame.clamp(0.0, last), false, self.bone),
+            animation.sample_bone(0.0, false, self.bone),
+        ) else {
+            return Transform::identity();
+        };
+        return self.extracted(&extracted).combine(&self.extracted(&reference).inverse());
+    }
+
+    /// Root motion between two frames, `to` may be past the end (or before the start) of a looping animation
+    pub fn delta(&self, animation: &ModelAnimation, from: f32, to: f32, looping: bool) -> Transform {
+        let period = animation.frame_count as f32;
+        if !looping || period <= 1.0 {
+            return self.motion(animation, to).relative_to(&self.motion(animation, from));
+        }
+
+        let loops = (to / period).floor() - (from / period).floor();
+        let (from, to) = (from.rem_euclid(period), to.rem_euclid(period));
+        if loops == 0.0 {
+            return self.motion(animation, to).relative_to(&self.motion(animation, from));
+        }
+
+        let cycle = self.motion(animation, period);
+        if loops > 0.0 {
+            let mut delta = cycle.relative_to(&self.motion(animation, from));
+            for _ in 1..loops as usize {
+                delta = delta.combine(&cycle);
+            }
+            return delta.combine(&self.motion(animation, to));
+        }
+
+        let mut delta = self.motion(animation, from).inverse();
+        for _ in 1..(-loops) as usize {
+            delta = delta.combine(&cycle.inverse());
+        }
+        return delta.combine(&self.motion(animation, to).relative_to(&cycle));
+    }
+
+    /// Remove the root motion from a sampled pose: extracted components keep their first frame value
+    pub fn remove(&self, animation: &ModelAnimation, pose: &mut Pose) {
+        let Some(reference) = animation.sample_bone(0.0, false, self.bone) else {
+            return;
+        };
+        let Some(root) = pose.transforms.get_mut(self.bone) else {
+            return;
+        };
+        if self.translation {
+            root.translation = reference.translation;
+        }
+        if self.rotation {
+            root.rotation = reference.rotation;
+        }
+    }
+
+    /// Components of a bone transform carried as root motion
+    fn extracted(&self, transform: &Transform) -> Transform {
+        return Transform {
+            translation: if self.translation { transform.translation } else { Vector3::zero() },
+            rotation: if self.rotation { transform.rotation } else { Quaternion::identity() },
+            scale: Vector3::one(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::animation::tests::{animation, assert_near, at, chain};
+
+    /// Single bone moving by 1 along X every frame
+    fn walk() -> ModelAnimation {
+        return animation(&chain(1), (0..4).map(|frame| vec![at(frame as f32, 0.0, 0.0)]).collect());
+    }
+
+    #[test]
+    fn delta_across_loops() {
+        let root_motion = RootMotion::new(0);
+        let animation = walk();
+        let delta = |from: f32, to: f32, looping: bool| root_motion.delta(&animation, from, to, looping).translation;
+
+        assert_near(delta(1.0, 3.0, false), Vector3::new(2.0, 0.0, 0.0));
+        assert_near(delta(1.0, 6.0, false), Vector3::new(2.0, 0.0, 0.0));
+        // Wrapping from the last frame back to the first one adds no motion
+        assert_near(delta(2.0, 6.0, true), Vector3::new(3.0, 0.0, 0.0));
+        assert_near(delta(2.0, 10.0, true), Vector3::new(6.0, 0.0, 0.0));
+        assert_near(delta(3.5, 4.5, true), Vector3::new(0.5, 0.0, 0.0));
+        assert_near(delta(6.0, 2.0, true), Vector3::new(-3.0, 0.0, 0.0));
+        assert_near(delta(1.0, -1.0, true), Vector3::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn remove_keeps_the_first_frame() {
+        let animation = walk();
+        let mut pose = animation.sample(2.0, false);
+        RootMotion::new(0).remove(&animation, &mut pose);
+        assert_near(pose.transforms[0].translation, Vector3::zero());
+
+        let unchanged = pose.clone();
+        RootMotion::new(3).remove(&animation, &mut pose);
+        assert_eq!(pose, unchanged);
+    }
+}
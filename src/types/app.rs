@@ -0,0 +1,126 @@
+use crate::{
+    Window, begin_drawing, disable_event_waiting, enable_event_waiting, end_drawing, get_frame_time, run_main_loop,
+};
+
+/// Game or tool driven by an `AppRunner`
+pub trait App {
+    /// Called once before the first frame, the window is already open
+    fn init(&mut self) {}
+
+    /// Called once per frame with the clamped frame time in seconds
+    fn update(&mut self, _delta_time: f32) {}
+
+    /// Called zero or more times per frame with the fixed timestep in seconds, for deterministic simulation
+    fn fixed_update(&mut self, _timestep: f32) {}
+
+    /// Called between `begin_drawing()` and `end_drawing()`. `alpha` is the fraction of a fixed step elapsed since
+    /// the last `fixed_update()`, to interpolate between the previous and current simulation states.
+    fn draw(&mut self, alpha: f32);
+}
+
+/// Main loop with a fixed simulation timestep.
+///
+/// The frame time is accumulated and consumed in `timestep` sized `fixed_update()` calls, clamped to
+/// `max_frame_time` so a slow frame does not trigger a spiral of catch-up steps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AppRunner {
+    timestep: f32,
+    max_frame_time: f32,
+    event_waiting: bool,
+    pause_when_minimized: bool,
+    accumulator: f32,
+}
+
+impl Default for AppRunner {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl AppRunner {
+    /// Runner with a 60 Hz timestep and frames clamped to 0.25 seconds
+    pub fn new() -> Self {
+        return Self {
+            timestep: 1.0 / 60.0,
+            max_frame_time: 0.25,
+            event_waiting: false,
+            pause_when_minimized: true,
+            accumulator: 0.0,
+        };
+    }
+
+    /// Seconds simulated by every `fixed_update()` call
+    pub fn timestep(mut self, timestep: f32) -> Self {
+        self.timestep = timestep.max(f32::EPSILON);
+        return self;
+    }
+
+    /// Longest frame time accounted for, longer frames slow the simulation down
+    pub fn max_frame_time(mut self, max_frame_time: f32) -> Self {
+        self.max_frame_time = max_frame_time.max(0.0);
+        return self;
+    }
+
+    /// Only render new frames on input events, for tool-style apps
+    pub fn event_waiting(mut self, event_waiting: bool) -> Self {
+        self.event_waiting = event_waiting;
+        return self;
+    }
+
+    /// Skip updates while the window is minimized (enabled by default)
+    pub fn pause_when_minimized(mut self, pause_when_minimized: bool) -> Self {
+        self.pause_when_minimized = pause_when_minimized;
+        return self;
+    }
+
+    /// Run `app` until the window should close, the window must be open and is left open.
+    /// NOTE: Blocking loops need the default `asyncify` feature on wasm32, see `run_main_loop()` otherwise
+    pub fn run(mut self, app: &mut impl App) {
+        if self.event_waiting {
+            enable_event_waiting();
+        }
+        app.init();
+        while !Window::should_close() {
+            self.frame(app);
+        }
+        if self.event_waiting {
+            disable_event_waiting();
+        }
+    }
+
+    /// Run `app` from `run_main_loop()` until the window should close, for wasm32 builds without `asyncify`.
+    /// NOTE: On wasm32 this never returns and `app` is never dropped
+    pub fn run_main_loop<A: App + 'static>(mut self, mut app: A) {
+        let event_waiting = self.event_waiting;
+        if event_waiting {
+            enable_event_waiting();
+        }
+        app.init();
+        run_main_loop(move || self.frame(&mut app));
+        if event_waiting {
+            disable_event_waiting();
+        }
+    }
+
+    /// Update and draw a single frame
+    fn frame(&mut self, app: &mut impl App) {
+        if self.pause_when_minimized && Window::is_minimized() {
+            // NOTE: Window events are only polled by end_drawing()
+            begin_drawing();
+            end_drawing();
+            return;
+        }
+
+        let delta_time = get_frame_time().min(self.max_frame_time);
+        app.update(delta_time);
+        self.accumulator += delta_time;
+        while self.accumulator >= self.timestep {
+            app.fixed_update(self.timestep);
+            self.accumulator -= self.timestep;
+        }
+
+        begin_drawing();
+        app.draw(self.accumulator / self.timestep);
+        end_drawing();
+    }
+}
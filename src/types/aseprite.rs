@@ -0,0 +1,950 @@
+use crate::{
+    Color, Image, Rectangle, SpriteFrame, SpriteSheet, SpriteSlice, SpriteSliceKey, SpriteTag, SpriteTagDirection,
+    Texture2D, Vector2, decompress_data,
+};
+use std::ptr::copy_nonoverlapping;
+
+const HEADER_MAGIC: u16 = 0xA5E0;
+const FRAME_MAGIC: u16 = 0xF1FA;
+
+const CHUNK_OLD_PALETTE: u16 = 0x0004;
+const CHUNK_LAYER: u16 = 0x2004;
+const CHUNK_CEL: u16 = 0x2005;
+const CHUNK_TAGS: u16 = 0x2018;
+const CHUNK_PALETTE: u16 = 0x2019;
+const CHUNK_SLICE: u16 = 0x2022;
+
+const LAYER_VISIBLE: u16 = 1;
+const LAYER_BACKGROUND: u16 = 8;
+const LAYER_REFERENCE: u16 = 64;
+const HEADER_LAYER_OPACITY: u32 = 1;
+
+/// Layer blend mode, as numbered in the Aseprite file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AsepriteBlendMode {
+    #[default]
+    Normal = 0,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+    Addition,
+    Subtract,
+    Divide,
+}
+
+impl AsepriteBlendMode {
+    fn from_u16(value: u16) -> Self {
+        const MODES: [AsepriteBlendMode; 19] = [
+            AsepriteBlendMode::Normal,
+            AsepriteBlendMode::Multiply,
+            AsepriteBlendMode::Screen,
+            AsepriteBlendMode::Overlay,
+            AsepriteBlendMode::Darken,
+            AsepriteBlendMode::Lighten,
+            AsepriteBlendMode::ColorDodge,
+            AsepriteBlendMode::ColorBurn,
+            AsepriteBlendMode::HardLight,
+            AsepriteBlendMode::SoftLight,
+            AsepriteBlendMode::Difference,
+            AsepriteBlendMode::Exclusion,
+            AsepriteBlendMode::Hue,
+            AsepriteBlendMode::Saturation,
+            AsepriteBlendMode::Color,
+            AsepriteBlendMode::Luminosity,
+            AsepriteBlendMode::Addition,
+            AsepriteBlendMode::Subtract,
+            AsepriteBlendMode::Divide,
+        ];
+        return MODES.get(value as usize).copied().unwrap_or_default();
+    }
+}
+
+/// Layer of an Aseprite file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsepriteLayer {
+    pub name: String,
+    /// Visible in the editor, hidden layers are not composited
+    pub visible: bool,
+    /// Group layer, its children follow it with a higher `child_level`
+    pub group: bool,
+    pub child_level: u16,
+    pub blend_mode: AsepriteBlendMode,
+    pub opacity: u8,
+    flags: u16,
+}
+
+/// Composited frame of an Aseprite file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AsepriteFrame {
+    /// RGBA image of the visible layers
+    pub image: Image,
+    /// Display time in seconds
+    pub duration: f32,
+}
+
+/// Aseprite document decoded from a `.ase`/`.aseprite` file.
+///
+/// Visible layers are composited per frame with their blend mode and opacity.
+/// Group layers only affect visibility, tilemap layers and reference layers are skipped.
+#[derive(Debug, Clone)]
+pub struct AsepriteFile {
+    pub width: i32,
+    pub height: i32,
+    pub frames: Vec<AsepriteFrame>,
+    pub layers: Vec<AsepriteLayer>,
+    pub tags: Vec<SpriteTag>,
+    pub slices: Vec<SpriteSlice>,
+}
+
+/// Cel pixels as stored in the file, converted to RGBA when compositing
+#[derive(Debug, Clone)]
+struct Cel {
+    layer: usize,
+    x: i32,
+    y: i32,
+    opacity: u8,
+    z_index: i16,
+    width: i32,
+    height: i32,
+    pixels: Vec<u8>,
+}
+
+impl AsepriteFile {
+    pub fn load(filename: &str) -> Result<Self, String> {
+        let data = std::fs::read(filename).map_err(|e| format!("aseprite: failed to read {}: {}", filename, e))?;
+        return Self::from_memory(&data);
+    }
+
+    pub fn from_memory(data: &[u8]) -> Result<Self, String> {
+        let mut reader = Reader { data, position: 0 };
+        reader.u32()?;
+        if reader.u16()? != HEADER_MAGIC {
+            return Err("aseprite: not an Aseprite file".to_string());
+        }
+        let frame_count = reader.u16()? as usize;
+        let width = reader.u16()? as i32;
+        let height = reader.u16()? as i32;
+        let depth = reader.u16()?;
+        let flags = reader.u32()?;
+        reader.skip(10)?;
+        let transparent_index = reader.u8()?;
+        if !matches!(depth, 8 | 16 | 32) {
+            return Err(format!("aseprite: unsupported color depth {}", depth));
+        }
+        reader.position = 128;
+
+        let mut document = Self {
+            width,
+            height,
+            frames: Vec::with_capacity(frame_count),
+            layers: Vec::new(),
+            tags: Vec::new(),
+            slices: Vec::new(),
+        };
+        let mut palette = vec![[0u8; 4]; 256];
+        let mut frame_cels: Vec<Vec<Cel>> = Vec::with_capacity(frame_count);
+        let mut durations = Vec::with_capacity(frame_count);
+
+        for frame in 0..frame_count {
+            let frame_start = reader.position;
+            let frame_size = reader.u32()? as usize;
+            if reader.u16()? != FRAME_MAGIC {
+                return Err(format!("aseprite: invalid frame {}", frame));
+            }
+            let old_chunk_count = reader.u16()? as u32;
+            durations.push(reader.u16()? as f32 / 1000.0);
+            reader.skip(2)?;
+            let chunk_count = match reader.u32()? {
+                0 => old_chunk_count,
+                count => count,
+            };
+
+            let mut cels = Vec::new();
+            for _ in 0..chunk_count {
+                let chunk_start = reader.position;
+                let chunk_size = reader.u32()? as usize;
+                let chunk_type = reader.u16()?;
+                let chunk_end = chunk_start + chunk_size;
+                let mut chunk = Reader {
+                    data: reader.slice(chunk_start + 6, chunk_end)?,
+                    position: 0,
+                };
+                match chunk_type {
+                    CHUNK_LAYER => document.layers.push(read_layer(&mut chunk, flags)?),
+                    CHUNK_CEL => {
+                        if let Some(cel) = read_cel(&mut chunk, depth, &frame_cels)? {
+                            cels.push(cel);
+                        }
+                    }
+                    CHUNK_PALETTE => read_palette(&mut chunk, &mut palette)?,
+                    CHUNK_OLD_PALETTE => read_old_palette(&mut chunk, &mut palette)?,
+                    CHUNK_TAGS => read_tags(&mut chunk, &mut document.tags)?,
+                    CHUNK_SLICE => document.slices.push(read_slice(&mut chunk)?),
+                    _ => {}
+                }
+                reader.position = chunk_end;
+            }
+            frame_cels.push(cels);
+            reader.position = frame_start + frame_size;
+        }
+
+        let visible = effective_visibility(&document.layers);
+        for (cels, duration) in frame_cels.iter().zip(durations) {
+            let pixels = document.composite(cels, &visible, depth, &palette, transparent_index)?;
+            let image = image_from_pixels(width, height, &pixels);
+            document.frames.push(AsepriteFrame { image, duration });
+        }
+        return Ok(document);
+    }
+
+    /// Unload the frame images
+    pub fn unload(&self) {
+        for frame in &self.frames {
+            frame.image.unload();
+        }
+    }
+
+    /// Frames packed in a grid on a new texture, with the file tags and slices and the frame durations
+    pub fn to_sprite_sheet(&self) -> SpriteSheet {
+        let count = self.frames.len().max(1) as i32;
+        let columns = (count as f32).sqrt().ceil() as i32;
+        let rows = (count + columns - 1) / columns;
+        let mut image = Image::gen_color(self.width * columns, self.height * rows, Color::BLANK);
+
+        let (width, height) = (self.width as f32, self.height as f32);
+        let mut frames = Vec::with_capacity(self.frames.len());
+        for (i, frame) in self.frames.iter().enumerate() {
+            let x = (i as i32 % columns) as f32 * width;
+            let y = (i as i32 / columns) as f32 * height;
+            let source = Rectangle::new(0.0, 0.0, width, height);
+            let dest = Rectangle::new(x, y, width, height);
+            image.draw(frame.image, source, dest, Color::WHITE);
+
+            let mut sprite_frame = SpriteFrame::new(dest);
+            sprite_frame.duration = frame.duration;
+            frames.push(sprite_frame);
+        }
+        let texture = Texture2D::from_image(image);
+        image.unload();
+
+        let mut sheet = SpriteSheet::new(texture, frames);
+        for tag in &self.tags {
+            sheet.add_tag(tag.clone());
+        }
+        for slice in &self.slices {
+            sheet.add_slice(slice.clone());
+        }
+        return sheet;
+    }
+
+    /// RGBA pixels of the visible cels of a frame
+    fn composite(
+        &self,
+        cels: &[Cel],
+        visible: &[bool],
+        depth: u16,
+        palette: &[[u8; 4]],
+        transparent: u8,
+    ) -> Result<Vec<u8>, String> {
+        let size = pixel_data_size(self.width, self.height, 4)
+            .ok_or_else(|| format!("aseprite: canvas {}x{} is too large", self.width, self.height))?;
+        let mut pixels = vec![0u8; size];
+        let mut order: Vec<&Cel> = cels
+            .iter()
+            .filter(|cel| visible.get(cel.layer) == Some(&true))
+            .collect();
+        // NOTE: The z-index moves a cel among the layers, ties are resolved by the z-index itself
+        order.sort_by_key(|cel| (cel.layer as i64 + cel.z_index as i64, cel.z_index));
+
+        for cel in order {
+            let layer = &self.layers[cel.layer];
+            let background = layer.flags & LAYER_BACKGROUND != 0;
+            let opacity = (cel.opacity as f32 / 255.0) * (layer.opacity as f32 / 255.0);
+            for cy in 0..cel.height {
+                let y = cel.y + cy;
+                if y < 0 || y >= self.height {
+                    continue;
+                }
+                for cx in 0..cel.width {
+                    let x = cel.x + cx;
+                    if x < 0 || x >= self.width {
+                        continue;
+                    }
+                    let source = cel_pixel(
+                        cel,
+                        cy as usize * cel.width as usize + cx as usize,
+                        depth,
+                        palette,
+                        transparent,
+                        background,
+                    );
+                    if source[3] == 0 {
+                        continue;
+                    }
+                    let offset = (y as usize * self.width as usize + x as usize) * 4;
+                    let backdrop = &mut pixels[offset..offset + 4];
+                    let result = blend(backdrop, source, layer.blend_mode, opacity);
+                    backdrop.copy_from_slice(&result);
+                }
+            }
+        }
+        return Ok(pixels);
+    }
+}
+
+/// Size in bytes of `width`x`height` pixels, `None` if it does not fit in memory
+fn pixel_data_size(width: i32, height: i32, bytes_per_pixel: usize) -> Option<usize> {
+    return (width as usize)
+        .checked_mul(height as usize)?
+        .checked_mul(bytes_per_pixel);
+}
+
+/// Visibility of every layer, hidden groups hide their children
+fn effective_visibility(layers: &[AsepriteLayer]) -> Vec<bool> {
+    let mut groups: Vec<bool> = Vec::new();
+    let mut visible = Vec::with_capacity(layers.len());
+    for layer in layers {
+        let level = layer.child_level as usize;
+        let parent_visible = level == 0 || groups.get(level - 1).copied().unwrap_or(true);
+        let layer_visible = layer.visible && parent_visible && layer.flags & LAYER_REFERENCE == 0;
+        if layer.group {
+            groups.resize(level + 1, true);
+            groups[level] = layer_visible;
+        }
+        visible.push(layer_visible && !layer.group);
+    }
+    return visible;
+}
+
+fn cel_pixel(cel: &Cel, index: usize, depth: u16, palette: &[[u8; 4]], transparent: u8, background: bool) -> [u8; 4] {
+    match depth {
+        32 => {
+            let p = &cel.pixels[index * 4..index * 4 + 4];
+            return [p[0], p[1], p[2], p[3]];
+        }
+        16 => {
+            let p = &cel.pixels[index * 2..index * 2 + 2];
+            return [p[0], p[0], p[0], p[1]];
+        }
+        _ => {
+            let color_index = cel.pixels[index];
+            // NOTE: The transparent index is opaque on the background layer
+            if color_index == transparent && !background {
+                return [0, 0, 0, 0];
+            }
+            return palette[color_index as usize];
+        }
+    }
+}
+
+/// Composite `source` over `backdrop` with a blend mode, following the W3C compositing model
+fn blend(backdrop: &[u8], source: [u8; 4], mode: AsepriteBlendMode, opacity: f32) -> [u8; 4] {
+    let b = [
+        backdrop[0] as f32 / 255.0,
+        backdrop[1] as f32 / 255.0,
+        backdrop[2] as f32 / 255.0,
+    ];
+    let s = [
+        source[0] as f32 / 255.0,
+        source[1] as f32 / 255.0,
+        source[2] as f32 / 255.0,
+    ];
+    let backdrop_alpha = backdrop[3] as f32 / 255.0;
+    let source_alpha = source[3] as f32 / 255.0 * opacity;
+
+    let mixed = blend_color(b, s, mode);
+    let mut color = [0.0; 3];
+    for i in 0..3 {
+        // Blended color where the backdrop is opaque, source color where it is transparent
+        color[i] = (1.0 - backdrop_alpha) * s[i] + backdrop_alpha * mixed[i];
+    }
+
+    let alpha = source_alpha + backdrop_alpha * (1.0 - source_alpha);
+    if alpha <= 0.0 {
+        return [0, 0, 0, 0];
+    }
+    let mut result = [0u8; 4];
+    for i in 0..3 {
+        let value = (color[i] * source_alpha + b[i] * backdrop_alpha * (1.0 - source_alpha)) / alpha;
+        result[i] = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    }
+    result[3] = (alpha * 255.0).round() as u8;
+    return result;
+}
+
+fn blend_color(b: [f32; 3], s: [f32; 3], mode: AsepriteBlendMode) -> [f32; 3] {
+    match mode {
+        AsepriteBlendMode::Hue => return set_lum(set_sat(s, sat(b)), lum(b)),
+        AsepriteBlendMode::Saturation => return set_lum(set_sat(b, sat(s)), lum(b)),
+        AsepriteBlendMode::Color => return set_lum(s, lum(b)),
+        AsepriteBlendMode::Luminosity => return set_lum(b, lum(s)),
+        _ => return [0, 1, 2].map(|i| blend_channel(b[i], s[i], mode)),
+    }
+}
+
+fn blend_channel(b: f32, s: f32, mode: AsepriteBlendMode) -> f32 {
+    let multiply = |b: f32, s: f32| b * s;
+    let screen = |b: f32, s: f32| b + s - b * s;
+    let hard_light = |b: f32, s: f32| {
+        if s <= 0.5 {
+            multiply(b, 2.0 * s)
+        } else {
+            screen(b, 2.0 * s - 1.0)
+        }
+    };
+    match mode {
+        AsepriteBlendMode::Multiply => return multiply(b, s),
+        AsepriteBlendMode::Screen => return screen(b, s),
+        AsepriteBlendMode::Overlay => return hard_light(s, b),
+        AsepriteBlendMode::Darken => return b.min(s),
+        AsepriteBlendMode::Lighten => return b.max(s),
+        AsepriteBlendMode::ColorDodge => {
+            if b <= 0.0 {
+                return 0.0;
+            }
+            if s >= 1.0 {
+                return 1.0;
+            }
+            return (b / (1.0 - s)).min(1.0);
+        }
+        AsepriteBlendMode::ColorBurn => {
+            if b >= 1.0 {
+                return 1.0;
+            }
+            if s <= 0.0 {
+                return 0.0;
+            }
+            return 1.0 - ((1.0 - b) / s).min(1.0);
+        }
+        AsepriteBlendMode::HardLight => return hard_light(b, s),
+        AsepriteBlendMode::SoftLight => {
+            if s <= 0.5 {
+                return b - (1.0 - 2.0 * s) * b * (1.0 - b);
+            }
+            let d = if b <= 0.25 {
+                ((16.0 * b - 12.0) * b + 4.0) * b
+            } else {
+                b.sqrt()
+            };
+            return b + (2.0 * s - 1.0) * (d - b);
+        }
+        AsepriteBlendMode::Difference => return (b - s).abs(),
+        AsepriteBlendMode::Exclusion => return b + s - 2.0 * b * s,
+        AsepriteBlendMode::Addition => return (b + s).min(1.0),
+        AsepriteBlendMode::Subtract => return (b - s).max(0.0),
+        AsepriteBlendMode::Divide => {
+            if b <= 0.0 {
+                return 0.0;
+            }
+            if b >= s {
+                return 1.0;
+            }
+            return b / s;
+        }
+        _ => return s,
+    }
+}
+
+fn lum(c: [f32; 3]) -> f32 {
+    return 0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2];
+}
+
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    let c = c.map(|v| v + d);
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+    if n < 0.0 {
+        return c.map(|v| l + (v - l) * l / (l - n));
+    }
+    if x > 1.0 {
+        return c.map(|v| l + (v - l) * (1.0 - l) / (x - l));
+    }
+    return c;
+}
+
+fn sat(c: [f32; 3]) -> f32 {
+    return c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2]);
+}
+
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| c[i].total_cmp(&c[j]));
+    let (min, mid, max) = (order[0], order[1], order[2]);
+    let mut result = [0.0; 3];
+    if c[max] > c[min] {
+        result[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        result[max] = s;
+    }
+    return result;
+}
+
+/// RGBA8 image holding a copy of `pixels`
+fn image_from_pixels(width: i32, height: i32, pixels: &[u8]) -> Image {
+    let image = Image::gen_color(width, height, Color::BLANK);
+    unsafe {
+        copy_nonoverlapping(pixels.as_ptr(), image.data as *mut u8, pixels.len());
+    }
+    return image;
+}
+
+fn read_layer(chunk: &mut Reader, header_flags: u32) -> Result<AsepriteLayer, String> {
+    let flags = chunk.u16()?;
+    let layer_type = chunk.u16()?;
+    let child_level = chunk.u16()?;
+    chunk.skip(4)?;
+    let blend_mode = AsepriteBlendMode::from_u16(chunk.u16()?);
+    let opacity = chunk.u8()?;
+    chunk.skip(3)?;
+    let name = chunk.string()?;
+    return Ok(AsepriteLayer {
+        name,
+        visible: flags & LAYER_VISIBLE != 0,
+        group: layer_type == 1,
+        child_level,
+        blend_mode,
+        // NOTE: Files without the valid opacity flag store layers as opaque
+        opacity: if header_flags & HEADER_LAYER_OPACITY != 0 {
+            opacity
+        } else {
+            255
+        },
+        flags,
+    });
+}
+
+fn read_cel(chunk: &mut Reader, depth: u16, previous: &[Vec<Cel>]) -> Result<Option<Cel>, String> {
+    let layer = chunk.u16()? as usize;
+    let x = chunk.i16()? as i32;
+    let y = chunk.i16()? as i32;
+    let opacity = chunk.u8()?;
+    let cel_type = chunk.u16()?;
+    let z_index = chunk.i16()?;
+    chunk.skip(5)?;
+
+    let bytes_per_pixel = (depth / 8) as usize;
+    match cel_type {
+        // Raw pixels
+        0 => {
+            let width = chunk.u16()? as i32;
+            let height = chunk.u16()? as i32;
+            let size = pixel_data_size(width, height, bytes_per_pixel).ok_or("aseprite: cel is too large")?;
+            let pixels = chunk.bytes(size)?.to_vec();
+            return Ok(Some(Cel {
+                layer,
+                x,
+                y,
+                opacity,
+                z_index,
+                width,
+                height,
+                pixels,
+            }));
+        }
+        // Linked to the cel of the same layer in an earlier frame
+        1 => {
+            let frame = chunk.u16()? as usize;
+            let linked = previous
+                .get(frame)
+                .and_then(|cels| cels.iter().find(|cel| cel.layer == layer));
+            return Ok(linked.map(|cel| Cel {
+                x,
+                y,
+                opacity,
+                z_index,
+                ..cel.clone()
+            }));
+        }
+        // Zlib compressed pixels
+        2 => {
+            let width = chunk.u16()? as i32;
+            let height = chunk.u16()? as i32;
+            let compressed = chunk.bytes(chunk.data.len() - chunk.position)?;
+            // NOTE: DecompressData expects raw DEFLATE, the zlib header is skipped
+            let pixels = decompress_data(compressed.get(2..).unwrap_or_default());
+            let size = pixel_data_size(width, height, bytes_per_pixel).ok_or("aseprite: cel is too large")?;
+            if pixels.len() < size {
+                return Err("aseprite: invalid compressed cel".to_string());
+            }
+            return Ok(Some(Cel {
+                layer,
+                x,
+                y,
+                opacity,
+                z_index,
+                width,
+                height,
+                pixels,
+            }));
+        }
+        // Tilemaps are not supported
+        _ => return Ok(None),
+    }
+}
+
+fn read_palette(chunk: &mut Reader, palette: &mut Vec<[u8; 4]>) -> Result<(), String> {
+    let size = chunk.u32()? as usize;
+    let first = chunk.u32()? as usize;
+    let last = chunk.u32()? as usize;
+    chunk.skip(8)?;
+    if palette.len() < size {
+        palette.resize(size, [0; 4]);
+    }
+    for index in first..=last {
+        let flags = chunk.u16()?;
+        let color = [chunk.u8()?, chunk.u8()?, chunk.u8()?, chunk.u8()?];
+        if flags & 1 != 0 {
+            chunk.string()?;
+        }
+        if let Some(entry) = palette.get_mut(index) {
+            *entry = color;
+        }
+    }
+    return Ok(());
+}
+
+fn read_old_palette(chunk: &mut Reader, palette: &mut [[u8; 4]]) -> Result<(), String> {
+    let packets = chunk.u16()?;
+    let mut index = 0;
+    for _ in 0..packets {
+        index += chunk.u8()? as usize;
+        let count = match chunk.u8()? {
+            0 => 256,
+            count => count as usize,
+        };
+        for _ in 0..count {
+            let color = [chunk.u8()?, chunk.u8()?, chunk.u8()?, 255];
+            if let Some(entry) = palette.get_mut(index) {
+                *entry = color;
+            }
+            index += 1;
+        }
+    }
+    return Ok(());
+}
+
+fn read_tags(chunk: &mut Reader, tags: &mut Vec<SpriteTag>) -> Result<(), String> {
+    let count = chunk.u16()?;
+    chunk.skip(8)?;
+    for _ in 0..count {
+        let from = chunk.u16()? as usize;
+        let to = chunk.u16()? as usize;
+        let direction = match chunk.u8()? {
+            1 => SpriteTagDirection::Reverse,
+            2 => SpriteTagDirection::PingPong,
+            3 => SpriteTagDirection::PingPongReverse,
+            _ => SpriteTagDirection::Forward,
+        };
+        let repeat = chunk.u16()? as u32;
+        chunk.skip(10)?;
+        let name = chunk.string()?;
+        tags.push(SpriteTag {
+            name,
+            from,
+            to,
+            direction,
+            repeat: (repeat > 0).then_some(repeat),
+        });
+    }
+    return Ok(());
+}
+
+fn read_slice(chunk: &mut Reader) -> Result<SpriteSlice, String> {
+    let key_count = chunk.u32()?;
+    let flags = chunk.u32()?;
+    chunk.skip(4)?;
+    let name = chunk.string()?;
+    let mut keys = Vec::with_capacity(key_count as usize);
+    for _ in 0..key_count {
+        let frame = chunk.u32()? as usize;
+        let bounds = Rectangle::new(
+            chunk.i32()? as f32,
+            chunk.i32()? as f32,
+            chunk.u32()? as f32,
+            chunk.u32()? as f32,
+        );
+        let center = match flags & 1 != 0 {
+            true => Some(Rectangle::new(
+                chunk.i32()? as f32,
+                chunk.i32()? as f32,
+                chunk.u32()? as f32,
+                chunk.u32()? as f32,
+            )),
+            false => None,
+        };
+        let pivot = match flags & 2 != 0 {
+            true => Some(Vector2::new(chunk.i32()? as f32, chunk.i32()? as f32)),
+            false => None,
+        };
+        keys.push(SpriteSliceKey {
+            frame,
+            bounds,
+            center,
+            pivot,
+        });
+    }
+    keys.sort_by_key(|key| key.frame);
+    return Ok(SpriteSlice { name, keys });
+}
+
+/// Little-endian reader over the file bytes
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn slice(&self, start: usize, end: usize) -> Result<&'a [u8], String> {
+        return self
+            .data
+            .get(start..end)
+            .ok_or_else(|| "aseprite: unexpected end of file".to_string());
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], String> {
+        let bytes = self.slice(self.position, self.position + count)?;
+        self.position += count;
+        return Ok(bytes);
+    }
+
+    fn skip(&mut self, count: usize) -> Result<(), String> {
+        self.bytes(count)?;
+        return Ok(());
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        return Ok(self.bytes(1)?[0]);
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        return Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()));
+    }
+
+    fn i16(&mut self) -> Result<i16, String> {
+        return Ok(i16::from_le_bytes(self.bytes(2)?.try_into().unwrap()));
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        return Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()));
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        return Ok(i32::from_le_bytes(self.bytes(4)?.try_into().unwrap()));
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let length = self.u16()? as usize;
+        return Ok(String::from_utf8_lossy(self.bytes(length)?).into_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compress_data;
+
+    fn chunk(chunk_type: u16, data: &[u8]) -> Vec<u8> {
+        let mut bytes = ((data.len() + 6) as u32).to_le_bytes().to_vec();
+        bytes.extend(chunk_type.to_le_bytes());
+        bytes.extend(data);
+        return bytes;
+    }
+
+    fn layer(name: &str, visible: bool, blend_mode: AsepriteBlendMode, opacity: u8) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend((visible as u16 * LAYER_VISIBLE).to_le_bytes());
+        data.extend([0, 0, 0, 0, 0, 0, 0, 0]);
+        data.extend((blend_mode as u16).to_le_bytes());
+        data.extend([opacity, 0, 0, 0]);
+        data.extend((name.len() as u16).to_le_bytes());
+        data.extend(name.as_bytes());
+        return chunk(CHUNK_LAYER, &data);
+    }
+
+    /// Cel header followed by the data of its type
+    fn cel(layer: u16, x: i16, y: i16, opacity: u8, cel_type: u16, data: &[u8]) -> Vec<u8> {
+        let mut bytes = layer.to_le_bytes().to_vec();
+        bytes.extend(x.to_le_bytes());
+        bytes.extend(y.to_le_bytes());
+        bytes.push(opacity);
+        bytes.extend(cel_type.to_le_bytes());
+        bytes.extend([0; 7]);
+        bytes.extend(data);
+        return chunk(CHUNK_CEL, &bytes);
+    }
+
+    /// Raw (compressed: false) or zlib (compressed: true) cel of a single color
+    fn cel_pixels(width: u16, height: u16, color: [u8; 4], compressed: bool) -> Vec<u8> {
+        let mut data = width.to_le_bytes().to_vec();
+        data.extend(height.to_le_bytes());
+        let pixels: Vec<u8> = (0..width as usize * height as usize).flat_map(|_| color).collect();
+        if compressed {
+            data.extend([0x78, 0x9c]);
+            data.extend(compress_data(&pixels));
+        } else {
+            data.extend(pixels);
+        }
+        return data;
+    }
+
+    fn frame(duration: u16, chunks: &[Vec<u8>]) -> Vec<u8> {
+        let data = chunks.concat();
+        let mut bytes = ((data.len() + 16) as u32).to_le_bytes().to_vec();
+        bytes.extend(FRAME_MAGIC.to_le_bytes());
+        bytes.extend((chunks.len() as u16).to_le_bytes());
+        bytes.extend(duration.to_le_bytes());
+        bytes.extend([0, 0, 0, 0, 0, 0]);
+        bytes.extend(data);
+        return bytes;
+    }
+
+    fn file(width: u16, height: u16, frames: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = vec![0u8; 128];
+        bytes[4..6].copy_from_slice(&HEADER_MAGIC.to_le_bytes());
+        bytes[6..8].copy_from_slice(&(frames.len() as u16).to_le_bytes());
+        bytes[8..10].copy_from_slice(&width.to_le_bytes());
+        bytes[10..12].copy_from_slice(&height.to_le_bytes());
+        bytes[12..14].copy_from_slice(&32u16.to_le_bytes());
+        bytes[14..18].copy_from_slice(&HEADER_LAYER_OPACITY.to_le_bytes());
+        bytes.extend(frames.concat());
+        return bytes;
+    }
+
+    fn pixel(frame: &AsepriteFrame, x: i32, y: i32) -> [u8; 4] {
+        let color = frame.image.get_color(x, y);
+        return [color.r, color.g, color.b, color.a];
+    }
+
+    #[test]
+    fn decode_frames() {
+        let first = frame(
+            100,
+            &[
+                layer("base", true, AsepriteBlendMode::Normal, 255),
+                layer("shade", true, AsepriteBlendMode::Multiply, 255),
+                layer("hidden", false, AsepriteBlendMode::Normal, 255),
+                cel(0, 0, 0, 255, 0, &cel_pixels(4, 2, [200, 100, 50, 255], false)),
+                cel(1, 2, 0, 255, 0, &cel_pixels(2, 2, [128, 128, 128, 255], false)),
+                cel(2, 0, 0, 255, 0, &cel_pixels(4, 2, [0, 255, 0, 255], false)),
+            ],
+        );
+        // Linked base cel moved one pixel and a compressed cel at half opacity
+        let mut linked = 0u16.to_le_bytes().to_vec();
+        linked.extend([0, 0]);
+        let second = frame(
+            250,
+            &[
+                cel(0, 1, 0, 255, 1, &linked),
+                cel(1, 0, 1, 128, 2, &cel_pixels(1, 1, [0, 0, 255, 255], true)),
+            ],
+        );
+        let document = AsepriteFile::from_memory(&file(4, 2, &[first, second])).unwrap();
+
+        assert_eq!((document.width, document.height), (4, 2));
+        let names: Vec<&str> = document.layers.iter().map(|layer| layer.name.as_str()).collect();
+        assert_eq!(names, ["base", "shade", "hidden"]);
+        assert_eq!(document.layers[1].blend_mode, AsepriteBlendMode::Multiply);
+        assert_eq!(document.frames.len(), 2);
+        assert_eq!(document.frames[0].duration, 0.1);
+        assert_eq!(document.frames[1].duration, 0.25);
+
+        let first = &document.frames[0];
+        assert_eq!(pixel(first, 0, 0), [200, 100, 50, 255]);
+        assert_eq!(pixel(first, 3, 1), [100, 50, 25, 255]);
+
+        let second = &document.frames[1];
+        assert_eq!(pixel(second, 0, 0), [0, 0, 0, 0]);
+        assert_eq!(pixel(second, 1, 0), [200, 100, 50, 255]);
+        assert_eq!(pixel(second, 0, 1), [0, 0, 255, 128]);
+        document.unload();
+    }
+
+    #[test]
+    fn decode_errors() {
+        assert_eq!(
+            AsepriteFile::from_memory(&[0; 4]).unwrap_err(),
+            "aseprite: unexpected end of file"
+        );
+        assert_eq!(
+            AsepriteFile::from_memory(&[0; 128]).unwrap_err(),
+            "aseprite: not an Aseprite file"
+        );
+
+        let mut data = file(4, 4, &[]);
+        data[12] = 24;
+        assert_eq!(
+            AsepriteFile::from_memory(&data).unwrap_err(),
+            "aseprite: unsupported color depth 24"
+        );
+
+        let mut truncated = file(
+            4,
+            4,
+            &[frame(100, &[cel(0, 0, 0, 255, 0, &cel_pixels(4, 4, [0; 4], false))])],
+        );
+        truncated.truncate(truncated.len() - 1);
+        assert_eq!(
+            AsepriteFile::from_memory(&truncated).unwrap_err(),
+            "aseprite: unexpected end of file"
+        );
+    }
+
+    #[test]
+    fn blend_modes() {
+        let backdrop = [200, 100, 50, 255];
+        let gray = [128, 128, 128, 255];
+        assert_eq!(blend(&backdrop, gray, AsepriteBlendMode::Normal, 1.0), gray);
+        assert_eq!(
+            blend(&backdrop, gray, AsepriteBlendMode::Multiply, 1.0),
+            [100, 50, 25, 255]
+        );
+        assert_eq!(
+            blend(&backdrop, gray, AsepriteBlendMode::Screen, 1.0),
+            [228, 178, 153, 255]
+        );
+        assert_eq!(
+            blend(&backdrop, gray, AsepriteBlendMode::Darken, 1.0),
+            [128, 100, 50, 255]
+        );
+        assert_eq!(
+            blend(&backdrop, gray, AsepriteBlendMode::Difference, 1.0),
+            [72, 28, 78, 255]
+        );
+        assert_eq!(
+            blend(&backdrop, gray, AsepriteBlendMode::Addition, 1.0),
+            [255, 228, 178, 255]
+        );
+
+        // Opacity mixes with the backdrop, a transparent backdrop takes the source color
+        assert_eq!(
+            blend(&backdrop, [0, 0, 0, 255], AsepriteBlendMode::Normal, 0.5),
+            [100, 50, 25, 255]
+        );
+        assert_eq!(
+            blend(&[0; 4], gray, AsepriteBlendMode::Multiply, 0.5),
+            [128, 128, 128, 128]
+        );
+
+        // Luminosity keeps the backdrop hue with the source luminance
+        let result = blend(
+            &[255, 0, 0, 255],
+            [255, 255, 255, 255],
+            AsepriteBlendMode::Luminosity,
+            1.0,
+        );
+        assert_eq!(result, [255, 255, 255, 255]);
+    }
+}
@@ -0,0 +1,748 @@
+use crate::json::Json;
+use crate::{Color, Image, PixelFormat, Rectangle, Texture2D};
+use std::collections::BTreeMap;
+
+/// Rectangle packing algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PackingAlgorithm {
+    /// Maximal rectangles with best short side fit, tightest packing
+    #[default]
+    MaxRects,
+    /// Bottom-left skyline, faster with many images
+    Skyline,
+}
+
+/// Placement of an image in an atlas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasRegion {
+    /// Area covered in the atlas, excluding padding and extrusion
+    pub rect: Rectangle,
+    /// Image was rotated 90 degrees clockwise, `rect` holds the rotated size
+    pub rotated: bool,
+}
+
+/// Name to region map of a packed atlas, serializable to JSON for offline packing
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AtlasLayout {
+    pub width: i32,
+    pub height: i32,
+    regions: BTreeMap<String, AtlasRegion>,
+}
+
+impl AtlasLayout {
+    pub fn get(&self, name: &str) -> Option<&AtlasRegion> {
+        return self.regions.get(name);
+    }
+
+    /// Atlas rectangle of `name`
+    pub fn rect(&self, name: &str) -> Option<Rectangle> {
+        return self.regions.get(name).map(|region| region.rect);
+    }
+
+    pub fn insert(&mut self, name: &str, region: AtlasRegion) {
+        self.regions.insert(name.to_string(), region);
+    }
+
+    /// Regions sorted by name
+    pub fn regions(&self) -> impl Iterator<Item = (&str, &AtlasRegion)> {
+        return self.regions.iter().map(|(name, region)| (name.as_str(), region));
+    }
+
+    pub fn len(&self) -> usize {
+        return self.regions.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.regions.is_empty();
+    }
+
+    /// Serialize as `{"width", "height", "regions": {name: {"x", "y", "width", "height", "rotated"}}}`
+    pub fn to_json(&self) -> String {
+        let regions = self
+            .regions
+            .iter()
+            .map(|(name, region)| {
+                let value = Json::Object(vec![
+                    ("x".to_string(), Json::Number(region.rect.x as f64)),
+                    ("y".to_string(), Json::Number(region.rect.y as f64)),
+                    ("width".to_string(), Json::Number(region.rect.width as f64)),
+                    ("height".to_string(), Json::Number(region.rect.height as f64)),
+                    ("rotated".to_string(), Json::Bool(region.rotated)),
+                ]);
+                return (name.clone(), value);
+            })
+            .collect();
+        let root = Json::Object(vec![
+            ("width".to_string(), Json::Number(self.width as f64)),
+            ("height".to_string(), Json::Number(self.height as f64)),
+            ("regions".to_string(), Json::Object(regions)),
+        ]);
+        return root.to_pretty_string();
+    }
+
+    /// Parse metadata written by `to_json()`
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let root = Json::parse(text)?;
+        let int = |value: &Json, key: &str| {
+            return value
+                .get(key)
+                .and_then(Json::as_i64)
+                .ok_or_else(|| format!("atlas: missing or invalid \"{}\"", key));
+        };
+        let mut layout = Self {
+            width: int(&root, "width")? as i32,
+            height: int(&root, "height")? as i32,
+            regions: BTreeMap::new(),
+        };
+        let regions = root
+            .get("regions")
+            .and_then(Json::as_object)
+            .ok_or("atlas: missing or invalid \"regions\"")?;
+        for (name, value) in regions {
+            let rect = Rectangle::new(
+                int(value, "x")? as f32,
+                int(value, "y")? as f32,
+                int(value, "width")? as f32,
+                int(value, "height")? as f32,
+            );
+            let rotated = value.get("rotated").and_then(Json::as_bool).unwrap_or(false);
+            layout.regions.insert(name.clone(), AtlasRegion { rect, rotated });
+        }
+        return Ok(layout);
+    }
+
+    pub fn save(&self, filename: &str) -> Result<(), String> {
+        return std::fs::write(filename, self.to_json())
+            .map_err(|e| format!("atlas: failed to write {}: {}", filename, e));
+    }
+
+    pub fn load(filename: &str) -> Result<Self, String> {
+        let text =
+            std::fs::read_to_string(filename).map_err(|e| format!("atlas: failed to read {}: {}", filename, e))?;
+        return Self::from_json(&text);
+    }
+}
+
+/// Packed atlas image and its layout
+#[derive(Debug, Clone)]
+pub struct TextureAtlas {
+    /// RGBA image owned by the atlas, unload it once it is no longer needed
+    pub image: Image,
+    pub layout: AtlasLayout,
+}
+
+impl TextureAtlas {
+    /// Upload the atlas image to a texture
+    pub fn to_texture(&self) -> Texture2D {
+        return Texture2D::from_image(self.image);
+    }
+
+    pub fn rect(&self, name: &str) -> Option<Rectangle> {
+        return self.layout.rect(name);
+    }
+
+    pub fn unload(&self) {
+        self.image.unload();
+    }
+}
+
+/// Packs named images into a single atlas.
+///
+/// Images are only read during `build()`, they stay owned by the caller.
+#[derive(Debug, Clone)]
+pub struct TextureAtlasBuilder {
+    images: Vec<(String, Image)>,
+    algorithm: PackingAlgorithm,
+    padding: i32,
+    extrude: i32,
+    allow_rotation: bool,
+    power_of_two: bool,
+    max_size: i32,
+}
+
+impl Default for TextureAtlasBuilder {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl TextureAtlasBuilder {
+    pub fn new() -> Self {
+        return Self {
+            images: Vec::new(),
+            algorithm: PackingAlgorithm::MaxRects,
+            padding: 1,
+            extrude: 0,
+            allow_rotation: false,
+            power_of_two: false,
+            max_size: 4096,
+        };
+    }
+
+    /// Add an image, a later image with the same name replaces the earlier one
+    pub fn image(mut self, name: &str, image: Image) -> Self {
+        self.images.retain(|(existing, _)| existing != name);
+        self.images.push((name.to_string(), image));
+        return self;
+    }
+
+    pub fn algorithm(mut self, algorithm: PackingAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        return self;
+    }
+
+    /// Transparent pixels between images and around the atlas border
+    pub fn padding(mut self, pixels: i32) -> Self {
+        self.padding = pixels.max(0);
+        return self;
+    }
+
+    /// Repeat the edge pixels of each image outward, avoids bleeding when sampling with filtering
+    pub fn extrude(mut self, pixels: i32) -> Self {
+        self.extrude = pixels.max(0);
+        return self;
+    }
+
+    /// Allow rotating images 90 degrees clockwise when it packs tighter
+    pub fn allow_rotation(mut self, allow: bool) -> Self {
+        self.allow_rotation = allow;
+        return self;
+    }
+
+    /// Pad the atlas to power of two dimensions
+    pub fn power_of_two(mut self, enabled: bool) -> Self {
+        self.power_of_two = enabled;
+        return self;
+    }
+
+    /// Maximum atlas width and height
+    pub fn max_size(mut self, pixels: i32) -> Self {
+        self.max_size = pixels;
+        return self;
+    }
+
+    /// Pack the images, growing the atlas from the smallest square fitting their area up to the maximum size
+    pub fn build(self) -> Result<TextureAtlas, String> {
+        if self.images.is_empty() {
+            return Err("atlas: no images to pack".to_string());
+        }
+        let border = self.extrude * 2 + self.padding;
+        let mut cells = Vec::with_capacity(self.images.len());
+        for (index, (name, image)) in self.images.iter().enumerate() {
+            if !image.is_valid() {
+                return Err(format!("atlas: image \"{}\" is not valid", name));
+            }
+            cells.push((index, image.width + border, image.height + border));
+        }
+        // NOTE: Placing large images first packs tighter
+        cells.sort_by_key(|&(_, width, height)| std::cmp::Reverse((width.max(height), width.min(height))));
+
+        let area: i64 = cells
+            .iter()
+            .map(|&(_, width, height)| width as i64 * height as i64)
+            .sum();
+        let longest = cells
+            .iter()
+            .map(|&(_, width, height)| width.max(height))
+            .max()
+            .unwrap_or(0);
+        let start = ((area as f64).sqrt().ceil() as i32).max(longest) + self.padding;
+        let (mut width, mut height) = (start.min(self.max_size), start.min(self.max_size));
+        let placements = loop {
+            if let Some(placements) = self.pack(&cells, width, height) {
+                break placements;
+            }
+            if width >= self.max_size && height >= self.max_size {
+                return Err(format!("atlas: images do not fit in {0}x{0}", self.max_size));
+            }
+            let grow = |size: i32| (size + (size / 8).max(1)).min(self.max_size);
+            if (width <= height && width < self.max_size) || height >= self.max_size {
+                width = grow(width);
+            } else {
+                height = grow(height);
+            }
+        };
+
+        // Crop to the used area
+        let used_width = placements
+            .iter()
+            .map(|(_, rect, _)| rect.x + rect.width)
+            .max()
+            .unwrap_or(0);
+        let used_height = placements
+            .iter()
+            .map(|(_, rect, _)| rect.y + rect.height)
+            .max()
+            .unwrap_or(0);
+        let mut atlas = Image::gen_color(used_width + self.padding, used_height + self.padding, Color::BLANK);
+        if self.power_of_two {
+            atlas.pad_to_power_of_two(Color::BLANK);
+        }
+
+        let mut layout = AtlasLayout {
+            width: atlas.width,
+            height: atlas.height,
+            regions: BTreeMap::new(),
+        };
+        for (index, cell, rotated) in placements {
+            let (name, image) = &self.images[index];
+            let mut source = image.copy();
+            source.change_format(PixelFormat::UncompressedR8g8b8a8);
+            if rotated {
+                source.rotate_clockwise();
+            }
+            let x = cell.x + self.padding + self.extrude;
+            let y = cell.y + self.padding + self.extrude;
+            blit(&mut atlas, source, x, y, self.extrude);
+            let rect = Rectangle::new(x as f32, y as f32, source.width as f32, source.height as f32);
+            source.unload();
+            layout.regions.insert(name.clone(), AtlasRegion { rect, rotated });
+        }
+        return Ok(TextureAtlas { image: atlas, layout });
+    }
+
+    /// Place every cell in a `width`x`height` bin, returns `None` when they do not fit
+    fn pack(&self, cells: &[(usize, i32, i32)], width: i32, height: i32) -> Option<Vec<(usize, PackRect, bool)>> {
+        // NOTE: The leading padding is kept out of the bin, each cell carries its trailing padding
+        let (width, height) = (width - self.padding, height - self.padding);
+        let mut placements = Vec::with_capacity(cells.len());
+        match self.algorithm {
+            PackingAlgorithm::MaxRects => {
+                let mut bin = MaxRects::new(width, height);
+                for &(index, w, h) in cells {
+                    let (rect, rotated) = bin.insert(w, h, self.allow_rotation)?;
+                    placements.push((index, rect, rotated));
+                }
+            }
+            PackingAlgorithm::Skyline => {
+                let mut bin = Skyline::new(width, height);
+                for &(index, w, h) in cells {
+                    let (rect, rotated) = bin.insert(w, h, self.allow_rotation)?;
+                    placements.push((index, rect, rotated));
+                }
+            }
+        }
+        return Some(placements);
+    }
+}
+
+/// Draw `source` at `x`, `y` and repeat its edges `extrude` pixels outward
+fn blit(atlas: &mut Image, source: Image, x: i32, y: i32, extrude: i32) {
+    let (w, h) = (source.width as f32, source.height as f32);
+    let (x, y, e) = (x as f32, y as f32, extrude as f32);
+    let draw = |atlas: &mut Image, src: (f32, f32, f32, f32), dst: (f32, f32, f32, f32)| {
+        atlas.draw(source, src.into(), dst.into(), Color::WHITE);
+    };
+    draw(atlas, (0.0, 0.0, w, h), (x, y, w, h));
+    if extrude == 0 {
+        return;
+    }
+    // Edges
+    draw(atlas, (0.0, 0.0, w, 1.0), (x, y - e, w, e));
+    draw(atlas, (0.0, h - 1.0, w, 1.0), (x, y + h, w, e));
+    draw(atlas, (0.0, 0.0, 1.0, h), (x - e, y, e, h));
+    draw(atlas, (w - 1.0, 0.0, 1.0, h), (x + w, y, e, h));
+    // Corners
+    draw(atlas, (0.0, 0.0, 1.0, 1.0), (x - e, y - e, e, e));
+    draw(atlas, (w - 1.0, 0.0, 1.0, 1.0), (x + w, y - e, e, e));
+    draw(atlas, (0.0, h - 1.0, 1.0, 1.0), (x - e, y + h, e, e));
+    draw(atlas, (w - 1.0, h - 1.0, 1.0, 1.0), (x + w, y + h, e, e));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PackRect {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+impl PackRect {
+    fn right(&self) -> i32 {
+        return self.x + self.width;
+    }
+
+    fn bottom(&self) -> i32 {
+        return self.y + self.height;
+    }
+
+    fn intersects(&self, other: &PackRect) -> bool {
+        return self.x < other.right() && other.x < self.right() && self.y < other.bottom() && other.y < self.bottom();
+    }
+
+    fn contains(&self, other: &PackRect) -> bool {
+        return other.x >= self.x
+            && other.y >= self.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom();
+    }
+}
+
+/// Sizes to try for a `width`x`height` cell, the rotated one last
+fn orientations(width: i32, height: i32, allow_rotation: bool) -> impl Iterator<Item = (i32, i32, bool)> {
+    let rotated = (allow_rotation && width != height).then_some((height, width, true));
+    return std::iter::once((width, height, false)).chain(rotated);
+}
+
+/// Free space tracked as the list of maximal free rectangles
+struct MaxRects {
+    free: Vec<PackRect>,
+}
+
+impl MaxRects {
+    fn new(width: i32, height: i32) -> Self {
+        return Self {
+            free: vec![PackRect {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            }],
+        };
+    }
+
+    fn insert(&mut self, width: i32, height: i32, allow_rotation: bool) -> Option<(PackRect, bool)> {
+        let mut best: Option<(PackRect, bool, (i32, i32))> = None;
+        for free in &self.free {
+            for (w, h, rotated) in orientations(width, height, allow_rotation) {
+                if w > free.width || h > free.height {
+                    continue;
+                }
+                let (left_x, left_y) = (free.width - w, free.height - h);
+                let score = (left_x.min(left_y), left_x.max(left_y));
+                if best.is_none_or(|(_, _, best_score)| score < best_score) {
+                    let rect = PackRect {
+                        x: free.x,
+                        y: free.y,
+                        width: w,
+                        height: h,
+                    };
+                    best = Some((rect, rotated, score));
+                }
+            }
+        }
+        let (placed, rotated, _) = best?;
+
+        // Split every free rectangle overlapping the placed one into up to four maximal pieces
+        let mut free = Vec::with_capacity(self.free.len() + 4);
+        for rect in &self.free {
+            if !rect.intersects(&placed) {
+                free.push(*rect);
+                continue;
+            }
+            if placed.x > rect.x {
+                free.push(PackRect {
+                    width: placed.x - rect.x,
+                    ..*rect
+                });
+            }
+            if placed.right() < rect.right() {
+                free.push(PackRect {
+                    x: placed.right(),
+                    width: rect.right() - placed.right(),
+                    ..*rect
+                });
+            }
+            if placed.y > rect.y {
+                free.push(PackRect {
+                    height: placed.y - rect.y,
+                    ..*rect
+                });
+            }
+            if placed.bottom() < rect.bottom() {
+                free.push(PackRect {
+                    y: placed.bottom(),
+                    height: rect.bottom() - placed.bottom(),
+                    ..*rect
+                });
+            }
+        }
+
+        // Drop rectangles contained in another one
+        let mut i = 0;
+        while i < free.len() {
+            let contained =
+                (0..free.len()).any(|j| j != i && free[j].contains(&free[i]) && (free[j] != free[i] || j < i));
+            if contained {
+                free.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        self.free = free;
+        return Some((placed, rotated));
+    }
+}
+
+/// Skyline segment, `y` is the filled height from the top
+#[derive(Debug, Clone, Copy)]
+struct SkylineNode {
+    x: i32,
+    y: i32,
+    width: i32,
+}
+
+struct Skyline {
+    width: i32,
+    height: i32,
+    nodes: Vec<SkylineNode>,
+}
+
+impl Skyline {
+    fn new(width: i32, height: i32) -> Self {
+        return Self {
+            width,
+            height,
+            nodes: vec![SkylineNode { x: 0, y: 0, width }],
+        };
+    }
+
+    /// Top of a `width`x`height` cell whose left edge starts at node `index`
+    fn fit(&self, index: usize, width: i32, height: i32) -> Option<i32> {
+        let x = self.nodes[index].x;
+        if x + width > self.width {
+            return None;
+        }
+        let mut y = 0;
+        let mut remaining = width;
+        let mut i = index;
+        while remaining > 0 {
+            let node = self.nodes.get(i)?;
+            y = y.max(node.y);
+            if y + height > self.height {
+                return None;
+            }
+            remaining -= node.width;
+            i += 1;
+        }
+        return Some(y);
+    }
+
+    fn insert(&mut self, width: i32, height: i32, allow_rotation: bool) -> Option<(PackRect, bool)> {
+        let mut best: Option<(usize, PackRect, bool)> = None;
+        for index in 0..self.nodes.len() {
+            for (w, h, rotated) in orientations(width, height, allow_rotation) {
+                let Some(y) = self.fit(index, w, h) else {
+                    continue;
+                };
+                let rect = PackRect {
+                    x: self.nodes[index].x,
+                    y,
+                    width: w,
+                    height: h,
+                };
+                // Bottom-left: lowest bottom edge, then leftmost
+                if best.is_none_or(|(_, best_rect, _)| (rect.bottom(), rect.x) < (best_rect.bottom(), best_rect.x)) {
+                    best = Some((index, rect, rotated));
+                }
+            }
+        }
+        let (index, placed, rotated) = best?;
+
+        self.nodes.insert(
+            index,
+            SkylineNode {
+                x: placed.x,
+                y: placed.bottom(),
+                width: placed.width,
+            },
+        );
+        // Shrink or remove the nodes now covered by the new one
+        let i = index + 1;
+        while i < self.nodes.len() {
+            let previous_right = self.nodes[i - 1].x + self.nodes[i - 1].width;
+            if self.nodes[i].x >= previous_right {
+                break;
+            }
+            let shrink = previous_right - self.nodes[i].x;
+            self.nodes[i].x += shrink;
+            self.nodes[i].width -= shrink;
+            if self.nodes[i].width > 0 {
+                break;
+            }
+            self.nodes.remove(i);
+        }
+        // Merge neighbours at the same height
+        let mut i = 0;
+        while i + 1 < self.nodes.len() {
+            if self.nodes[i].y == self.nodes[i + 1].y {
+                self.nodes[i].width += self.nodes[i + 1].width;
+                self.nodes.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+        return Some((placed, rotated));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Images of varied sizes, each filled with its own color
+    fn images() -> Vec<(String, Image, Color)> {
+        let mut seed = 7u32;
+        let mut next = |range: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            return ((seed >> 16) % range) as i32;
+        };
+        return (0..24)
+            .map(|i| {
+                let color = Color::new(i * 10, 255 - i * 10, 100, 255);
+                let image = Image::gen_color(4 + next(40), 4 + next(24), color);
+                return (format!("image{}", i), image, color);
+            })
+            .collect();
+    }
+
+    fn build(algorithm: PackingAlgorithm, allow_rotation: bool, padding: i32, extrude: i32) -> TextureAtlas {
+        let images = images();
+        let mut builder = TextureAtlasBuilder::new()
+            .algorithm(algorithm)
+            .allow_rotation(allow_rotation)
+            .padding(padding)
+            .extrude(extrude)
+            .max_size(512);
+        for (name, image, _) in &images {
+            builder = builder.image(name, *image);
+        }
+        let atlas = builder.build().unwrap();
+
+        for (name, image, color) in &images {
+            let region = atlas.layout.get(name).unwrap();
+            let rect = region.rect;
+            let (width, height) = match region.rotated {
+                true => (image.height, image.width),
+                false => (image.width, image.height),
+            };
+            assert_eq!((rect.width as i32, rect.height as i32), (width, height), "{}", name);
+            assert!(allow_rotation || !region.rotated);
+            let center = atlas
+                .image
+                .get_color((rect.x + rect.width / 2.0) as i32, (rect.y + rect.height / 2.0) as i32);
+            assert_eq!(center, *color, "{}", name);
+            image.unload();
+        }
+        return atlas;
+    }
+
+    /// Region grown by `pixels` on every side, as (left, top, right, bottom)
+    fn grown(rect: Rectangle, pixels: i32) -> (i32, i32, i32, i32) {
+        let (x, y) = (rect.x as i32, rect.y as i32);
+        return (
+            x - pixels,
+            y - pixels,
+            x + rect.width as i32 + pixels,
+            y + rect.height as i32 + pixels,
+        );
+    }
+
+    fn check_layout(atlas: &TextureAtlas, padding: i32, extrude: i32) {
+        let layout = &atlas.layout;
+        assert_eq!((layout.width, layout.height), (atlas.image.width, atlas.image.height));
+
+        let regions: Vec<Rectangle> = layout.regions().map(|(_, region)| region.rect).collect();
+        for (i, &rect) in regions.iter().enumerate() {
+            // Extruded area with the padding around the atlas border
+            let (left, top, right, bottom) = grown(rect, extrude);
+            assert!(left >= padding && top >= padding, "{:?}", rect);
+            assert!(
+                right + padding <= layout.width && bottom + padding <= layout.height,
+                "{:?}",
+                rect
+            );
+
+            // Extruded areas are at least `padding` pixels apart
+            let (left, top, right, bottom) = grown(rect, extrude + padding);
+            for &other in &regions[i + 1..] {
+                let (other_left, other_top, other_right, other_bottom) = grown(other, extrude);
+                let overlaps = left < other_right && other_left < right && top < other_bottom && other_top < bottom;
+                assert!(
+                    !overlaps,
+                    "{:?} and {:?} are closer than {} pixels",
+                    rect, other, padding
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pack_without_overlaps() {
+        for algorithm in [PackingAlgorithm::MaxRects, PackingAlgorithm::Skyline] {
+            for allow_rotation in [false, true] {
+                for (padding, extrude) in [(0, 0), (1, 0), (2, 1), (3, 2)] {
+                    let atlas = build(algorithm, allow_rotation, padding, extrude);
+                    check_layout(&atlas, padding, extrude);
+                    atlas.image.unload();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn power_of_two_and_max_size() {
+        let image = Image::gen_color(33, 20, Color::RED);
+        let atlas = TextureAtlasBuilder::new()
+            .image("a", image)
+            .power_of_two(true)
+            .build()
+            .unwrap();
+        assert_eq!((atlas.image.width, atlas.image.height), (64, 32));
+        atlas.image.unload();
+
+        let error = TextureAtlasBuilder::new()
+            .image("a", image)
+            .image("b", image)
+            .max_size(40)
+            .build()
+            .unwrap_err();
+        assert_eq!(error, "atlas: images do not fit in 40x40");
+        image.unload();
+
+        assert!(TextureAtlasBuilder::new().build().is_err());
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let atlas = build(PackingAlgorithm::MaxRects, true, 1, 1);
+        let json = atlas.layout.to_json();
+        assert_eq!(AtlasLayout::from_json(&json), Ok(atlas.layout.clone()));
+        atlas.image.unload();
+
+        let mut layout = AtlasLayout {
+            width: 256,
+            height: 128,
+            ..AtlasLayout::default()
+        };
+        layout.insert(
+            "hero \"idle\"",
+            AtlasRegion {
+                rect: Rectangle::new(1.0, 2.0, 30.0, 40.0),
+                rotated: true,
+            },
+        );
+        layout.insert(
+            "tile/grass",
+            AtlasRegion {
+                rect: Rectangle::new(100.0, 0.0, 16.0, 16.0),
+                rotated: false,
+            },
+        );
+        assert_eq!(AtlasLayout::from_json(&layout.to_json()), Ok(layout));
+    }
+
+    #[test]
+    fn json_errors() {
+        assert_eq!(
+            AtlasLayout::from_json(r#"{"height": 4, "regions": {}}"#).unwrap_err(),
+            "atlas: missing or invalid \"width\""
+        );
+        let text = r#"{"width": 4, "height": 4, "regions": {"a": {"x": 0, "y": 0, "width": 2}}}"#;
+        assert_eq!(
+            AtlasLayout::from_json(text).unwrap_err(),
+            "atlas: missing or invalid \"height\""
+        );
+        assert!(AtlasLayout::from_json("[1, 2").is_err());
+    }
+}
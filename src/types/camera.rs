@@ -1,4 +1,4 @@
-use crate::{begin_mode_2d, begin_mode_3d, end_mode_2d, end_mode_3d, get_camera_matrix, get_camera_matrix_2d, get_screen_to_world_2d, get_screen_to_world_ray, get_screen_to_world_ray_ex, get_world_to_screen, get_world_to_screen_2d, get_world_to_screen_ex, update_camera, update_camera_pro, Matrix, Ray, Vector2, Vector3};
+use crate::{begin_mode_2d, begin_mode_3d, end_mode_2d, end_mode_3d, get_camera_matrix, get_camera_matrix_2d, get_screen_to_world_2d, get_screen_to_world_ray, get_screen_to_world_ray_ex, get_world_to_screen, get_world_to_screen_2d, get_world_to_screen_ex, update_camera, update_camera_pro, Matrix, Ray, Rectangle, Vector2, Vector3};
 
 /// Camera system modes
 #[repr(i32)]
@@ -75,6 +75,21 @@ impl Camera3D {
     pub fn matrix(&self) -> Matrix {
         return get_camera_matrix(*self);
     }
+
+    /// Projection matrix used by `begin_mode()` for a viewport aspect ratio (near 0.01, far 1000)
+    pub fn projection_matrix(&self, aspect: f32) -> Matrix {
+        const NEAR: f32 = 0.01;
+        const FAR: f32 = 1000.0;
+
+        match self.projection {
+            CameraProjection::Perspective => return Matrix::perspective(self.fov_y.to_radians(), aspect, NEAR, FAR),
+            CameraProjection::Orthographic => {
+                let top = self.fov_y / 2.0;
+                let right = top * aspect;
+                return Matrix::ortho(-right, right, -top, top, NEAR, FAR);
+            }
+        }
+    }
     
     pub fn update(&mut self, mode: CameraMode) {
         update_camera(self, mode);
@@ -122,4 +137,17 @@ impl Camera2D {
     pub fn matrix(&self) -> Matrix {
         return get_camera_matrix_2d(*self);
     }
+
+    /// World area visible in a `viewport` sized screen or render texture, the bounding box of the view when the
+    /// camera is rotated
+    pub fn visible_area(&self, viewport: Vector2) -> Rectangle {
+        let (width, height) = (viewport.x, viewport.y);
+        let corners = [(0.0, 0.0), (width, 0.0), (0.0, height), (width, height)]
+            .map(|(x, y)| self.screen_to_world(Vector2::new(x, y)));
+        let min_x = corners.iter().map(|corner| corner.x).fold(f32::MAX, f32::min);
+        let min_y = corners.iter().map(|corner| corner.y).fold(f32::MAX, f32::min);
+        let max_x = corners.iter().map(|corner| corner.x).fold(f32::MIN, f32::max);
+        let max_y = corners.iter().map(|corner| corner.y).fold(f32::MIN, f32::max);
+        return Rectangle::new(min_x, min_y, max_x - min_x, max_y - min_y);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,295 @@
+use crate::rlgl::{self, BlitMask, FramebufferAttachTextureType, FramebufferAttachType, FramebufferTarget};
+use crate::{PixelFormat, RenderTexture, RenderTexture2D, Texture, Texture2D, begin_texture_mode, end_texture_mode};
+
+/// Depth attachment of a framebuffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthAttachment {
+    None,
+    /// Depth renderbuffer, only usable for depth testing
+    Renderbuffer,
+    /// Depth texture that can be sampled after rendering (shadow maps, SSAO)
+    Texture,
+}
+
+/// Builder for framebuffers with several color attachments, a sampleable depth texture or multisampling
+#[derive(Debug, Clone)]
+pub struct FramebufferBuilder {
+    width: i32,
+    height: i32,
+    colors: Vec<PixelFormat>,
+    depth: DepthAttachment,
+    samples: i32,
+}
+
+impl FramebufferBuilder {
+    /// Framebuffer without color attachments and with a depth renderbuffer
+    pub fn new(width: i32, height: i32) -> Self {
+        return Self { width, height, colors: Vec::new(), depth: DepthAttachment::Renderbuffer, samples: 1 };
+    }
+
+    /// Add a color attachment (up to 8), attachments are written by the fragment shader outputs in order
+    pub fn color(mut self, format: PixelFormat) -> Self {
+        self.colors.push(format);
+        return self;
+    }
+
+    pub fn depth(mut self, depth: DepthAttachment) -> Self {
+        self.depth = depth;
+        return self;
+    }
+
+    /// Render with multisampling (MSAA), attachments are resolved into textures by `Framebuffer::resolve()`.
+    /// NOTE: Not supported on web (WebGL 1)
+    pub fn samples(mut self, samples: i32) -> Self {
+        self.samples = samples;
+        return self;
+    }
+
+    pub fn build(self) -> Result<Framebuffer, String> {
+        if self.width <= 0 || self.height <= 0 {
+            return Err(format!("framebuffer: invalid size {}x{}", self.width, self.height));
+        }
+        if self.colors.len() > 8 {
+            return Err(format!("framebuffer: {} color attachments requested, 8 supported", self.colors.len()));
+        }
+        if let Some(format) = self.colors.iter().find(|&&format| format as i32 >= PixelFormat::CompressedDxt1Rgb as i32) {
+            return Err(format!("framebuffer: compressed format {:?} cannot be rendered to", format));
+        }
+
+        let id = rlgl::load_framebuffer();
+        if id == 0 {
+            return Err("framebuffer: render textures are not supported".to_string());
+        }
+
+        // NOTE: Attachments are owned by the framebuffer as soon as they are created, errors drop it to clean up
+        let mut framebuffer = Framebuffer {
+            id,
+            width: self.width,
+            height: self.height,
+            colors: Vec::with_capacity(self.colors.len()),
+            depth: self.depth,
+            // NOTE: Depth textures have no matching pixel format, this one is never used to upload data
+            depth_texture: Texture2D(Texture {
+                id: 0,
+                width: self.width,
+                height: self.height,
+                mipmaps: 1,
+                format: PixelFormat::UncompressedR32,
+            }),
+            multisample: None,
+        };
+
+        for (index, &format) in self.colors.iter().enumerate() {
+            let texture_id = rlgl::load_texture(None, self.width, self.height, format, 1);
+            if texture_id == 0 {
+                return Err(format!("framebuffer: could not create a {:?} color attachment", format));
+            }
+            framebuffer.colors.push(Texture2D(Texture {
+                id: texture_id,
+                width: self.width,
+                height: self.height,
+                mipmaps: 1,
+                format,
+            }));
+            rlgl::framebuffer_attach(id, texture_id, color_channel(index), FramebufferAttachTextureType::Texture2D, 0);
+        }
+
+        if self.depth != DepthAttachment::None {
+            let renderbuffer = self.depth == DepthAttachment::Renderbuffer;
+            framebuffer.depth_texture.0.id = rlgl::load_texture_depth(self.width, self.height, renderbuffer);
+            let texture_type = match renderbuffer {
+                true => FramebufferAttachTextureType::Renderbuffer,
+                false => FramebufferAttachTextureType::Texture2D,
+            };
+            rlgl::framebuffer_attach(id, framebuffer.depth_texture.0.id, FramebufferAttachType::Depth, texture_type, 0);
+        }
+
+        set_draw_buffers(id, self.colors.len());
+        if !rlgl::framebuffer_complete(id) {
+            return Err("framebuffer: incomplete, attachment formats are not supported".to_string());
+        }
+
+        if self.samples > 1 {
+            framebuffer.multisample = Some(self.build_multisample()?);
+        }
+        return Ok(framebuffer);
+    }
+
+    /// Multisampled framebuffer rendered to, with renderbuffers matching the color and depth attachments
+    fn build_multisample(&self) -> Result<Multisample, String> {
+        let id = rlgl::load_framebuffer();
+        if id == 0 {
+            return Err("framebuffer: render textures are not supported".to_string());
+        }
+
+        let mut multisample = Multisample { id, samples: self.samples, renderbuffers: Vec::new() };
+        for (index, &format) in self.colors.iter().enumerate() {
+            let (internal_format, _, _) = rlgl::get_gl_texture_formats(format);
+            let renderbuffer = rlgl::load_renderbuffer_multisample(self.width, self.height, internal_format, self.samples);
+            if renderbuffer == 0 {
+                return Err("framebuffer: multisampling is not supported".to_string());
+            }
+            multisample.renderbuffers.push(renderbuffer);
+            rlgl::framebuffer_attach(id, renderbuffer, color_channel(index), FramebufferAttachTextureType::Renderbuffer, 0);
+        }
+
+        if self.depth != DepthAttachment::None {
+            let renderbuffer =
+                rlgl::load_renderbuffer_multisample(self.width, self.height, rlgl::GL_DEPTH_COMPONENT24, self.samples);
+            if renderbuffer == 0 {
+                return Err("framebuffer: multisampling is not supported".to_string());
+            }
+            rlgl::framebuffer_attach(
+                id,
+                renderbuffer,
+                FramebufferAttachType::Depth,
+                FramebufferAttachTextureType::Renderbuffer,
+                0,
+            );
+        }
+
+        set_draw_buffers(id, self.colors.len());
+        if !rlgl::framebuffer_complete(id) {
+            return Err(format!("framebuffer: incomplete with {} samples", self.samples));
+        }
+        return Ok(multisample);
+    }
+}
+
+/// Multisampled render target resolved into the framebuffer textures
+#[derive(Debug)]
+struct Multisample {
+    id: u32,
+    samples: i32,
+    /// Color renderbuffers (the depth renderbuffer is unloaded with the framebuffer)
+    renderbuffers: Vec<u32>,
+}
+
+/// Framebuffer object with its attachments, created by `FramebufferBuilder`.
+/// Attachment textures are owned by the framebuffer and unloaded on drop.
+#[derive(Debug)]
+pub struct Framebuffer {
+    id: u32,
+    width: i32,
+    height: i32,
+    colors: Vec<Texture2D>,
+    depth: DepthAttachment,
+    /// Depth texture or renderbuffer id
+    depth_texture: Texture2D,
+    multisample: Option<Multisample>,
+}
+
+impl Framebuffer {
+    /// OpenGL framebuffer object id (the resolve target when multisampled)
+    pub fn id(&self) -> u32 {
+        return self.id;
+    }
+
+    pub fn width(&self) -> i32 {
+        return self.width;
+    }
+
+    pub fn height(&self) -> i32 {
+        return self.height;
+    }
+
+    /// Number of samples per pixel, 1 without multisampling
+    pub fn samples(&self) -> i32 {
+        return self.multisample.as_ref().map_or(1, |multisample| multisample.samples);
+    }
+
+    pub fn color(&self, index: usize) -> &Texture2D {
+        return &self.colors[index];
+    }
+
+    pub fn colors(&self) -> &[Texture2D] {
+        return &self.colors;
+    }
+
+    /// Depth texture, `None` unless built with `DepthAttachment::Texture`
+    pub fn depth(&self) -> Option<&Texture2D> {
+        return (self.depth == DepthAttachment::Texture).then_some(&self.depth_texture);
+    }
+
+    /// Render texture to draw into with `begin_texture_mode()`, the multisampled target if any
+    pub fn render_texture(&self) -> RenderTexture2D {
+        // NOTE: Depth only framebuffers still need the size in the color texture for the viewport
+        let color = self.colors.first().map_or(Texture { id: 0, ..self.depth_texture.0 }, |color| color.0);
+        let id = self.multisample.as_ref().map_or(self.id, |multisample| multisample.id);
+        return RenderTexture2D(RenderTexture { id, texture: color, depth: self.depth_texture.0 });
+    }
+
+    /// Begin drawing to the framebuffer
+    pub fn begin(&self) {
+        begin_texture_mode(self.render_texture());
+    }
+
+    /// End drawing to the framebuffer and resolve multisampling
+    pub fn end(&self) {
+        end_texture_mode();
+        self.resolve();
+    }
+
+    /// Copy the multisampled attachments into the framebuffer textures (done by `end()`)
+    pub fn resolve(&self) {
+        let Some(multisample) = &self.multisample else {
+            return;
+        };
+
+        rlgl::draw_render_batch_active();
+        for index in 0..self.colors.len() {
+            rlgl::blit_color_attachment(multisample.id, self.id, index as u32, self.width, self.height);
+        }
+        if self.depth == DepthAttachment::Texture {
+            // NOTE: Requires the depth texture format to match the 24-bit multisampled depth
+            rlgl::bind_framebuffer(FramebufferTarget::Read, multisample.id);
+            rlgl::bind_framebuffer(FramebufferTarget::Draw, self.id);
+            let area = (0, 0, self.width, self.height);
+            rlgl::blit_framebuffer(area, area, BlitMask::DEPTH);
+        }
+        set_draw_buffers(self.id, self.colors.len());
+    }
+}
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        for color in &self.colors {
+            rlgl::unload_texture(color.0.id);
+        }
+        // NOTE: Unloading a framebuffer also unloads its depth texture/renderbuffer
+        rlgl::unload_framebuffer(self.id);
+    }
+}
+
+impl Drop for Multisample {
+    fn drop(&mut self) {
+        for &renderbuffer in &self.renderbuffers {
+            rlgl::unload_renderbuffer(renderbuffer);
+        }
+        rlgl::unload_framebuffer(self.id);
+    }
+}
+
+fn color_channel(index: usize) -> FramebufferAttachType {
+    match index {
+        0 => return FramebufferAttachType::ColorChannel0,
+        1 => return FramebufferAttachType::ColorChannel1,
+        2 => return FramebufferAttachType::ColorChannel2,
+        3 => return FramebufferAttachType::ColorChannel3,
+        4 => return FramebufferAttachType::ColorChannel4,
+        5 => return FramebufferAttachType::ColorChannel5,
+        6 => return FramebufferAttachType::ColorChannel6,
+        _ => return FramebufferAttachType::ColorChannel7,
+    }
+}
+
+/// Enable the color attachments of a framebuffer as draw buffers (none for depth only framebuffers)
+fn set_draw_buffers(id: u32, count: usize) {
+    if count == 0 {
+        rlgl::disable_color_buffers(id);
+        return;
+    }
+    rlgl::enable_framebuffer(id);
+    rlgl::active_draw_buffers(count as i32);
+    rlgl::disable_framebuffer();
+}
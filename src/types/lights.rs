@@ -0,0 +1,564 @@
+use crate::{
+    Camera3D, Color, Material, MaterialMapIndex, SHADER_FS_HEADER, SHADER_VS_HEADER, Shader, ShaderLocation,
+    ShaderLocationIndex, ShaderUniformDataType, Vector2, Vector3, Vector4, color_normalize,
+};
+
+const LIT_VS: &str = r#"
+ATTRIBUTE vec3 vertexPosition;
+ATTRIBUTE vec2 vertexTexCoord;
+ATTRIBUTE vec3 vertexNormal;
+ATTRIBUTE vec4 vertexTangent;
+ATTRIBUTE vec4 vertexColor;
+
+uniform mat4 mvp;
+uniform mat4 matModel;
+uniform mat4 matNormal;
+
+VARYING vec3 fragPosition;
+VARYING vec2 fragTexCoord;
+VARYING vec4 fragColor;
+VARYING vec3 fragNormal;
+VARYING vec3 fragTangent;
+VARYING float fragTangentSign;
+
+void main()
+{
+    fragPosition = vec3(matModel*vec4(vertexPosition, 1.0));
+    fragTexCoord = vertexTexCoord;
+    fragColor = vertexColor;
+    fragNormal = normalize(vec3(matNormal*vec4(vertexNormal, 0.0)));
+    fragTangent = vec3(matModel*vec4(vertexTangent.xyz, 0.0));
+    fragTangentSign = (vertexTangent.w < 0.0)? -1.0 : 1.0;
+    gl_Position = mvp*vec4(vertexPosition, 1.0);
+}
+"#;
+
+/// Inputs and light helpers shared by the lit fragment shaders
+const LIT_FS_COMMON: &str = r#"
+VARYING vec3 fragPosition;
+VARYING vec2 fragTexCoord;
+VARYING vec4 fragColor;
+VARYING vec3 fragNormal;
+VARYING vec3 fragTangent;
+VARYING float fragTangentSign;
+
+uniform sampler2D texture0;
+uniform sampler2D texture1;
+uniform sampler2D texture2;
+uniform vec4 colDiffuse;
+uniform vec4 ambient;
+uniform vec3 viewPos;
+uniform int useNormalMap;
+
+uniform int lightCount;
+uniform int lightTypes[MAX_LIGHTS];
+uniform vec3 lightPositions[MAX_LIGHTS];
+uniform vec3 lightDirections[MAX_LIGHTS];
+uniform vec4 lightColors[MAX_LIGHTS];
+uniform float lightRanges[MAX_LIGHTS];
+uniform vec2 lightCones[MAX_LIGHTS];
+
+// Surface normal, perturbed by the normal map when the mesh has tangents
+vec3 surfaceNormal()
+{
+    vec3 normal = normalize(fragNormal);
+    if ((useNormalMap == 1) && (dot(fragTangent, fragTangent) > 0.0))
+    {
+        vec3 tangent = normalize(fragTangent - normal*dot(normal, fragTangent));
+        vec3 bitangent = cross(normal, tangent)*fragTangentSign;
+        vec3 mapped = TEXTURE(texture2, fragTexCoord).rgb*2.0 - 1.0;
+        normal = normalize(mat3(tangent, bitangent, normal)*mapped);
+    }
+    return normal;
+}
+
+// Attenuation of a light at the fragment, outputs the direction to the light
+float lightAttenuation(int type, vec3 position, vec3 direction, float range, vec2 cone, out vec3 toLight)
+{
+    if (type == 0)
+    {
+        toLight = -normalize(direction);
+        return 1.0;
+    }
+
+    vec3 offset = position - fragPosition;
+    float dist = length(offset);
+    toLight = offset/max(dist, 0.0001);
+
+    // Inverse square falloff windowed to reach zero at the light range
+    float window = clamp(1.0 - pow(dist/max(range, 0.0001), 4.0), 0.0, 1.0);
+    float attenuation = window*window/(dist*dist + 1.0);
+    if (type == 2) attenuation *= smoothstep(cone.y, cone.x, dot(-toLight, normalize(direction)));
+    return attenuation;
+}
+"#;
+
+const BLINN_PHONG_FS: &str = r#"
+uniform vec4 colSpecular;
+uniform float shininess;
+uniform int useSpecularMap;
+
+void main()
+{
+    vec4 albedo = TEXTURE(texture0, fragTexCoord)*colDiffuse*fragColor;
+    vec3 specularColor = colSpecular.rgb;
+    if (useSpecularMap == 1) specularColor *= TEXTURE(texture1, fragTexCoord).rgb;
+
+    vec3 normal = surfaceNormal();
+    vec3 viewDirection = normalize(viewPos - fragPosition);
+    vec3 diffuse = vec3(0.0);
+    vec3 specular = vec3(0.0);
+
+    for (int i = 0; i < MAX_LIGHTS; i++)
+    {
+        if (i >= lightCount) break;
+
+        vec3 toLight;
+        float attenuation = lightAttenuation(lightTypes[i], lightPositions[i], lightDirections[i], lightRanges[i], lightCones[i], toLight);
+        vec3 radiance = lightColors[i].rgb*attenuation;
+        float NdotL = max(dot(normal, toLight), 0.0);
+
+        diffuse += radiance*NdotL;
+        if (NdotL > 0.0) specular += radiance*pow(max(dot(normal, normalize(toLight + viewDirection)), 0.0), shininess);
+    }
+
+    OUTPUT = vec4(albedo.rgb*(ambient.rgb + diffuse) + specularColor*specular, albedo.a);
+}
+"#;
+
+const PBR_FS: &str = r#"
+uniform sampler2D roughnessMap;
+uniform sampler2D occlusionMap;
+uniform sampler2D emissionMap;
+
+uniform float metalnessValue;
+uniform float roughnessValue;
+uniform float occlusionValue;
+uniform vec4 emissiveColor;
+uniform int useMetalnessMap;
+uniform int useRoughnessMap;
+uniform int useOcclusionMap;
+uniform int useEmissionMap;
+
+uniform samplerCube irradianceMap;
+uniform samplerCube prefilterMap;
+uniform sampler2D brdfLut;
+uniform float prefilterMaxLod;
+uniform int useIbl;
+
+const float PI = 3.14159265358979;
+
+float distributionGGX(float NdotH, float roughness)
+{
+    float a = roughness*roughness;
+    float a2 = a*a;
+    float d = NdotH*NdotH*(a2 - 1.0) + 1.0;
+    return a2/(PI*d*d);
+}
+
+float geometrySmith(float NdotV, float NdotL, float roughness)
+{
+    float k = (roughness + 1.0)*(roughness + 1.0)/8.0;
+    return (NdotV/(NdotV*(1.0 - k) + k))*(NdotL/(NdotL*(1.0 - k) + k));
+}
+
+vec3 fresnelSchlick(float cosTheta, vec3 f0)
+{
+    return f0 + (1.0 - f0)*pow(1.0 - cosTheta, 5.0);
+}
+
+vec3 fresnelSchlickRoughness(float cosTheta, vec3 f0, float roughness)
+{
+    return f0 + (max(vec3(1.0 - roughness), f0) - f0)*pow(1.0 - cosTheta, 5.0);
+}
+
+void main()
+{
+    vec4 albedo = TEXTURE(texture0, fragTexCoord)*colDiffuse*fragColor;
+
+    // Metalness in blue and roughness in green, as packed by glTF
+    float metalness = metalnessValue;
+    if (useMetalnessMap == 1) metalness *= TEXTURE(texture1, fragTexCoord).b;
+    float roughness = roughnessValue;
+    if (useRoughnessMap == 1) roughness *= TEXTURE(roughnessMap, fragTexCoord).g;
+    roughness = clamp(roughness, 0.04, 1.0);
+    float occlusion = occlusionValue;
+    if (useOcclusionMap == 1) occlusion *= TEXTURE(occlusionMap, fragTexCoord).r;
+    vec3 emission = emissiveColor.rgb;
+    if (useEmissionMap == 1) emission *= TEXTURE(emissionMap, fragTexCoord).rgb;
+
+    vec3 normal = surfaceNormal();
+    vec3 viewDirection = normalize(viewPos - fragPosition);
+    float NdotV = max(dot(normal, viewDirection), 0.0001);
+    vec3 f0 = mix(vec3(0.04), albedo.rgb, metalness);
+    vec3 light = vec3(0.0);
+
+    for (int i = 0; i < MAX_LIGHTS; i++)
+    {
+        if (i >= lightCount) break;
+
+        vec3 toLight;
+        float attenuation = lightAttenuation(lightTypes[i], lightPositions[i], lightDirections[i], lightRanges[i], lightCones[i], toLight);
+        float NdotL = max(dot(normal, toLight), 0.0);
+        if (NdotL <= 0.0) continue;
+
+        vec3 halfway = normalize(toLight + viewDirection);
+        vec3 fresnel = fresnelSchlick(max(dot(halfway, viewDirection), 0.0), f0);
+        vec3 specular = distributionGGX(max(dot(normal, halfway), 0.0), roughness)*geometrySmith(NdotV, NdotL, roughness)*fresnel/(4.0*NdotV*NdotL + 0.0001);
+        vec3 diffuse = (1.0 - fresnel)*(1.0 - metalness)*albedo.rgb/PI;
+
+        // NOTE: Light colors are scaled by PI so intensities match the Blinn-Phong shader
+        light += (diffuse + specular)*lightColors[i].rgb*attenuation*NdotL*PI;
+    }
+
+    // Ambient light, from the environment when image-based lighting maps are set
+    vec3 ambientLight = ambient.rgb*albedo.rgb;
+    if (useIbl == 1)
+    {
+        vec3 fresnel = fresnelSchlickRoughness(NdotV, f0, roughness);
+        vec3 diffuse = (1.0 - fresnel)*(1.0 - metalness)*TEXTURE_CUBE(irradianceMap, normal).rgb*albedo.rgb;
+        vec3 reflected = TEXTURE_CUBE_LOD(prefilterMap, reflect(-viewDirection, normal), roughness*prefilterMaxLod).rgb;
+        vec2 brdf = TEXTURE(brdfLut, vec2(NdotV, roughness)).rg;
+        ambientLight = diffuse + reflected*(fresnel*brdf.x + brdf.y);
+    }
+
+    OUTPUT = vec4(ambientLight*occlusion + light + emission, albedo.a);
+}
+"#;
+
+/// Light type
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightType {
+    /// Light shining along a direction from infinitely far away (sun)
+    Directional = 0,
+    /// Light shining in every direction from a position
+    Point,
+    /// Light shining in a cone from a position
+    Spot,
+}
+
+/// Light source used by the built-in lit shaders
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Light {
+    pub light_type: LightType,
+    /// Disabled lights are not uploaded
+    pub enabled: bool,
+    /// Position of point and spot lights
+    pub position: Vector3,
+    /// Direction of directional and spot lights
+    pub direction: Vector3,
+    pub color: Color,
+    /// Color multiplier
+    pub intensity: f32,
+    /// Distance at which point and spot lights fade out completely
+    pub range: f32,
+    /// Spot cone half angle with full intensity in degrees
+    pub inner_angle: f32,
+    /// Spot cone half angle in degrees
+    pub outer_angle: f32,
+}
+
+impl Light {
+    pub fn directional(direction: Vector3, color: Color) -> Self {
+        return Self {
+            light_type: LightType::Directional,
+            enabled: true,
+            position: Vector3::new(0.0, 0.0, 0.0),
+            direction,
+            color,
+            intensity: 1.0,
+            range: 0.0,
+            inner_angle: 0.0,
+            outer_angle: 0.0,
+        };
+    }
+
+    pub fn point(position: Vector3, color: Color, range: f32) -> Self {
+        return Self {
+            light_type: LightType::Point,
+            position,
+            range,
+            ..Self::directional(Vector3::new(0.0, -1.0, 0.0), color)
+        };
+    }
+
+    /// Spot light fading from 80% of `angle` to the cone border
+    pub fn spot(position: Vector3, direction: Vector3, angle: f32, color: Color, range: f32) -> Self {
+        return Self {
+            light_type: LightType::Spot,
+            position,
+            range,
+            inner_angle: angle * 0.8,
+            outer_angle: angle,
+            ..Self::directional(direction, color)
+        };
+    }
+}
+
+/// Lighting model of the built-in lit shaders
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightingModel {
+    /// Blinn-Phong: albedo map and color, specular map and color (`MaterialMapIndex::Specular`),
+    /// shininess from the specular map value (32 if not set), normal map
+    BlinnPhong,
+    /// Metallic-roughness PBR: albedo, metalness, roughness, normal, occlusion and emission maps,
+    /// with the map values and colors set by `PbrMaterialBuilder`.
+    /// Image-based ambient lighting when the irradiance, prefilter and BRDF maps are set (`ImageBasedLighting`)
+    Pbr,
+}
+
+/// Set of lights uploaded to lit shaders.
+///
+/// Shaders generated by `load_shader()` read at most `max_lights` lights, the light uniform arrays,
+/// the ambient color (`ShaderLocationIndex::ColorAmbient`) and the camera position
+/// (`ShaderLocationIndex::VectorView`) are uploaded by `update()` every frame.
+#[derive(Debug, Clone)]
+pub struct Lights {
+    lights: Vec<Light>,
+    max_lights: usize,
+    /// Ambient light color
+    pub ambient: Color,
+}
+
+impl Lights {
+    pub const DEFAULT_MAX_LIGHTS: usize = 8;
+
+    pub fn new(max_lights: usize) -> Self {
+        assert!(max_lights > 0, "lights: at least one light is required");
+        return Self {
+            lights: Vec::new(),
+            max_lights,
+            ambient: Color::new(30, 30, 30, 255),
+        };
+    }
+
+    pub fn max_lights(&self) -> usize {
+        return self.max_lights;
+    }
+
+    /// Add a light, returns its index
+    pub fn add(&mut self, light: Light) -> Result<usize, String> {
+        if self.lights.len() >= self.max_lights {
+            return Err(format!("lights: limit of {} lights reached", self.max_lights));
+        }
+        self.lights.push(light);
+        return Ok(self.lights.len() - 1);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Light {
+        return self.lights.remove(index);
+    }
+
+    pub fn clear(&mut self) {
+        self.lights.clear();
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Light> {
+        return self.lights.get(index);
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Light> {
+        return self.lights.get_mut(index);
+    }
+
+    pub fn lights(&self) -> &[Light] {
+        return &self.lights;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.lights.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.lights.is_empty();
+    }
+
+    /// Generate and load a lit shader reading up to `max_lights` lights
+    pub fn load_shader(&self, model: LightingModel) -> Shader {
+        let body = match model {
+            LightingModel::BlinnPhong => BLINN_PHONG_FS,
+            LightingModel::Pbr => PBR_FS,
+        };
+        let vs_code = format!("{}{}", SHADER_VS_HEADER, LIT_VS);
+        let fs_code = format!(
+            "{}#define MAX_LIGHTS {}\n{}{}",
+            SHADER_FS_HEADER, self.max_lights, LIT_FS_COMMON, body
+        );
+        let mut shader = Shader::from_memory(&vs_code, &fs_code);
+
+        let locations = [
+            (ShaderLocationIndex::VectorView, "viewPos"),
+            (ShaderLocationIndex::ColorSpecular, "colSpecular"),
+            (ShaderLocationIndex::ColorAmbient, "ambient"),
+            (ShaderLocationIndex::MapRoughness, "roughnessMap"),
+            (ShaderLocationIndex::MapOcclusion, "occlusionMap"),
+            (ShaderLocationIndex::MapEmission, "emissionMap"),
+            (ShaderLocationIndex::MapIrradiance, "irradianceMap"),
+            (ShaderLocationIndex::MapPrefilter, "prefilterMap"),
+            (ShaderLocationIndex::MapBrdf, "brdfLut"),
+        ];
+        for (index, name) in locations {
+            let location = shader.uniform_location(name);
+            shader.set_location_index(index, location);
+        }
+
+        // NOTE: Samplers are bound to the texture unit of their material map up front,
+        // 2d and cube samplers must not share a unit even when the map is not set
+        let samplers = [
+            (ShaderLocationIndex::MapAlbedo, MaterialMapIndex::Albedo),
+            (ShaderLocationIndex::MapMetalness, MaterialMapIndex::Metalness),
+            (ShaderLocationIndex::MapNormal, MaterialMapIndex::Normal),
+            (ShaderLocationIndex::MapRoughness, MaterialMapIndex::Roughness),
+            (ShaderLocationIndex::MapOcclusion, MaterialMapIndex::Occlusion),
+            (ShaderLocationIndex::MapEmission, MaterialMapIndex::Emission),
+            (ShaderLocationIndex::MapIrradiance, MaterialMapIndex::Irradiance),
+            (ShaderLocationIndex::MapPrefilter, MaterialMapIndex::Prefilter),
+            (ShaderLocationIndex::MapBrdf, MaterialMapIndex::Brdf),
+        ];
+        for (location, map) in samplers {
+            set(
+                &shader,
+                shader.location_index(location),
+                &(map as i32),
+                ShaderUniformDataType::Int,
+            );
+        }
+        return shader;
+    }
+
+    /// Upload the enabled lights, the ambient color and the camera position, call every frame before drawing
+    pub fn update(&self, shader: &Shader, camera: &Camera3D) {
+        let lights: Vec<&Light> = self
+            .lights
+            .iter()
+            .filter(|light| light.enabled)
+            .take(self.max_lights)
+            .collect();
+
+        let types: Vec<i32> = lights.iter().map(|light| light.light_type as i32).collect();
+        let positions: Vec<Vector3> = lights.iter().map(|light| light.position).collect();
+        let directions: Vec<Vector3> = lights.iter().map(|light| light.direction).collect();
+        let colors: Vec<Vector4> = lights
+            .iter()
+            .map(|light| {
+                let color = color_normalize(light.color);
+                return Vector4::new(
+                    color.x * light.intensity,
+                    color.y * light.intensity,
+                    color.z * light.intensity,
+                    1.0,
+                );
+            })
+            .collect();
+        let ranges: Vec<f32> = lights.iter().map(|light| light.range).collect();
+        let cones: Vec<Vector2> = lights
+            .iter()
+            .map(|light| {
+                Vector2::new(
+                    light.inner_angle.to_radians().cos(),
+                    light.outer_angle.to_radians().cos(),
+                )
+            })
+            .collect();
+
+        set(
+            shader,
+            shader.uniform_location("lightCount"),
+            &(lights.len() as i32),
+            ShaderUniformDataType::Int,
+        );
+        if !lights.is_empty() {
+            set_array(shader, "lightTypes", &types, ShaderUniformDataType::Int);
+            set_array(shader, "lightPositions", &positions, ShaderUniformDataType::Vec3);
+            set_array(shader, "lightDirections", &directions, ShaderUniformDataType::Vec3);
+            set_array(shader, "lightColors", &colors, ShaderUniformDataType::Vec4);
+            set_array(shader, "lightRanges", &ranges, ShaderUniformDataType::Float);
+            set_array(shader, "lightCones", &cones, ShaderUniformDataType::Vec2);
+        }
+
+        let ambient = color_normalize(self.ambient);
+        set(
+            shader,
+            shader.location_index(ShaderLocationIndex::ColorAmbient),
+            &ambient,
+            ShaderUniformDataType::Vec4,
+        );
+        set(
+            shader,
+            shader.location_index(ShaderLocationIndex::VectorView),
+            &camera.position,
+            ShaderUniformDataType::Vec3,
+        );
+    }
+
+    /// Upload the material map values and which maps have a texture, call before drawing meshes with the material.
+    /// NOTE: Uniforms are stored in the shader program, materials sharing a shader need it before each draw
+    pub fn apply_material(material: &Material) {
+        let shader = &material.shader;
+        let has_texture = |index: MaterialMapIndex| -> i32 { return material.texture(index).is_some_and(|texture| texture.0.id != 0) as i32 };
+        let shininess = material.value(MaterialMapIndex::Specular).unwrap_or(0.0);
+
+        let flags = [
+            ("useNormalMap", has_texture(MaterialMapIndex::Normal)),
+            ("useSpecularMap", has_texture(MaterialMapIndex::Specular)),
+            ("useMetalnessMap", has_texture(MaterialMapIndex::Metalness)),
+            ("useRoughnessMap", has_texture(MaterialMapIndex::Roughness)),
+            ("useOcclusionMap", has_texture(MaterialMapIndex::Occlusion)),
+            ("useEmissionMap", has_texture(MaterialMapIndex::Emission)),
+            (
+                "useIbl",
+                has_texture(MaterialMapIndex::Irradiance)
+                    & has_texture(MaterialMapIndex::Prefilter)
+                    & has_texture(MaterialMapIndex::Brdf),
+            ),
+        ];
+        for (name, flag) in flags {
+            set(shader, shader.uniform_location(name), &flag, ShaderUniformDataType::Int);
+        }
+
+        let values = [
+            ("shininess", if shininess > 0.0 { shininess } else { 32.0 }),
+            ("metalnessValue", material.value(MaterialMapIndex::Metalness).unwrap_or(0.0)),
+            ("roughnessValue", material.value(MaterialMapIndex::Roughness).unwrap_or(0.0)),
+            ("occlusionValue", material.value(MaterialMapIndex::Occlusion).unwrap_or(0.0)),
+            ("prefilterMaxLod", material.value(MaterialMapIndex::Prefilter).unwrap_or(0.0)),
+        ];
+        for (name, value) in values {
+            set(
+                shader,
+                shader.uniform_location(name),
+                &value,
+                ShaderUniformDataType::Float,
+            );
+        }
+
+        let emission = color_normalize(material.color(MaterialMapIndex::Emission).unwrap_or(Color::BLACK));
+        set(
+            shader,
+            shader.uniform_location("emissiveColor"),
+            &emission,
+            ShaderUniformDataType::Vec4,
+        );
+    }
+}
+
+impl Default for Lights {
+    fn default() -> Self {
+        return Self::new(Self::DEFAULT_MAX_LIGHTS);
+    }
+}
+
+/// Set a uniform if the shader uses it
+fn set<T>(shader: &Shader, location: ShaderLocation, value: &T, uniform_type: ShaderUniformDataType) {
+    if location.is_valid() {
+        shader.set(location, value, uniform_type);
+    }
+}
+
+fn set_array<T>(shader: &Shader, name: &str, values: &[T], uniform_type: ShaderUniformDataType) {
+    let location = shader.uniform_location(name);
+    if location.is_valid() {
+        shader.set_vector(location, values, uniform_type);
+    }
+}
@@ -0,0 +1,411 @@
+use crate::{
+    Color, PixelFormat, Shader, Texture, Texture2D, is_material_valid, load_material_default, load_materials,
+    set_material_texture, unload_material,
+};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::{ptr, slice};
+
+/// Maximum number of material maps per material (MAX_MATERIAL_MAPS)
+pub const MAX_MATERIAL_MAPS: usize = 12;
+
+/// Number of material maps used by raylib, one per `MaterialMapIndex`
+const MATERIAL_MAP_COUNT: usize = MaterialMapIndex::Brdf as usize + 1;
+
+/// Material map index
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaterialMapIndex {
+    /// Albedo material (same as: MATERIAL_MAP_DIFFUSE)
+    Albedo = 0,
+    /// Metalness material (same as: MATERIAL_MAP_SPECULAR)
+    Metalness,
+    /// Normal material
+    Normal,
+    /// Roughness material
+    Roughness,
+    /// Ambient occlusion material
+    Occlusion,
+    /// Emission material
+    Emission,
+    /// Heightmap material
+    Height,
+    /// Cubemap material (NOTE: Uses GL_TEXTURE_CUBE_MAP)
+    Cubemap,
+    /// Irradiance material (NOTE: Uses GL_TEXTURE_CUBE_MAP)
+    Irradiance,
+    /// Prefilter material (NOTE: Uses GL_TEXTURE_CUBE_MAP)
+    Prefilter,
+    /// Brdf material
+    Brdf,
+}
+
+impl MaterialMapIndex {
+    #[allow(non_upper_case_globals)]
+    pub const Diffuse: Self = Self::Albedo;
+    #[allow(non_upper_case_globals)]
+    pub const Specular: Self = Self::Metalness;
+}
+
+/// MaterialMap
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct MaterialMap {
+    /// Material map texture
+    pub texture: Texture2D,
+    /// Material map color
+    pub color: Color,
+    /// Material map value
+    pub value: f32,
+}
+
+/// Material, includes shader and maps
+#[repr(C)]
+#[derive(Debug)]
+pub struct Material {
+    /// Material shader
+    pub shader: Shader,
+    /// Material maps array
+    pub maps: *mut MaterialMap,
+    /// Material generic parameters (if required)
+    pub params: [f32; 4],
+}
+
+impl Material {
+    // Loading
+
+    pub fn load_default() -> Material {
+        return load_material_default();
+    }
+
+    pub fn load(filename: &str) -> Vec<Material> {
+        return load_materials(filename);
+    }
+
+    pub fn is_valid(&self) -> bool {
+        return is_material_valid(self.shallow_copy());
+    }
+
+    /// Unload the material, its shader and the map textures it owns.
+    /// Textures attached with `set_texture` belong to the caller and are left untouched.
+    pub fn unload(self) {
+        unload_material(self);
+    }
+
+    // Maps
+
+    /// Material maps, one per `MaterialMapIndex` (empty if the maps array was never allocated)
+    pub fn maps(&self) -> &[MaterialMap] {
+        if self.maps.is_null() {
+            return &[];
+        }
+        return unsafe { slice::from_raw_parts(self.maps, MATERIAL_MAP_COUNT) };
+    }
+
+    /// Material map, `None` if the maps array was never allocated
+    pub fn map(&self, index: MaterialMapIndex) -> Option<&MaterialMap> {
+        return self.maps().get(index as usize);
+    }
+
+    pub fn texture(&self, index: MaterialMapIndex) -> Option<Texture2D> {
+        return self.map(index).map(|map| map.texture);
+    }
+
+    pub fn color(&self, index: MaterialMapIndex) -> Option<Color> {
+        return self.map(index).map(|map| map.color);
+    }
+
+    pub fn value(&self, index: MaterialMapIndex) -> Option<f32> {
+        return self.map(index).map(|map| map.value);
+    }
+
+    /// Attach a texture to a material map.
+    /// The texture stays owned by the caller: unloading the material will not unload it.
+    pub fn set_texture(&mut self, index: MaterialMapIndex, texture: Texture2D) {
+        if self.maps.is_null() {
+            return;
+        }
+        set_material_texture(self, index, texture);
+    }
+
+    /// Attach a texture to a material map, the material takes ownership of it and unloads it with the material
+    pub fn attach_texture(&mut self, index: MaterialMapIndex, texture: Texture2D) {
+        if let Some(map) = self.maps_mut().get_mut(index as usize) {
+            map.texture = texture;
+            set_texture_borrowed(self, index as usize, false);
+        }
+    }
+
+    /// Set a material map color, does nothing if the maps array was never allocated
+    pub fn set_color(&mut self, index: MaterialMapIndex, color: Color) {
+        if let Some(map) = self.maps_mut().get_mut(index as usize) {
+            map.color = color;
+        }
+    }
+
+    /// Set a material map value, does nothing if the maps array was never allocated
+    pub fn set_value(&mut self, index: MaterialMapIndex, value: f32) {
+        if let Some(map) = self.maps_mut().get_mut(index as usize) {
+            map.value = value;
+        }
+    }
+
+    // Parameters
+
+    pub fn params(&self) -> [f32; 4] {
+        return self.params;
+    }
+
+    pub fn set_params(&mut self, params: [f32; 4]) {
+        self.params = params;
+    }
+
+    fn maps_mut(&mut self) -> &mut [MaterialMap] {
+        if self.maps.is_null() {
+            return &mut [];
+        }
+        return unsafe { slice::from_raw_parts_mut(self.maps, MATERIAL_MAP_COUNT) };
+    }
+
+    /// Shallow copy sharing the shader and maps, to pass the material by value to raylib functions.
+    /// NOTE: The copy must be moved into a raylib call, dropping it would unload the shared shader
+    pub(crate) fn shallow_copy(&self) -> Material {
+        return unsafe { ptr::read(self) };
+    }
+}
+
+// ---------------------------------------------------------------------------------
+// Texture ownership
+// ---------------------------------------------------------------------------------
+
+/// Map slots holding a texture owned by the caller, keyed by the address of the material maps array.
+/// NOTE: Materials are passed by value to raylib and stored in its model arrays, so the mask cannot be a field.
+/// Entries are removed when the maps array is unloaded and cleared when raylib allocates one, as addresses are reused.
+static BORROWED_TEXTURES: Mutex<BTreeMap<usize, u32>> = Mutex::new(BTreeMap::new());
+
+fn borrowed_textures() -> std::sync::MutexGuard<'static, BTreeMap<usize, u32>> {
+    // NOTE: The masks stay consistent even if a thread panicked while holding the lock
+    return BORROWED_TEXTURES.lock().unwrap_or_else(|error| error.into_inner());
+}
+
+fn set_texture_borrowed(material: &Material, index: usize, borrowed: bool) {
+    if material.maps.is_null() {
+        return;
+    }
+    let mut masks = borrowed_textures();
+    let mask = masks.entry(material.maps as usize).or_insert(0);
+    if borrowed {
+        *mask |= 1 << index;
+    } else {
+        *mask &= !(1 << index);
+    }
+    if *mask == 0 {
+        masks.remove(&(material.maps as usize));
+    }
+}
+
+/// Record that the texture in a material map slot is owned outside the material
+pub(crate) fn mark_texture_borrowed(material: &Material, index: MaterialMapIndex) {
+    set_texture_borrowed(material, index as usize, true);
+}
+
+/// Forget the ownership recorded for the maps arrays of materials just allocated by raylib
+pub(crate) fn forget_borrowed_textures(materials: &[Material]) {
+    let mut masks = borrowed_textures();
+    for material in materials {
+        masks.remove(&(material.maps as usize));
+    }
+}
+
+/// Detach borrowed textures from a material so raylib does not unload them with it, and forget its ownership
+pub(crate) fn detach_borrowed_textures(material: &Material) {
+    let Some(mask) = borrowed_textures().remove(&(material.maps as usize)) else {
+        return;
+    };
+
+    // NOTE: Texture id 0 is ignored by rlUnloadTexture()
+    let detached = Texture2D(Texture { id: 0, width: 0, height: 0, mipmaps: 0, format: PixelFormat::UncompressedR8g8b8a8 });
+    for index in 0..MATERIAL_MAP_COUNT {
+        if mask & (1 << index) != 0 {
+            unsafe { (*material.maps.add(index)).texture = detached };
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------
+// PBR material builder
+// ---------------------------------------------------------------------------------
+
+/// Builder for physically based materials.
+/// Textures passed to the builder stay owned by the caller.
+pub struct PbrMaterialBuilder {
+    shader: Option<Shader>,
+    albedo: Color,
+    albedo_texture: Option<Texture2D>,
+    metalness: f32,
+    metalness_texture: Option<Texture2D>,
+    roughness: f32,
+    roughness_texture: Option<Texture2D>,
+    normal_texture: Option<Texture2D>,
+    occlusion: f32,
+    occlusion_texture: Option<Texture2D>,
+    emission: Color,
+    emission_texture: Option<Texture2D>,
+}
+
+impl PbrMaterialBuilder {
+    pub fn new() -> Self {
+        return Self {
+            shader: None,
+            albedo: Color::WHITE,
+            albedo_texture: None,
+            metalness: 0.0,
+            metalness_texture: None,
+            roughness: 0.5,
+            roughness_texture: None,
+            normal_texture: None,
+            occlusion: 1.0,
+            occlusion_texture: None,
+            emission: Color::BLACK,
+            emission_texture: None,
+        };
+    }
+
+    /// Shader used by the material, the material takes ownership of it (default shader if not set)
+    pub fn shader(mut self, shader: Shader) -> Self {
+        self.shader = Some(shader);
+        return self;
+    }
+
+    pub fn albedo(mut self, color: Color) -> Self {
+        self.albedo = color;
+        return self;
+    }
+
+    pub fn albedo_texture(mut self, texture: Texture2D) -> Self {
+        self.albedo_texture = Some(texture);
+        return self;
+    }
+
+    pub fn metalness(mut self, metalness: f32) -> Self {
+        self.metalness = metalness;
+        return self;
+    }
+
+    pub fn metalness_texture(mut self, texture: Texture2D) -> Self {
+        self.metalness_texture = Some(texture);
+        return self;
+    }
+
+    pub fn roughness(mut self, roughness: f32) -> Self {
+        self.roughness = roughness;
+        return self;
+    }
+
+    pub fn roughness_texture(mut self, texture: Texture2D) -> Self {
+        self.roughness_texture = Some(texture);
+        return self;
+    }
+
+    pub fn normal_texture(mut self, texture: Texture2D) -> Self {
+        self.normal_texture = Some(texture);
+        return self;
+    }
+
+    pub fn occlusion(mut self, occlusion: f32) -> Self {
+        self.occlusion = occlusion;
+        return self;
+    }
+
+    pub fn occlusion_texture(mut self, texture: Texture2D) -> Self {
+        self.occlusion_texture = Some(texture);
+        return self;
+    }
+
+    pub fn emission(mut self, color: Color) -> Self {
+        self.emission = color;
+        return self;
+    }
+
+    pub fn emission_texture(mut self, texture: Texture2D) -> Self {
+        self.emission_texture = Some(texture);
+        return self;
+    }
+
+    pub fn build(self) -> Material {
+        let mut material = load_material_default();
+        if let Some(shader) = self.shader {
+            // NOTE: The default material uses raylib's default shader, dropping it is a no-op
+            material.shader = shader;
+        }
+
+        material.set_color(MaterialMapIndex::Albedo, self.albedo);
+        material.set_value(MaterialMapIndex::Metalness, self.metalness);
+        material.set_value(MaterialMapIndex::Roughness, self.roughness);
+        material.set_value(MaterialMapIndex::Occlusion, self.occlusion);
+        material.set_color(MaterialMapIndex::Emission, self.emission);
+
+        let textures = [
+            (MaterialMapIndex::Albedo, self.albedo_texture),
+            (MaterialMapIndex::Metalness, self.metalness_texture),
+            (MaterialMapIndex::Roughness, self.roughness_texture),
+            (MaterialMapIndex::Normal, self.normal_texture),
+            (MaterialMapIndex::Occlusion, self.occlusion_texture),
+            (MaterialMapIndex::Emission, self.emission_texture),
+        ];
+        for (index, texture) in textures {
+            if let Some(texture) = texture {
+                material.set_texture(index, texture);
+            }
+        }
+
+        return material;
+    }
+}
+
+impl Default for PbrMaterialBuilder {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem;
+
+    fn texture(id: u32) -> Texture2D {
+        return Texture2D(Texture { id, width: 1, height: 1, mipmaps: 1, format: PixelFormat::UncompressedR8g8b8a8 });
+    }
+
+    #[test]
+    fn borrowed_textures_are_detached() {
+        let map = MaterialMap { texture: texture(1), color: Color::WHITE, value: 0.0 };
+        let mut maps = vec![map; MAX_MATERIAL_MAPS];
+        // NOTE: The material is forgotten at the end, dropping its shader would call into raylib without a window
+        let mut material = Material {
+            shader: Shader { id: 0, locs: ptr::null_mut() },
+            maps: maps.as_mut_ptr(),
+            params: [0.0; 4],
+        };
+        assert_eq!(material.maps().len(), MaterialMapIndex::Brdf as usize + 1);
+
+        material.set_texture(MaterialMapIndex::Albedo, texture(2));
+        material.set_texture(MaterialMapIndex::Normal, texture(3));
+        material.attach_texture(MaterialMapIndex::Normal, texture(4));
+        detach_borrowed_textures(&material);
+        let ids: Vec<u32> = material.maps().iter().map(|map| map.texture.0.id).collect();
+        assert_eq!(ids, [0, 1, 4, 1, 1, 1, 1, 1, 1, 1, 1]);
+
+        // The ownership is forgotten once detached, and when raylib hands out the same maps array again
+        material.set_texture(MaterialMapIndex::Metalness, texture(5));
+        forget_borrowed_textures(slice::from_ref(&material));
+        detach_borrowed_textures(&material);
+        assert_eq!(material.texture(MaterialMapIndex::Metalness).map(|texture| texture.0.id), Some(5));
+
+        material.maps = ptr::null_mut();
+        assert!(material.texture(MaterialMapIndex::Albedo).is_none());
+        material.set_texture(MaterialMapIndex::Albedo, texture(6));
+        mem::forget(material);
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Vector2 {
@@ -13,6 +15,39 @@ impl Vector2 {
     pub fn zero() -> Self {
         return Self { x: 0.0, y: 0.0 };
     }
+
+    pub fn one() -> Self {
+        return Self { x: 1.0, y: 1.0 };
+    }
+
+    pub fn dot(&self, other: Vector2) -> f32 {
+        return self.x * other.x + self.y * other.y;
+    }
+
+    pub fn length_sqr(&self) -> f32 {
+        return self.dot(*self);
+    }
+
+    pub fn length(&self) -> f32 {
+        return self.length_sqr().sqrt();
+    }
+
+    pub fn distance(&self, other: Vector2) -> f32 {
+        return (*self - other).length();
+    }
+
+    /// Unit vector with the same direction (zero vector stays zero)
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == 0.0 {
+            return *self;
+        }
+        return *self / length;
+    }
+
+    pub fn lerp(&self, other: Vector2, amount: f32) -> Self {
+        return *self + (other - *self) * amount;
+    }
 }
 
 impl From<(f32, f32)> for Vector2 {
@@ -21,6 +56,67 @@ impl From<(f32, f32)> for Vector2 {
     }
 }
 
+impl Add for Vector2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        return Self { x: self.x + rhs.x, y: self.y + rhs.y };
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        return Self { x: self.x - rhs.x, y: self.y - rhs.y };
+    }
+}
+
+impl Mul<f32> for Vector2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        return Self { x: self.x * rhs, y: self.y * rhs };
+    }
+}
+
+impl Div<f32> for Vector2 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        return Self { x: self.x / rhs, y: self.y / rhs };
+    }
+}
+
+impl Neg for Vector2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Self { x: -self.x, y: -self.y };
+    }
+}
+
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl SubAssign for Vector2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl MulAssign<f32> for Vector2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Vector3 {
@@ -37,6 +133,60 @@ impl Vector3 {
     pub fn zero() -> Self {
         return Self { x: 0.0, y: 0.0, z: 0.0 };
     }
+
+    pub fn one() -> Self {
+        return Self { x: 1.0, y: 1.0, z: 1.0 };
+    }
+
+    pub fn dot(&self, other: Vector3) -> f32 {
+        return self.x * other.x + self.y * other.y + self.z * other.z;
+    }
+
+    pub fn cross(&self, other: Vector3) -> Self {
+        return Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        };
+    }
+
+    pub fn length_sqr(&self) -> f32 {
+        return self.dot(*self);
+    }
+
+    pub fn length(&self) -> f32 {
+        return self.length_sqr().sqrt();
+    }
+
+    pub fn distance(&self, other: Vector3) -> f32 {
+        return (*self - other).length();
+    }
+
+    /// Unit vector with the same direction (zero vector stays zero)
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == 0.0 {
+            return *self;
+        }
+        return *self / length;
+    }
+
+    pub fn lerp(&self, other: Vector3, amount: f32) -> Self {
+        return *self + (other - *self) * amount;
+    }
+
+    /// Component-wise multiplication
+    pub fn scale(&self, other: Vector3) -> Self {
+        return Self { x: self.x * other.x, y: self.y * other.y, z: self.z * other.z };
+    }
+
+    pub fn min(&self, other: Vector3) -> Self {
+        return Self { x: self.x.min(other.x), y: self.y.min(other.y), z: self.z.min(other.z) };
+    }
+
+    pub fn max(&self, other: Vector3) -> Self {
+        return Self { x: self.x.max(other.x), y: self.y.max(other.y), z: self.z.max(other.z) };
+    }
 }
 
 impl From<(f32, f32, f32)> for Vector3 {
@@ -45,6 +195,70 @@ impl From<(f32, f32, f32)> for Vector3 {
     }
 }
 
+impl Add for Vector3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        return Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z };
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        return Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z };
+    }
+}
+
+impl Mul<f32> for Vector3 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        return Self { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs };
+    }
+}
+
+impl Div<f32> for Vector3 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        return Self { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs };
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        return Self { x: -self.x, y: -self.y, z: -self.z };
+    }
+}
+
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl MulAssign<f32> for Vector3 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Vector4 {
@@ -77,7 +291,157 @@ impl From<(f32, f32, f32, f32)> for Vector4 {
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct Quaternion(Vector4);
+pub struct Quaternion(pub Vector4);
+
+impl Quaternion {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        return Self(Vector4::new(x, y, z, w));
+    }
+
+    pub fn identity() -> Self {
+        return Self::new(0.0, 0.0, 0.0, 1.0);
+    }
+
+    /// Rotation around an axis, angle in radians
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        let axis = axis.normalize();
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        return Self::new(axis.x * sin, axis.y * sin, axis.z * sin, cos);
+    }
+
+    /// Shortest rotation turning direction `from` into direction `to`
+    pub fn from_to(from: Vector3, to: Vector3) -> Self {
+        let (from, to) = (from.normalize(), to.normalize());
+        let cos = from.dot(to);
+        if cos < -0.999999 {
+            // Opposite directions: rotate half a turn around any perpendicular axis
+            let mut axis = Vector3::new(1.0, 0.0, 0.0).cross(from);
+            if axis.length_sqr() < 1e-6 {
+                axis = Vector3::new(0.0, 1.0, 0.0).cross(from);
+            }
+            return Self::from_axis_angle(axis, std::f32::consts::PI);
+        }
+        let axis = from.cross(to);
+        return Self::new(axis.x, axis.y, axis.z, 1.0 + cos).normalize();
+    }
+
+    /// Rotation axis and angle (radians)
+    pub fn to_axis_angle(&self) -> (Vector3, f32) {
+        let q = if self.0.w > 1.0 { self.normalize() } else { *self };
+        let angle = 2.0 * q.0.w.clamp(-1.0, 1.0).acos();
+        let sin = (1.0 - q.0.w * q.0.w).max(0.0).sqrt();
+        if sin < 1e-6 {
+            return (Vector3::new(1.0, 0.0, 0.0), 0.0);
+        }
+        return (Vector3::new(q.0.x / sin, q.0.y / sin, q.0.z / sin), angle);
+    }
+
+    pub fn dot(&self, other: Quaternion) -> f32 {
+        let (a, b) = (self.0, other.0);
+        return a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+    }
+
+    pub fn length(&self) -> f32 {
+        return self.dot(*self).sqrt();
+    }
+
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        if length == 0.0 {
+            return Self::identity();
+        }
+        let q = self.0;
+        return Self::new(q.x / length, q.y / length, q.z / length, q.w / length);
+    }
+
+    pub fn conjugate(&self) -> Self {
+        let q = self.0;
+        return Self::new(-q.x, -q.y, -q.z, q.w);
+    }
+
+    pub fn invert(&self) -> Self {
+        let length_sqr = self.dot(*self);
+        if length_sqr == 0.0 {
+            return *self;
+        }
+        let q = self.conjugate().0;
+        return Self::new(q.x / length_sqr, q.y / length_sqr, q.z / length_sqr, q.w / length_sqr);
+    }
+
+    /// Hamilton product, the rotation `other` followed by `self` (same as raymath QuaternionMultiply())
+    pub fn multiply(&self, other: Quaternion) -> Self {
+        let (a, b) = (self.0, other.0);
+        return Self::new(
+            a.x * b.w + a.w * b.x + a.y * b.z - a.z * b.y,
+            a.y * b.w + a.w * b.y + a.z * b.x - a.x * b.z,
+            a.z * b.w + a.w * b.z + a.x * b.y - a.y * b.x,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        );
+    }
+
+    pub fn rotate(&self, v: Vector3) -> Vector3 {
+        let q = Vector3::new(self.0.x, self.0.y, self.0.z);
+        let t = q.cross(v) * 2.0;
+        return v + t * self.0.w + q.cross(t);
+    }
+
+    /// Normalized linear interpolation along the shortest path
+    pub fn nlerp(&self, other: Quaternion, amount: f32) -> Self {
+        let (a, mut b) = (self.0, other.0);
+        if self.dot(other) < 0.0 {
+            b = Vector4::new(-b.x, -b.y, -b.z, -b.w);
+        }
+        return Self::new(
+            a.x + (b.x - a.x) * amount,
+            a.y + (b.y - a.y) * amount,
+            a.z + (b.z - a.z) * amount,
+            a.w + (b.w - a.w) * amount,
+        )
+        .normalize();
+    }
+
+    /// Spherical linear interpolation along the shortest path
+    pub fn slerp(&self, other: Quaternion, amount: f32) -> Self {
+        let (a, mut b) = (self.0, other.0);
+        let mut cos = self.dot(other);
+        if cos < 0.0 {
+            b = Vector4::new(-b.x, -b.y, -b.z, -b.w);
+            cos = -cos;
+        }
+        if cos > 0.9995 {
+            return self.nlerp(Self(b), amount);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let ratio_a = ((1.0 - amount) * angle).sin() / sin;
+        let ratio_b = (amount * angle).sin() / sin;
+        return Self::new(
+            a.x * ratio_a + b.x * ratio_b,
+            a.y * ratio_a + b.y * ratio_b,
+            a.z * ratio_a + b.z * ratio_b,
+            a.w * ratio_a + b.w * ratio_b,
+        );
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        let Vector4 { x, y, z, w } = self.0;
+        return Matrix::from_rows([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w), 0.0],
+            [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w), 0.0],
+            [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: Quaternion) -> Quaternion {
+        return self.multiply(other);
+    }
+}
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -100,13 +464,220 @@ pub struct Matrix {
     pub m15: f32,
 }
 
+impl Matrix {
+    pub fn identity() -> Self {
+        return Self::from_rows([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// Build a matrix from its rows (the same layout raylib uses for matrix literals)
+    pub fn from_rows(rows: [[f32; 4]; 4]) -> Self {
+        return Self {
+            m0: rows[0][0],
+            m4: rows[0][1],
+            m8: rows[0][2],
+            m12: rows[0][3],
+            m1: rows[1][0],
+            m5: rows[1][1],
+            m9: rows[1][2],
+            m13: rows[1][3],
+            m2: rows[2][0],
+            m6: rows[2][1],
+            m10: rows[2][2],
+            m14: rows[2][3],
+            m3: rows[3][0],
+            m7: rows[3][1],
+            m11: rows[3][2],
+            m15: rows[3][3],
+        };
+    }
+
+    pub fn translate(x: f32, y: f32, z: f32) -> Self {
+        return Self::from_rows([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    pub fn scale(x: f32, y: f32, z: f32) -> Self {
+        return Self::from_rows([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// Rotation matrix from axis and angle (radians)
+    pub fn rotate(axis: Vector3, angle: f32) -> Self {
+        let Vector3 { x, y, z } = axis.normalize();
+        let (sin, cos) = angle.sin_cos();
+        let t = 1.0 - cos;
+
+        return Self::from_rows([
+            [x * x * t + cos, x * y * t - z * sin, x * z * t + y * sin, 0.0],
+            [y * x * t + z * sin, y * y * t + cos, y * z * t - x * sin, 0.0],
+            [z * x * t - y * sin, z * y * t + x * sin, z * z * t + cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// Perspective projection matrix (same as raymath MatrixPerspective()), `fov_y` in radians
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let top = near * (fov_y * 0.5).tan();
+        let right = top * aspect;
+        let depth = far - near;
+
+        return Self::from_rows([
+            [near / right, 0.0, 0.0, 0.0],
+            [0.0, near / top, 0.0, 0.0],
+            [0.0, 0.0, -(far + near) / depth, -2.0 * far * near / depth],
+            [0.0, 0.0, -1.0, 0.0],
+        ]);
+    }
+
+    /// Orthographic projection matrix (same as raymath MatrixOrtho())
+    pub fn ortho(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+
+        return Self::from_rows([
+            [2.0 / width, 0.0, 0.0, -(left + right) / width],
+            [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+            [0.0, 0.0, -2.0 / depth, -(far + near) / depth],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// View matrix looking from `eye` to `target` (same as raymath MatrixLookAt())
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Self {
+        let z = (eye - target).normalize();
+        let x = up.cross(z).normalize();
+        let y = z.cross(x);
+
+        return Self::from_rows([
+            [x.x, x.y, x.z, -x.dot(eye)],
+            [y.x, y.y, y.z, -y.dot(eye)],
+            [z.x, z.y, z.z, -z.dot(eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+    }
+
+    /// Matrix product, `self` is applied first and `right` second (same as raymath MatrixMultiply())
+    pub fn multiply(&self, right: Matrix) -> Self {
+        return Self {
+            m0: self.m0 * right.m0 + self.m1 * right.m4 + self.m2 * right.m8 + self.m3 * right.m12,
+            m1: self.m0 * right.m1 + self.m1 * right.m5 + self.m2 * right.m9 + self.m3 * right.m13,
+            m2: self.m0 * right.m2 + self.m1 * right.m6 + self.m2 * right.m10 + self.m3 * right.m14,
+            m3: self.m0 * right.m3 + self.m1 * right.m7 + self.m2 * right.m11 + self.m3 * right.m15,
+            m4: self.m4 * right.m0 + self.m5 * right.m4 + self.m6 * right.m8 + self.m7 * right.m12,
+            m5: self.m4 * right.m1 + self.m5 * right.m5 + self.m6 * right.m9 + self.m7 * right.m13,
+            m6: self.m4 * right.m2 + self.m5 * right.m6 + self.m6 * right.m10 + self.m7 * right.m14,
+            m7: self.m4 * right.m3 + self.m5 * right.m7 + self.m6 * right.m11 + self.m7 * right.m15,
+            m8: self.m8 * right.m0 + self.m9 * right.m4 + self.m10 * right.m8 + self.m11 * right.m12,
+            m9: self.m8 * right.m1 + self.m9 * right.m5 + self.m10 * right.m9 + self.m11 * right.m13,
+            m10: self.m8 * right.m2 + self.m9 * right.m6 + self.m10 * right.m10 + self.m11 * right.m14,
+            m11: self.m8 * right.m3 + self.m9 * right.m7 + self.m10 * right.m11 + self.m11 * right.m15,
+            m12: self.m12 * right.m0 + self.m13 * right.m4 + self.m14 * right.m8 + self.m15 * right.m12,
+            m13: self.m12 * right.m1 + self.m13 * right.m5 + self.m14 * right.m9 + self.m15 * right.m13,
+            m14: self.m12 * right.m2 + self.m13 * right.m6 + self.m14 * right.m10 + self.m15 * right.m14,
+            m15: self.m12 * right.m3 + self.m13 * right.m7 + self.m14 * right.m11 + self.m15 * right.m15,
+        };
+    }
+
+    pub fn transpose(&self) -> Self {
+        return Self {
+            m0: self.m0,
+            m4: self.m1,
+            m8: self.m2,
+            m12: self.m3,
+            m1: self.m4,
+            m5: self.m5,
+            m9: self.m6,
+            m13: self.m7,
+            m2: self.m8,
+            m6: self.m9,
+            m10: self.m10,
+            m14: self.m11,
+            m3: self.m12,
+            m7: self.m13,
+            m11: self.m14,
+            m15: self.m15,
+        };
+    }
+
+    pub fn invert(&self) -> Self {
+        let (a00, a01, a02, a03) = (self.m0, self.m1, self.m2, self.m3);
+        let (a10, a11, a12, a13) = (self.m4, self.m5, self.m6, self.m7);
+        let (a20, a21, a22, a23) = (self.m8, self.m9, self.m10, self.m11);
+        let (a30, a31, a32, a33) = (self.m12, self.m13, self.m14, self.m15);
+
+        let b00 = a00 * a11 - a01 * a10;
+        let b01 = a00 * a12 - a02 * a10;
+        let b02 = a00 * a13 - a03 * a10;
+        let b03 = a01 * a12 - a02 * a11;
+        let b04 = a01 * a13 - a03 * a11;
+        let b05 = a02 * a13 - a03 * a12;
+        let b06 = a20 * a31 - a21 * a30;
+        let b07 = a20 * a32 - a22 * a30;
+        let b08 = a20 * a33 - a23 * a30;
+        let b09 = a21 * a32 - a22 * a31;
+        let b10 = a21 * a33 - a23 * a31;
+        let b11 = a22 * a33 - a23 * a32;
+
+        let inv_det = 1.0 / (b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06);
+
+        return Self {
+            m0: (a11 * b11 - a12 * b10 + a13 * b09) * inv_det,
+            m1: (-a01 * b11 + a02 * b10 - a03 * b09) * inv_det,
+            m2: (a31 * b05 - a32 * b04 + a33 * b03) * inv_det,
+            m3: (-a21 * b05 + a22 * b04 - a23 * b03) * inv_det,
+            m4: (-a10 * b11 + a12 * b08 - a13 * b07) * inv_det,
+            m5: (a00 * b11 - a02 * b08 + a03 * b07) * inv_det,
+            m6: (-a30 * b05 + a32 * b02 - a33 * b01) * inv_det,
+            m7: (a20 * b05 - a22 * b02 + a23 * b01) * inv_det,
+            m8: (a10 * b10 - a11 * b08 + a13 * b06) * inv_det,
+            m9: (-a00 * b10 + a01 * b08 - a03 * b06) * inv_det,
+            m10: (a30 * b04 - a31 * b02 + a33 * b00) * inv_det,
+            m11: (-a20 * b04 + a21 * b02 - a23 * b00) * inv_det,
+            m12: (-a10 * b09 + a11 * b07 - a12 * b06) * inv_det,
+            m13: (a00 * b09 - a01 * b07 + a02 * b06) * inv_det,
+            m14: (-a30 * b03 + a31 * b01 - a32 * b00) * inv_det,
+            m15: (a20 * b03 - a21 * b01 + a22 * b00) * inv_det,
+        };
+    }
+
+    /// Transform a point (translation applied)
+    pub fn transform_point(&self, v: Vector3) -> Vector3 {
+        return Vector3 {
+            x: self.m0 * v.x + self.m4 * v.y + self.m8 * v.z + self.m12,
+            y: self.m1 * v.x + self.m5 * v.y + self.m9 * v.z + self.m13,
+            z: self.m2 * v.x + self.m6 * v.y + self.m10 * v.z + self.m14,
+        };
+    }
+
+    /// Transform a direction (translation ignored)
+    pub fn transform_vector(&self, v: Vector3) -> Vector3 {
+        return Vector3 {
+            x: self.m0 * v.x + self.m4 * v.y + self.m8 * v.z,
+            y: self.m1 * v.x + self.m5 * v.y + self.m9 * v.z,
+            z: self.m2 * v.x + self.m6 * v.y + self.m10 * v.z,
+        };
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Rectangle {
-    x: f32,      // Rectangle top-left corner position x
-    y: f32,      // Rectangle top-left corner position y
-    width: f32,  // Rectangle width
-    height: f32, // Rectangle height
+    pub x: f32,      // Rectangle top-left corner position x
+    pub y: f32,      // Rectangle top-left corner position y
+    pub width: f32,  // Rectangle width
+    pub height: f32, // Rectangle height
 }
 
 impl Rectangle {
@@ -123,7 +694,7 @@ impl From<(f32, f32, f32, f32)> for Rectangle {
 
 /// Transform, vertex transformation data
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Transform {
     /// Translation
     pub translation: Vector3,
@@ -132,3 +703,58 @@ pub struct Transform {
     /// Scale
     pub scale: Vector3,
 }
+
+impl Transform {
+    pub fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Self {
+        return Self { translation, rotation, scale };
+    }
+
+    pub fn identity() -> Self {
+        return Self::new(Vector3::zero(), Quaternion::identity(), Vector3::one());
+    }
+
+    /// Transform of `child` (given relative to `self`) in the space `self` is relative to
+    pub fn combine(&self, child: &Transform) -> Transform {
+        return Transform {
+            translation: self.translation + self.rotation.rotate(self.scale.scale(child.translation)),
+            rotation: self.rotation.multiply(child.rotation),
+            scale: self.scale.scale(child.scale),
+        };
+    }
+
+    /// Transform relative to `parent`, the inverse of `parent.combine()`
+    pub fn relative_to(&self, parent: &Transform) -> Transform {
+        let inverse_rotation = parent.rotation.invert();
+        let inverse_scale = Vector3::new(1.0 / parent.scale.x, 1.0 / parent.scale.y, 1.0 / parent.scale.z);
+        return Transform {
+            translation: inverse_rotation.rotate(self.translation - parent.translation).scale(inverse_scale),
+            rotation: inverse_rotation.multiply(self.rotation),
+            scale: self.scale.scale(inverse_scale),
+        };
+    }
+
+    /// Inverse transform (exact for uniform scales)
+    pub fn inverse(&self) -> Transform {
+        return Transform::identity().relative_to(self);
+    }
+
+    /// Interpolate translation and scale linearly, rotation with slerp
+    pub fn lerp(&self, other: &Transform, amount: f32) -> Transform {
+        return Transform {
+            translation: self.translation.lerp(other.translation, amount),
+            rotation: self.rotation.slerp(other.rotation, amount),
+            scale: self.scale.lerp(other.scale, amount),
+        };
+    }
+
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        return self.translation + self.rotation.rotate(self.scale.scale(point));
+    }
+
+    /// Matrix applying scale, then rotation, then translation
+    pub fn to_matrix(&self) -> Matrix {
+        let scale = Matrix::scale(self.scale.x, self.scale.y, self.scale.z);
+        let translation = Matrix::translate(self.translation.x, self.translation.y, self.translation.z);
+        return scale.multiply(self.rotation.to_matrix()).multiply(translation);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{Color, Mesh, Vector2, Vector3, Vector4, mem_alloc, upload_mesh};
+use std::ptr::{copy_nonoverlapping, null_mut};
+
+/// Builder for meshes created from vertex data on the CPU.
+/// Vertex arrays are validated, copied into raylib-allocated memory and (optionally) uploaded to the GPU.
+#[derive(Debug, Clone)]
+pub struct MeshBuilder {
+    positions: Vec<Vector3>,
+    normals: Option<Vec<Vector3>>,
+    tex_coords: Option<Vec<Vector2>>,
+    tex_coords2: Option<Vec<Vector2>>,
+    colors: Option<Vec<Color>>,
+    indices: Option<Vec<u32>>,
+    compute_normals: bool,
+    compute_tangents: bool,
+    dynamic: bool,
+}
+
+impl MeshBuilder {
+    pub fn new(positions: Vec<Vector3>) -> Self {
+        return Self {
+            positions,
+            normals: None,
+            tex_coords: None,
+            tex_coords2: None,
+            colors: None,
+            indices: None,
+            compute_normals: false,
+            compute_tangents: false,
+            dynamic: false,
+        };
+    }
+
+    pub fn normals(mut self, normals: Vec<Vector3>) -> Self {
+        self.normals = Some(normals);
+        return self;
+    }
+
+    pub fn tex_coords(mut self, tex_coords: Vec<Vector2>) -> Self {
+        self.tex_coords = Some(tex_coords);
+        return self;
+    }
+
+    pub fn tex_coords2(mut self, tex_coords2: Vec<Vector2>) -> Self {
+        self.tex_coords2 = Some(tex_coords2);
+        return self;
+    }
+
+    pub fn colors(mut self, colors: Vec<Color>) -> Self {
+        self.colors = Some(colors);
+        return self;
+    }
+
+    pub fn indices(mut self, indices: Vec<u16>) -> Self {
+        self.indices = Some(indices.into_iter().map(u32::from).collect());
+        return self;
+    }
+
+    /// 32-bit indices, meshes that do not fit raylib's 16-bit indices are converted to non-indexed triangles
+    pub fn indices_u32(mut self, indices: Vec<u32>) -> Self {
+        self.indices = Some(indices);
+        return self;
+    }
+
+    /// Compute smooth (area weighted) normals, replacing any normals provided
+    pub fn compute_normals(mut self) -> Self {
+        self.compute_normals = true;
+        return self;
+    }
+
+    /// Compute tangents from normals and texture coordinates
+    pub fn compute_tangents(mut self) -> Self {
+        self.compute_tangents = true;
+        return self;
+    }
+
+    /// Upload vertex buffers as dynamic (for meshes updated with `update_mesh_buffer`)
+    pub fn dynamic(mut self, dynamic: bool) -> Self {
+        self.dynamic = dynamic;
+        return self;
+    }
+
+    /// Build the mesh and upload it to the GPU
+    pub fn build(self) -> Result<Mesh, String> {
+        let dynamic = self.dynamic;
+        let mut mesh = self.build_cpu()?;
+        upload_mesh(&mut mesh, dynamic);
+        return Ok(mesh);
+    }
+
+    /// Build the mesh in CPU memory only, it can be uploaded later with `Mesh::upload`
+    pub fn build_cpu(mut self) -> Result<Mesh, String> {
+        self.validate()?;
+
+        let vertex_count = self.positions.len();
+        let triangles = triangle_list(self.indices.as_deref(), vertex_count);
+
+        if self.compute_normals {
+            self.normals = Some(compute_smooth_normals(&self.positions, &triangles));
+        }
+
+        let mut tangents = None;
+        if self.compute_tangents {
+            let Some(tex_coords) = &self.tex_coords else {
+                return Err("Mesh tangents require texture coordinates".to_string());
+            };
+            let normals = match &self.normals {
+                Some(normals) => normals.clone(),
+                None => compute_smooth_normals(&self.positions, &triangles),
+            };
+            tangents = Some(compute_tangents(&self.positions, &normals, tex_coords, &triangles));
+        }
+
+        // NOTE: raylib meshes only support 16-bit indices
+        if self.indices.is_some() && vertex_count > u16::MAX as usize + 1 {
+            self.positions = unroll(&self.positions, &triangles);
+            self.normals = self.normals.map(|normals| unroll(&normals, &triangles));
+            self.tex_coords = self.tex_coords.map(|tex_coords| unroll(&tex_coords, &triangles));
+            self.tex_coords2 = self.tex_coords2.map(|tex_coords| unroll(&tex_coords, &triangles));
+            self.colors = self.colors.map(|colors| unroll(&colors, &triangles));
+            tangents = tangents.map(|tangents| unroll(&tangents, &triangles));
+            self.indices = None;
+        }
+
+        let mut mesh = Mesh::empty();
+        mesh.vertex_count = self.positions.len() as i32;
+        mesh.triangle_count = (triangles.len() / 3) as i32;
+        unsafe {
+            mesh.vertices = raylib_copy(&self.positions);
+            mesh.normals = self.normals.as_deref().map_or(null_mut(), |data| raylib_copy(data));
+            mesh.tex_coords = self.tex_coords.as_deref().map_or(null_mut(), |data| raylib_copy(data));
+            mesh.tex_coords2 = self.tex_coords2.as_deref().map_or(null_mut(), |data| raylib_copy(data));
+            mesh.colors = self.colors.as_deref().map_or(null_mut(), |data| raylib_copy(data));
+            mesh.tangents = tangents.as_deref().map_or(null_mut(), |data| raylib_copy(data));
+            if let Some(indices) = &self.indices {
+                let indices: Vec<u16> = indices.iter().map(|&index| index as u16).collect();
+                mesh.indices = raylib_copy(&indices);
+            }
+        }
+
+        return Ok(mesh);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let vertex_count = self.positions.len();
+        if vertex_count == 0 {
+            return Err("Mesh has no vertex positions".to_string());
+        }
+
+        check_attribute_length("normals", self.normals.as_ref().map(Vec::len), vertex_count)?;
+        check_attribute_length("texture coordinates", self.tex_coords.as_ref().map(Vec::len), vertex_count)?;
+        check_attribute_length("second texture coordinates", self.tex_coords2.as_ref().map(Vec::len), vertex_count)?;
+        check_attribute_length("colors", self.colors.as_ref().map(Vec::len), vertex_count)?;
+
+        match &self.indices {
+            Some(indices) => {
+                if indices.is_empty() || !indices.len().is_multiple_of(3) {
+                    return Err(format!("Mesh index count ({}) is not a multiple of 3", indices.len()));
+                }
+                if let Some(index) = indices.iter().find(|&&index| index as usize >= vertex_count) {
+                    return Err(format!("Mesh index {} is out of range ({} vertices)", index, vertex_count));
+                }
+            }
+            None => {
+                if !vertex_count.is_multiple_of(3) {
+                    return Err(format!("Non-indexed mesh vertex count ({}) is not a multiple of 3", vertex_count));
+                }
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+fn check_attribute_length(name: &str, length: Option<usize>, vertex_count: usize) -> Result<(), String> {
+    match length {
+        Some(length) if length != vertex_count => {
+            return Err(format!("Mesh has {} {} for {} vertices", length, name, vertex_count));
+        }
+        _ => return Ok(()),
+    }
+}
+
+/// Copy a slice into memory allocated with raylib's allocator (freed by UnloadMesh())
+unsafe fn raylib_copy<T: Copy, U>(data: &[T]) -> *mut U {
+    let size = size_of_val(data);
+    unsafe {
+        let ptr = mem_alloc(size as u32);
+        copy_nonoverlapping(data.as_ptr() as *const u8, ptr as *mut u8, size);
+        return ptr as *mut U;
+    }
+}
+
+/// Vertex indices of every triangle, generated for non-indexed meshes
+pub(crate) fn triangle_list(indices: Option<&[u32]>, vertex_count: usize) -> Vec<u32> {
+    match indices {
+        Some(indices) => return indices.to_vec(),
+        None => return (0..vertex_count as u32).collect(),
+    }
+}
+
+/// Expand an indexed vertex attribute into one value per triangle corner
+fn unroll<T: Copy>(data: &[T], triangles: &[u32]) -> Vec<T> {
+    return triangles.iter().map(|&index| data[index as usize]).collect();
+}
+
+/// Smooth vertex normals, weighted by the area of the adjacent triangles
+pub(crate) fn compute_smooth_normals(positions: &[Vector3], triangles: &[u32]) -> Vec<Vector3> {
+    let mut normals = vec![Vector3::zero(); positions.len()];
+    for triangle in triangles.chunks_exact(3) {
+        let (a, b, c) = (triangle[0] as usize, triangle[1] as usize, triangle[2] as usize);
+        // NOTE: The cross product length is twice the triangle area
+        let face_normal = (positions[b] - positions[a]).cross(positions[c] - positions[a]);
+        normals[a] += face_normal;
+        normals[b] += face_normal;
+        normals[c] += face_normal;
+    }
+
+    return normals.iter().map(Vector3::normalize).collect();
+}
+
+/// Per-vertex tangents (XYZ direction, W handedness) from normals and texture coordinates
+pub(crate) fn compute_tangents(
+    positions: &[Vector3],
+    normals: &[Vector3],
+    tex_coords: &[Vector2],
+    triangles: &[u32],
+) -> Vec<Vector4> {
+    let mut tangents = vec![Vector3::zero(); positions.len()];
+    let mut bitangents = vec![Vector3::zero(); positions.len()];
+    for triangle in triangles.chunks_exact(3) {
+        let (a, b, c) = (triangle[0] as usize, triangle[1] as usize, triangle[2] as usize);
+        let edge1 = positions[b] - positions[a];
+        let edge2 = positions[c] - positions[a];
+        let delta_uv1 = tex_coords[b] - tex_coords[a];
+        let delta_uv2 = tex_coords[c] - tex_coords[a];
+
+        let determinant = delta_uv1.x * delta_uv2.y - delta_uv2.x * delta_uv1.y;
+        let r = if determinant.abs() < 1e-12 { 1.0 } else { 1.0 / determinant };
+        let tangent = (edge1 * delta_uv2.y - edge2 * delta_uv1.y) * r;
+        let bitangent = (edge2 * delta_uv1.x - edge1 * delta_uv2.x) * r;
+
+        for index in [a, b, c] {
+            tangents[index] += tangent;
+            bitangents[index] += bitangent;
+        }
+    }
+
+    let mut result = Vec::with_capacity(positions.len());
+    for i in 0..positions.len() {
+        let normal = normals[i];
+        // Gram-Schmidt orthogonalize
+        let tangent = (tangents[i] - normal * normal.dot(tangents[i])).normalize();
+        let handedness = if normal.cross(tangent).dot(bitangents[i]) < 0.0 { -1.0 } else { 1.0 };
+        result.push(Vector4::new(tangent.x, tangent.y, tangent.z, handedness));
+    }
+
+    return result;
+}
@@ -0,0 +1,86 @@
+use crate::{Color, Mesh, MeshBuilder, Vector2, Vector3, triangle_list};
+
+/// Vertex data of a mesh kept in CPU memory, used to process meshes before uploading them.
+/// Triangles are always indexed (32-bit), tangents are not stored and can be recomputed by `MeshBuilder`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshData {
+    pub positions: Vec<Vector3>,
+    pub normals: Option<Vec<Vector3>>,
+    pub tex_coords: Option<Vec<Vector2>>,
+    pub tex_coords2: Option<Vec<Vector2>>,
+    pub colors: Option<Vec<Color>>,
+    /// Triangle list, three vertex indices per triangle
+    pub indices: Vec<u32>,
+}
+
+impl MeshData {
+    /// Copy the vertex data of a mesh (the mesh must still have its CPU data)
+    pub fn from_mesh(mesh: &Mesh) -> MeshData {
+        let positions = mesh.positions().to_vec();
+        let indices = match mesh.indices() {
+            Some(indices) => indices.iter().map(|&index| index as u32).collect(),
+            None => triangle_list(None, positions.len()),
+        };
+
+        return MeshData {
+            positions,
+            normals: mesh.normals().map(<[Vector3]>::to_vec),
+            tex_coords: mesh.tex_coords().map(<[Vector2]>::to_vec),
+            tex_coords2: mesh.tex_coords2().map(<[Vector2]>::to_vec),
+            colors: mesh.colors().map(<[Color]>::to_vec),
+            indices,
+        };
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        return self.positions.len();
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        return self.indices.len() / 3;
+    }
+
+    pub fn into_builder(self) -> MeshBuilder {
+        let mut builder = MeshBuilder::new(self.positions).indices_u32(self.indices);
+        if let Some(normals) = self.normals {
+            builder = builder.normals(normals);
+        }
+        if let Some(tex_coords) = self.tex_coords {
+            builder = builder.tex_coords(tex_coords);
+        }
+        if let Some(tex_coords2) = self.tex_coords2 {
+            builder = builder.tex_coords2(tex_coords2);
+        }
+        if let Some(colors) = self.colors {
+            builder = builder.colors(colors);
+        }
+        return builder;
+    }
+
+    /// Build and upload a mesh from this data
+    pub fn to_mesh(&self) -> Result<Mesh, String> {
+        return self.clone().into_builder().build();
+    }
+
+    /// New vertex set made of copies of the `sources` vertices, with the given triangle list
+    pub(crate) fn select_vertices(&self, sources: &[usize], indices: Vec<u32>) -> MeshData {
+        fn select<T: Copy>(data: &Option<Vec<T>>, sources: &[usize]) -> Option<Vec<T>> {
+            return data.as_ref().map(|data| sources.iter().map(|&source| data[source]).collect());
+        }
+
+        return MeshData {
+            positions: sources.iter().map(|&source| self.positions[source]).collect(),
+            normals: select(&self.normals, sources),
+            tex_coords: select(&self.tex_coords, sources),
+            tex_coords2: select(&self.tex_coords2, sources),
+            colors: select(&self.colors, sources),
+            indices,
+        };
+    }
+}
+
+impl From<MeshData> for MeshBuilder {
+    fn from(data: MeshData) -> Self {
+        return data.into_builder();
+    }
+}
@@ -0,0 +1,339 @@
+use crate::rlgl::{self, DataType};
+use crate::{
+    Color, Frustum, Material, Matrix, Mesh, Shader, ShaderAttributeDataType, ShaderLocation, ShaderLocationIndex,
+    Vector3,
+};
+use std::borrow::Cow;
+
+#[cfg(not(target_arch = "wasm32"))]
+const INSTANCING_VS: &str = r#"#version 330
+in vec3 vertexPosition;
+in vec2 vertexTexCoord;
+in vec4 vertexColor;
+in mat4 instanceTransform;
+in vec4 instanceColor;
+
+uniform mat4 mvp;
+
+out vec2 fragTexCoord;
+out vec4 fragColor;
+
+void main()
+{
+    fragTexCoord = vertexTexCoord;
+    fragColor = vertexColor*instanceColor;
+    gl_Position = mvp*instanceTransform*vec4(vertexPosition, 1.0);
+}
+"#;
+
+#[cfg(not(target_arch = "wasm32"))]
+const INSTANCING_FS: &str = r#"#version 330
+in vec2 fragTexCoord;
+in vec4 fragColor;
+
+uniform sampler2D texture0;
+uniform vec4 colDiffuse;
+
+out vec4 finalColor;
+
+void main()
+{
+    finalColor = texture(texture0, fragTexCoord)*colDiffuse*fragColor;
+}
+"#;
+
+#[cfg(target_arch = "wasm32")]
+const INSTANCING_VS: &str = r#"#version 100
+attribute vec3 vertexPosition;
+attribute vec2 vertexTexCoord;
+attribute vec4 vertexColor;
+attribute mat4 instanceTransform;
+attribute vec4 instanceColor;
+
+uniform mat4 mvp;
+
+varying vec2 fragTexCoord;
+varying vec4 fragColor;
+
+void main()
+{
+    fragTexCoord = vertexTexCoord;
+    fragColor = vertexColor*instanceColor;
+    gl_Position = mvp*instanceTransform*vec4(vertexPosition, 1.0);
+}
+"#;
+
+#[cfg(target_arch = "wasm32")]
+const INSTANCING_FS: &str = r#"#version 100
+precision mediump float;
+
+varying vec2 fragTexCoord;
+varying vec4 fragColor;
+
+uniform sampler2D texture0;
+uniform vec4 colDiffuse;
+
+void main()
+{
+    gl_FragColor = texture2D(texture0, fragTexCoord)*colDiffuse*fragColor;
+}
+"#;
+
+/// GPU buffer for one per-instance stream, reallocated (doubling) when the data outgrows it
+#[derive(Debug, Default)]
+struct InstanceBuffer {
+    id: u32,
+    capacity: usize,
+}
+
+impl InstanceBuffer {
+    fn upload<T: rlgl::Pod>(&mut self, data: &[T]) {
+        let size = size_of_val(data);
+        if size > self.capacity {
+            self.unload();
+            self.capacity = size.next_power_of_two().max(256);
+            self.id = rlgl::load_vertex_buffer_empty(self.capacity, true);
+        }
+        rlgl::update_vertex_buffer(self.id, data, 0);
+    }
+
+    fn unload(&mut self) {
+        if self.id != 0 {
+            rlgl::unload_vertex_buffer(self.id);
+        }
+        self.id = 0;
+        self.capacity = 0;
+    }
+}
+
+/// Custom per-instance float attribute, bound to the shader input with the same name
+#[derive(Debug)]
+struct InstanceAttribute {
+    name: String,
+    components: usize,
+    data: Vec<f32>,
+    buffer: InstanceBuffer,
+}
+
+/// Batch of mesh instances drawn with a single instanced draw call.
+/// Holds the instance transforms plus optional per-instance colors and custom float attributes,
+/// streamed to the GPU on every draw.
+#[derive(Debug, Default)]
+pub struct InstanceBatch {
+    transforms: Vec<Matrix>,
+    colors: Option<Vec<Color>>,
+    color_buffer: InstanceBuffer,
+    attributes: Vec<InstanceAttribute>,
+}
+
+impl InstanceBatch {
+    /// Shader input receiving the per-instance transform
+    pub const TRANSFORM_ATTRIBUTE: &'static str = "instanceTransform";
+    /// Shader input receiving the per-instance color (white when the batch has no colors)
+    pub const COLOR_ATTRIBUTE: &'static str = "instanceColor";
+
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut batch = Self::default();
+        batch.transforms.reserve(capacity);
+        return batch;
+    }
+
+    /// Load the built-in instancing shader (textured, tinted by vertex and instance colors).
+    /// Custom shaders need their `VertexInstanceTx` location set the same way.
+    pub fn load_shader() -> Shader {
+        let mut shader = Shader::from_memory(INSTANCING_VS, INSTANCING_FS);
+        let location = shader.attrib_location(Self::TRANSFORM_ATTRIBUTE);
+        shader.set_location_index(ShaderLocationIndex::VertexInstanceTx, location);
+        return shader;
+    }
+
+    /// Add an instance, returns its index
+    pub fn push(&mut self, transform: Matrix) -> usize {
+        self.transforms.push(transform);
+        if let Some(colors) = &mut self.colors {
+            colors.push(Color::WHITE);
+        }
+        for attribute in &mut self.attributes {
+            attribute.data.resize(self.transforms.len() * attribute.components, 0.0);
+        }
+        return self.transforms.len() - 1;
+    }
+
+    /// Add a colored instance, instances without a color are white
+    pub fn push_colored(&mut self, transform: Matrix, color: Color) -> usize {
+        let index = self.push(transform);
+        self.set_color(index, color);
+        return index;
+    }
+
+    pub fn set_transform(&mut self, index: usize, transform: Matrix) {
+        self.transforms[index] = transform;
+    }
+
+    pub fn set_color(&mut self, index: usize, color: Color) {
+        assert!(index < self.transforms.len(), "instance index out of bounds");
+        let count = self.transforms.len();
+        self.colors.get_or_insert_with(|| vec![Color::WHITE; count])[index] = color;
+    }
+
+    /// Declare a custom float attribute (1 to 4 components), returns its id.
+    /// Values start at zero for every instance.
+    pub fn add_attribute(&mut self, name: &str, components: usize) -> usize {
+        assert!((1..=4).contains(&components), "instance attributes have 1 to 4 components");
+        self.attributes.push(InstanceAttribute {
+            name: name.to_string(),
+            components,
+            data: vec![0.0; self.transforms.len() * components],
+            buffer: InstanceBuffer::default(),
+        });
+        return self.attributes.len() - 1;
+    }
+
+    /// Set the value of a custom attribute for an instance, `value` must match the attribute components
+    pub fn set_attribute(&mut self, attribute: usize, index: usize, value: &[f32]) {
+        let attribute = &mut self.attributes[attribute];
+        assert_eq!(value.len(), attribute.components, "attribute '{}' component count mismatch", attribute.name);
+        let start = index * attribute.components;
+        attribute.data[start..start + attribute.components].copy_from_slice(value);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.transforms.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.transforms.is_empty();
+    }
+
+    /// Remove all instances, attributes and GPU buffers are kept
+    pub fn clear(&mut self) {
+        self.transforms.clear();
+        if let Some(colors) = &mut self.colors {
+            colors.clear();
+        }
+        for attribute in &mut self.attributes {
+            attribute.data.clear();
+        }
+    }
+
+    pub fn transforms(&self) -> &[Matrix] {
+        return &self.transforms;
+    }
+
+    pub fn colors(&self) -> Option<&[Color]> {
+        return self.colors.as_deref();
+    }
+
+    /// Draw every instance
+    pub fn draw(&mut self, mesh: &Mesh, material: &Material) {
+        self.submit(mesh, material, None);
+    }
+
+    /// Draw the instances whose bounds intersect the frustum, returns the number of instances drawn.
+    /// NOTE: Mesh bounds are computed from the CPU vertex data on every call.
+    pub fn draw_culled(&mut self, mesh: &Mesh, material: &Material, frustum: &Frustum) -> usize {
+        let bounds = mesh.bounding_box();
+        let center = (bounds.min + bounds.max) * 0.5;
+        let radius = (bounds.max - bounds.min).length() * 0.5;
+
+        let visible: Vec<usize> = (0..self.transforms.len())
+            .filter(|&index| {
+                let transform = &self.transforms[index];
+                let scale = [Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0)]
+                    .iter()
+                    .map(|&axis| transform.transform_vector(axis).length())
+                    .fold(0.0, f32::max);
+                return frustum.intersects_sphere(transform.transform_point(center), radius * scale);
+            })
+            .collect();
+
+        self.submit(mesh, material, Some(&visible));
+        return visible.len();
+    }
+
+    /// Upload the per-instance streams of the selected instances, attach them to the mesh and draw
+    fn submit(&mut self, mesh: &Mesh, material: &Material, visible: Option<&[usize]>) {
+        let count = visible.map_or(self.transforms.len(), |visible| visible.len());
+        if count == 0 {
+            return;
+        }
+
+        let shader = &material.shader;
+        let mut enabled = Vec::new();
+        rlgl::enable_vertex_array(mesh.vao_id);
+
+        let color_location = location_index(shader.attrib_location(Self::COLOR_ATTRIBUTE));
+        if let Some(location) = color_location {
+            match &self.colors {
+                Some(colors) => {
+                    self.color_buffer.upload(&gather(colors, 1, visible));
+                    rlgl::enable_vertex_buffer(self.color_buffer.id);
+                    rlgl::set_vertex_attribute(location, 4, DataType::UnsignedByte, true, 0, 0);
+                    rlgl::enable_vertex_attribute(location);
+                    rlgl::set_vertex_attribute_divisor(location, 1);
+                    enabled.push(location);
+                }
+                None => {
+                    rlgl::disable_vertex_attribute(location);
+                    rlgl::set_vertex_attribute_default(location, &[1.0; 4], ShaderAttributeDataType::Vec4);
+                }
+            }
+        }
+
+        for attribute in &mut self.attributes {
+            let Some(location) = location_index(shader.attrib_location(&attribute.name)) else {
+                continue;
+            };
+            attribute.buffer.upload(&gather(&attribute.data, attribute.components, visible));
+            rlgl::enable_vertex_buffer(attribute.buffer.id);
+            rlgl::set_vertex_attribute(location, attribute.components as i32, DataType::Float, false, 0, 0);
+            rlgl::enable_vertex_attribute(location);
+            rlgl::set_vertex_attribute_divisor(location, 1);
+            enabled.push(location);
+        }
+
+        rlgl::disable_vertex_buffer();
+        rlgl::disable_vertex_array();
+
+        mesh.draw_instanced(material, &gather(&self.transforms, 1, visible));
+
+        // Detach the instance streams so regular draws of the mesh are not affected
+        rlgl::enable_vertex_array(mesh.vao_id);
+        for location in enabled {
+            rlgl::set_vertex_attribute_divisor(location, 0);
+            rlgl::disable_vertex_attribute(location);
+        }
+        rlgl::disable_vertex_array();
+    }
+}
+
+impl Drop for InstanceBatch {
+    fn drop(&mut self) {
+        self.color_buffer.unload();
+        for attribute in &mut self.attributes {
+            attribute.buffer.unload();
+        }
+    }
+}
+
+/// Attribute index of a shader location, `None` if the shader does not use it
+fn location_index(location: ShaderLocation) -> Option<u32> {
+    let index: i32 = location.into();
+    return if index >= 0 { Some(index as u32) } else { None };
+}
+
+/// Copy the values of the selected instances (`stride` values per instance), all of them if `visible` is not set
+fn gather<'a, T: Copy>(data: &'a [T], stride: usize, visible: Option<&[usize]>) -> Cow<'a, [T]> {
+    let Some(visible) = visible else {
+        return Cow::Borrowed(data);
+    };
+    let mut gathered = Vec::with_capacity(visible.len() * stride);
+    for &index in visible {
+        gathered.extend_from_slice(&data[index * stride..(index + 1) * stride]);
+    }
+    return Cow::Owned(gathered);
+}
@@ -0,0 +1,178 @@
+mod builder;
+mod data;
+mod instancing;
+mod obj;
+mod ply;
+mod processing;
+
+pub use builder::*;
+pub use data::*;
+pub use instancing::*;
+pub use obj::*;
+pub use ply::*;
+pub use processing::*;
+
+use crate::{
+    BoundingBox, Color, Material, Matrix, Vector2, Vector3, Vector4, draw_mesh, draw_mesh_instanced, export_mesh,
+    export_mesh_as_code, gen_mesh_tangents, get_mesh_bounding_box, raw_slice, unload_mesh, update_mesh_buffer,
+    upload_mesh,
+};
+use std::ptr::null_mut;
+
+/// Mesh, vertex data and vao/vbo
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Mesh {
+    /// Number of vertices stored in arrays
+    pub vertex_count: i32,
+    /// Number of triangles stored (indexed or not)
+    pub triangle_count: i32,
+
+    /// Vertex and Attribute data
+    /// Vertex position (XYZ - 3 components per vertex) (shader-location = 0)
+    pub vertices: *mut f32,
+    /// Vertex texture coordinates (UV - 2 components per vertex) (shader-location = 1)
+    pub tex_coords: *mut f32,
+    /// Vertex texture second coordinates (UV - 2 components per vertex) (shader-location = 5)
+    pub tex_coords2: *mut f32,
+    /// Vertex normals (XYZ - 3 components per vertex) (shader-location = 2)
+    pub normals: *mut f32,
+    /// Vertex tangents (XYZW - 4 components per vertex) (shader-location = 4)
+    pub tangents: *mut f32,
+    /// Vertex colors (RGBA - 4 components per vertex) (shader-location = 3)
+    pub colors: *mut u8,
+    /// Vertex indices (in case vertex data comes indexed)
+    pub indices: *mut u16,
+
+    /// Animation vertex data
+    /// Animated vertex positions (after bones transformations)
+    pub anim_vertices: *mut f32,
+    /// Animated normals (after bones transformations)
+    pub anim_normals: *mut f32,
+    /// Vertex bone ids, max 255 bone ids, up to 4 bones influence by vertex (skinning) (shader-location = 6)
+    pub bone_ids: *mut u8,
+    /// Vertex bone weight, up to 4 bones influence by vertex (skinning) (shader-location = 7)
+    pub bone_weights: *mut f32,
+    /// Bones animated transformation matrices
+    pub bone_matrices: *mut Matrix,
+    /// Number of bones
+    pub bone_count: i32,
+
+    /// OpenGL identifiers
+    /// OpenGL Vertex Array Object id
+    pub vao_id: u32,
+    /// OpenGL Vertex Buffer Objects id (default vertex data)
+    pub vbo_id: *mut u32,
+}
+
+impl Mesh {
+    /// Mesh with no vertex data and no GPU buffers
+    pub fn empty() -> Mesh {
+        return Mesh {
+            vertex_count: 0,
+            triangle_count: 0,
+            vertices: null_mut(),
+            tex_coords: null_mut(),
+            tex_coords2: null_mut(),
+            normals: null_mut(),
+            tangents: null_mut(),
+            colors: null_mut(),
+            indices: null_mut(),
+            anim_vertices: null_mut(),
+            anim_normals: null_mut(),
+            bone_ids: null_mut(),
+            bone_weights: null_mut(),
+            bone_matrices: null_mut(),
+            bone_count: 0,
+            vao_id: 0,
+            vbo_id: null_mut(),
+        };
+    }
+
+    // Vertex data views
+
+    pub fn vertex_count(&self) -> usize {
+        return self.vertex_count.max(0) as usize;
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        return self.triangle_count.max(0) as usize;
+    }
+
+    pub fn positions(&self) -> &[Vector3] {
+        return unsafe { raw_slice(self.vertices as *const Vector3, self.vertex_count) };
+    }
+
+    pub fn tex_coords(&self) -> Option<&[Vector2]> {
+        return unsafe { optional_slice(self.tex_coords as *const Vector2, self.vertex_count) };
+    }
+
+    pub fn tex_coords2(&self) -> Option<&[Vector2]> {
+        return unsafe { optional_slice(self.tex_coords2 as *const Vector2, self.vertex_count) };
+    }
+
+    pub fn normals(&self) -> Option<&[Vector3]> {
+        return unsafe { optional_slice(self.normals as *const Vector3, self.vertex_count) };
+    }
+
+    pub fn tangents(&self) -> Option<&[Vector4]> {
+        return unsafe { optional_slice(self.tangents as *const Vector4, self.vertex_count) };
+    }
+
+    pub fn colors(&self) -> Option<&[Color]> {
+        return unsafe { optional_slice(self.colors as *const Color, self.vertex_count) };
+    }
+
+    /// Triangle vertex indices, `None` for non-indexed meshes
+    pub fn indices(&self) -> Option<&[u16]> {
+        return unsafe { optional_slice(self.indices as *const u16, self.triangle_count * 3) };
+    }
+
+    // Management
+
+    pub fn upload(&mut self, dynamic: bool) {
+        upload_mesh(self, dynamic);
+    }
+
+    pub fn update_buffer<T>(&self, index: i32, data: &[T], offset: i32) {
+        update_mesh_buffer(*self, index, data, offset);
+    }
+
+    pub fn unload(self) {
+        unload_mesh(self);
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        return get_mesh_bounding_box(*self);
+    }
+
+    pub fn gen_tangents(&mut self) {
+        gen_mesh_tangents(self);
+    }
+
+    pub fn export(&self, filename: &str) -> bool {
+        return export_mesh(*self, filename);
+    }
+
+    pub fn export_as_code(&self, filename: &str) -> bool {
+        return export_mesh_as_code(*self, filename);
+    }
+
+    // Drawing
+
+    pub fn draw(&self, material: &Material, transform: Matrix) {
+        draw_mesh(*self, material.shallow_copy(), transform);
+    }
+
+    pub fn draw_instanced(&self, material: &Material, transforms: &[Matrix]) {
+        draw_mesh_instanced(*self, material.shallow_copy(), transforms);
+    }
+}
+
+/// Build a slice from an optional vertex attribute array
+unsafe fn optional_slice<'a, T>(ptr: *const T, count: i32) -> Option<&'a [T]> {
+    if ptr.is_null() {
+        return None;
+    }
+    return Some(unsafe { raw_slice(ptr, count) });
+}
@@ -0,0 +1,689 @@
+use crate::{
+    Color, Material, MaterialMapIndex, MeshData, Texture2D, Vector2, Vector3, load_file_text, load_material_default,
+    save_file_text,
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Mesh read from (or written to) a Wavefront OBJ file, one per object/group and material
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjMesh {
+    /// Object or group name (`o`/`g`)
+    pub name: String,
+    /// Name of the material used by the mesh (`usemtl`)
+    pub material: Option<String>,
+    pub data: MeshData,
+}
+
+/// Wavefront OBJ file contents.
+/// Vertex colors use the common `v x y z r g b` extension, a second UV set is stored in `#vt2 u v`
+/// lines following the `vt` line they belong to (other readers see them as comments).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjFile {
+    /// Material libraries referenced with `mtllib`
+    pub material_libraries: Vec<String>,
+    pub meshes: Vec<ObjMesh>,
+}
+
+/// Vertex attribute lists shared by all the meshes of an OBJ file
+#[derive(Default)]
+struct ObjAttributes {
+    positions: Vec<Vector3>,
+    colors: Vec<Option<Color>>,
+    tex_coords: Vec<Vector2>,
+    /// Second UV set of each `vt` line, if a `#vt2` line follows it
+    tex_coords2: Vec<Option<Vector2>>,
+    normals: Vec<Vector3>,
+}
+
+/// Face corner: position, texture coordinate and normal indices
+type ObjCorner = (usize, Option<usize>, Option<usize>);
+
+/// Mesh being read, with one vertex per distinct face corner
+struct ObjMeshAssembler {
+    name: String,
+    material: Option<String>,
+    vertices: HashMap<ObjCorner, u32>,
+    corners: Vec<ObjCorner>,
+    indices: Vec<u32>,
+}
+
+impl ObjMeshAssembler {
+    fn new(name: String, material: Option<String>) -> Self {
+        return Self { name, material, vertices: HashMap::new(), corners: Vec::new(), indices: Vec::new() };
+    }
+
+    fn add_corner(&mut self, corner: ObjCorner) {
+        let next_index = self.corners.len() as u32;
+        let index = *self.vertices.entry(corner).or_insert(next_index);
+        if index == next_index {
+            self.corners.push(corner);
+        }
+        self.indices.push(index);
+    }
+
+    fn finish(self, attributes: &ObjAttributes) -> ObjMesh {
+        let corners = &self.corners;
+        let has_tex_coords = corners.iter().any(|corner| corner.1.is_some());
+        let has_normals = corners.iter().any(|corner| corner.2.is_some());
+        let has_tex_coords2 =
+            corners.iter().any(|corner| corner.1.is_some_and(|index| attributes.tex_coords2[index].is_some()));
+        let has_colors = corners.iter().any(|corner| attributes.colors[corner.0].is_some());
+
+        let tex_coord = |corner: &ObjCorner| corner.1.map_or(Vector2::zero(), |index| attributes.tex_coords[index]);
+        let tex_coord2 = |corner: &ObjCorner| {
+            return corner.1.and_then(|index| attributes.tex_coords2[index]).unwrap_or(Vector2::zero());
+        };
+
+        let data = MeshData {
+            positions: corners.iter().map(|corner| attributes.positions[corner.0]).collect(),
+            normals: has_normals.then(|| {
+                corners.iter().map(|corner| corner.2.map_or(Vector3::zero(), |index| attributes.normals[index])).collect()
+            }),
+            tex_coords: has_tex_coords.then(|| corners.iter().map(tex_coord).collect()),
+            tex_coords2: has_tex_coords2.then(|| corners.iter().map(tex_coord2).collect()),
+            colors: has_colors
+                .then(|| corners.iter().map(|corner| attributes.colors[corner.0].unwrap_or(Color::WHITE)).collect()),
+            indices: self.indices,
+        };
+
+        return ObjMesh { name: self.name, material: self.material, data };
+    }
+}
+
+impl ObjFile {
+    pub fn load(filename: &str) -> Result<ObjFile, String> {
+        let text = load_file_text(filename)?;
+        return ObjFile::parse(&text).map_err(|error| format!("{}: {}", filename, error));
+    }
+
+    /// Parse OBJ text, polygons are triangulated as fans and errors are reported as `line N: ...`
+    pub fn parse(text: &str) -> Result<ObjFile, String> {
+        let mut file = ObjFile::default();
+        let mut attributes = ObjAttributes::default();
+        let mut current = ObjMeshAssembler::new(String::new(), None);
+
+        for (line_index, line) in text.lines().enumerate() {
+            let line_error = |message: String| format!("line {}: {}", line_index + 1, message);
+
+            let line = line.trim();
+            let line = match line.starts_with("#vt2") {
+                true => line,
+                false => line.split('#').next().unwrap_or(""),
+            };
+            let mut tokens = line.split_whitespace();
+            let Some(keyword) = tokens.next() else {
+                continue;
+            };
+            let args: Vec<&str> = tokens.collect();
+
+            match keyword {
+                "v" => {
+                    let values = parse_floats(&args).map_err(line_error)?;
+                    let color = match values.len() {
+                        3 | 4 => None,
+                        6 => Some(Color::new(unit_to_byte(values[3]), unit_to_byte(values[4]), unit_to_byte(values[5]), 255)),
+                        7 => Some(Color::new(
+                            unit_to_byte(values[3]),
+                            unit_to_byte(values[4]),
+                            unit_to_byte(values[5]),
+                            unit_to_byte(values[6]),
+                        )),
+                        count => return Err(line_error(format!("vertex has {} values, expected 3, 4, 6 or 7", count))),
+                    };
+                    attributes.positions.push(Vector3::new(values[0], values[1], values[2]));
+                    attributes.colors.push(color);
+                }
+                "vt" | "#vt2" => {
+                    let values = parse_floats(&args).map_err(line_error)?;
+                    if values.is_empty() || values.len() > 3 {
+                        let message = format!("texture coordinate has {} values, expected 1 to 3", values.len());
+                        return Err(line_error(message));
+                    }
+                    // NOTE: OBJ texture coordinates start at the bottom of the image, raylib's at the top
+                    let tex_coord = Vector2::new(values[0], 1.0 - values.get(1).copied().unwrap_or(0.0));
+                    if keyword == "vt" {
+                        attributes.tex_coords.push(tex_coord);
+                        attributes.tex_coords2.push(None);
+                    } else {
+                        let Some(tex_coord2) = attributes.tex_coords2.last_mut() else {
+                            return Err(line_error("'#vt2' before any 'vt'".to_string()));
+                        };
+                        *tex_coord2 = Some(tex_coord);
+                    }
+                }
+                "vn" => {
+                    let values = parse_floats(&args).map_err(line_error)?;
+                    if values.len() != 3 {
+                        return Err(line_error(format!("normal has {} values, expected 3", values.len())));
+                    }
+                    attributes.normals.push(Vector3::new(values[0], values[1], values[2]));
+                }
+                "f" => {
+                    if args.len() < 3 {
+                        return Err(line_error(format!("face has {} vertices, expected at least 3", args.len())));
+                    }
+                    let corners = args
+                        .iter()
+                        .map(|arg| parse_corner(arg, &attributes))
+                        .collect::<Result<Vec<ObjCorner>, String>>()
+                        .map_err(line_error)?;
+                    for i in 1..corners.len() - 1 {
+                        current.add_corner(corners[0]);
+                        current.add_corner(corners[i]);
+                        current.add_corner(corners[i + 1]);
+                    }
+                }
+                "o" | "g" | "usemtl" => {
+                    let (mut name, mut material) = (current.name.clone(), current.material.clone());
+                    match keyword {
+                        // NOTE: A bare `usemtl` goes back to no material
+                        "usemtl" => material = (!args.is_empty()).then(|| args.join(" ")),
+                        _ => name = args.join(" "),
+                    }
+                    if current.indices.is_empty() {
+                        current.name = name;
+                        current.material = material;
+                    } else {
+                        let finished = std::mem::replace(&mut current, ObjMeshAssembler::new(name, material));
+                        file.meshes.push(finished.finish(&attributes));
+                    }
+                }
+                "mtllib" => {
+                    file.material_libraries.extend(args.iter().map(|arg| arg.to_string()));
+                }
+                // Smoothing groups, lines, points and free-form geometry are not supported
+                _ => {}
+            }
+        }
+
+        if !current.indices.is_empty() {
+            file.meshes.push(current.finish(&attributes));
+        }
+
+        return Ok(file);
+    }
+
+    /// Load the materials of every `mtllib` library, paths are relative to `directory`
+    pub fn load_materials(&self, directory: &str) -> Result<Vec<ObjMaterial>, String> {
+        let mut materials = Vec::new();
+        for library in &self.material_libraries {
+            let path = Path::new(directory).join(library);
+            materials.extend(ObjMaterial::load_library(&path.to_string_lossy())?);
+        }
+        return Ok(materials);
+    }
+
+    /// OBJ text of all the meshes, texture coordinates and normals are written only when present
+    pub fn write(&self) -> String {
+        let mut text = String::new();
+        for library in &self.material_libraries {
+            writeln!(text, "mtllib {}", library).unwrap();
+        }
+
+        // NOTE: Positions, texture coordinates and normals are numbered separately across the whole file
+        let (mut position_offset, mut tex_coord_offset, mut normal_offset) = (1, 1, 1);
+        for (mesh_index, mesh) in self.meshes.iter().enumerate() {
+            let data = &mesh.data;
+            match mesh.name.is_empty() {
+                true => writeln!(text, "o mesh{}", mesh_index).unwrap(),
+                false => writeln!(text, "o {}", mesh.name).unwrap(),
+            }
+            match &mesh.material {
+                Some(material) => writeln!(text, "usemtl {}", material).unwrap(),
+                None if mesh_index > 0 && self.meshes[mesh_index - 1].material.is_some() => text.push_str("usemtl\n"),
+                None => {}
+            }
+
+            for (i, position) in data.positions.iter().enumerate() {
+                write!(text, "v {} {} {}", position.x, position.y, position.z).unwrap();
+                if let Some(colors) = &data.colors {
+                    let color = colors[i];
+                    let (r, g, b, a) = (byte_to_unit(color.r), byte_to_unit(color.g), byte_to_unit(color.b), color.a);
+                    match a {
+                        255 => write!(text, " {} {} {}", r, g, b).unwrap(),
+                        _ => write!(text, " {} {} {} {}", r, g, b, byte_to_unit(a)).unwrap(),
+                    }
+                }
+                text.push('\n');
+            }
+            for (i, tex_coord) in data.tex_coords.iter().flatten().enumerate() {
+                writeln!(text, "vt {} {}", tex_coord.x, 1.0 - tex_coord.y).unwrap();
+                if let Some(tex_coord2) = data.tex_coords2.as_ref().and_then(|tex_coords2| tex_coords2.get(i)) {
+                    writeln!(text, "#vt2 {} {}", tex_coord2.x, 1.0 - tex_coord2.y).unwrap();
+                }
+            }
+            for normal in data.normals.iter().flatten() {
+                writeln!(text, "vn {} {} {}", normal.x, normal.y, normal.z).unwrap();
+            }
+
+            // NOTE: Every vertex has its own position, texture coordinate and normal, one index maps to all three
+            for triangle in data.indices.chunks_exact(3) {
+                text.push('f');
+                for &index in triangle {
+                    let index = index as usize;
+                    let (v, vt, vn) = (position_offset + index, tex_coord_offset + index, normal_offset + index);
+                    match (data.tex_coords.is_some(), data.normals.is_some()) {
+                        (false, false) => write!(text, " {}", v).unwrap(),
+                        (true, false) => write!(text, " {}/{}", v, vt).unwrap(),
+                        (false, true) => write!(text, " {}//{}", v, vn).unwrap(),
+                        (true, true) => write!(text, " {}/{}/{}", v, vt, vn).unwrap(),
+                    }
+                }
+                text.push('\n');
+            }
+
+            position_offset += data.positions.len();
+            tex_coord_offset += data.tex_coords.as_ref().map_or(0, Vec::len);
+            normal_offset += data.normals.as_ref().map_or(0, Vec::len);
+        }
+
+        return text;
+    }
+
+    pub fn save(&self, filename: &str) -> Result<(), String> {
+        if !save_file_text(filename, &self.write()) {
+            return Err(format!("Failed to save OBJ file: {}", filename));
+        }
+        return Ok(());
+    }
+}
+
+fn parse_floats(args: &[&str]) -> Result<Vec<f32>, String> {
+    return args
+        .iter()
+        .map(|arg| arg.parse::<f32>().map_err(|_| format!("invalid number '{}'", arg)))
+        .collect();
+}
+
+fn parse_corner(token: &str, attributes: &ObjAttributes) -> Result<ObjCorner, String> {
+    let parts: Vec<&str> = token.split('/').collect();
+    if parts.len() > 3 {
+        return Err(format!("invalid face vertex '{}'", token));
+    }
+
+    let position = resolve_index(parts[0], attributes.positions.len(), "vertex")?;
+    let tex_coord = match parts.get(1) {
+        Some(part) if !part.is_empty() => Some(resolve_index(part, attributes.tex_coords.len(), "texture coordinate")?),
+        _ => None,
+    };
+    let normal = match parts.get(2) {
+        Some(part) if !part.is_empty() => Some(resolve_index(part, attributes.normals.len(), "normal")?),
+        _ => None,
+    };
+
+    return Ok((position, tex_coord, normal));
+}
+
+/// Resolve a 1-based (or negative, relative to the end) OBJ index
+fn resolve_index(token: &str, count: usize, kind: &str) -> Result<usize, String> {
+    let index: i64 = token.parse().map_err(|_| format!("invalid {} index '{}'", kind, token))?;
+    let resolved = if index > 0 { index - 1 } else { count as i64 + index };
+    if index == 0 || resolved < 0 || resolved >= count as i64 {
+        return Err(format!("{} index {} is out of range ({} defined)", kind, index, count));
+    }
+    return Ok(resolved as usize);
+}
+
+fn unit_to_byte(value: f32) -> u8 {
+    return (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+}
+
+fn byte_to_unit(value: u8) -> f32 {
+    return value as f32 / 255.0;
+}
+
+// ---------------------------------------------------------------------------------
+// MTL material libraries
+// ---------------------------------------------------------------------------------
+
+/// Material of an MTL library, texture maps are file names relative to the library
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjMaterial {
+    pub name: String,
+    /// Ambient color (`Ka`)
+    pub ambient: Color,
+    /// Diffuse color (`Kd`)
+    pub diffuse: Color,
+    /// Specular color (`Ks`)
+    pub specular: Color,
+    /// Emissive color (`Ke`)
+    pub emission: Color,
+    /// Specular exponent (`Ns`)
+    pub shininess: f32,
+    /// Dissolve factor (`d`, or `1 - Tr`)
+    pub opacity: f32,
+    /// PBR roughness (`Pr`)
+    pub roughness: Option<f32>,
+    /// PBR metalness (`Pm`)
+    pub metalness: Option<f32>,
+    pub diffuse_map: Option<String>,
+    pub specular_map: Option<String>,
+    /// Normal map (`norm`, `bump` or `map_Bump`)
+    pub normal_map: Option<String>,
+    pub emission_map: Option<String>,
+    pub roughness_map: Option<String>,
+    pub metalness_map: Option<String>,
+}
+
+impl ObjMaterial {
+    pub fn new(name: &str) -> Self {
+        return Self {
+            name: name.to_string(),
+            ambient: Color::BLACK,
+            diffuse: Color::WHITE,
+            specular: Color::BLACK,
+            emission: Color::BLACK,
+            shininess: 0.0,
+            opacity: 1.0,
+            roughness: None,
+            metalness: None,
+            diffuse_map: None,
+            specular_map: None,
+            normal_map: None,
+            emission_map: None,
+            roughness_map: None,
+            metalness_map: None,
+        };
+    }
+
+    pub fn load_library(filename: &str) -> Result<Vec<ObjMaterial>, String> {
+        let text = load_file_text(filename)?;
+        return ObjMaterial::parse_library(&text).map_err(|error| format!("{}: {}", filename, error));
+    }
+
+    /// Parse MTL text, errors are reported as `line N: ...`
+    pub fn parse_library(text: &str) -> Result<Vec<ObjMaterial>, String> {
+        let mut materials: Vec<ObjMaterial> = Vec::new();
+
+        for (line_index, line) in text.lines().enumerate() {
+            let line_error = |message: String| format!("line {}: {}", line_index + 1, message);
+
+            let line = line.split('#').next().unwrap_or("").trim();
+            let mut tokens = line.split_whitespace();
+            let Some(keyword) = tokens.next() else {
+                continue;
+            };
+            let args: Vec<&str> = tokens.collect();
+
+            if keyword == "newmtl" {
+                if args.is_empty() {
+                    return Err(line_error("material without a name".to_string()));
+                }
+                materials.push(ObjMaterial::new(&args.join(" ")));
+                continue;
+            }
+
+            let Some(material) = materials.last_mut() else {
+                return Err(line_error(format!("'{}' before any 'newmtl'", keyword)));
+            };
+            match keyword {
+                "Ka" => material.ambient = parse_color(&args).map_err(line_error)?,
+                "Kd" => material.diffuse = parse_color(&args).map_err(line_error)?,
+                "Ks" => material.specular = parse_color(&args).map_err(line_error)?,
+                "Ke" => material.emission = parse_color(&args).map_err(line_error)?,
+                "Ns" => material.shininess = parse_scalar(&args).map_err(line_error)?,
+                "d" => material.opacity = parse_scalar(&args).map_err(line_error)?,
+                "Tr" => material.opacity = 1.0 - parse_scalar(&args).map_err(line_error)?,
+                "Pr" => material.roughness = Some(parse_scalar(&args).map_err(line_error)?),
+                "Pm" => material.metalness = Some(parse_scalar(&args).map_err(line_error)?),
+                "map_Kd" => material.diffuse_map = Some(parse_map(&args).map_err(line_error)?),
+                "map_Ks" => material.specular_map = Some(parse_map(&args).map_err(line_error)?),
+                "norm" | "bump" | "map_Bump" | "map_bump" => {
+                    material.normal_map = Some(parse_map(&args).map_err(line_error)?)
+                }
+                "map_Ke" => material.emission_map = Some(parse_map(&args).map_err(line_error)?),
+                "map_Pr" => material.roughness_map = Some(parse_map(&args).map_err(line_error)?),
+                "map_Pm" => material.metalness_map = Some(parse_map(&args).map_err(line_error)?),
+                // Illumination models, transmission filters and other maps are not supported
+                _ => {}
+            }
+        }
+
+        return Ok(materials);
+    }
+
+    pub fn write_library(materials: &[ObjMaterial]) -> String {
+        let mut text = String::new();
+        for material in materials {
+            let color = |color: Color| {
+                return format!("{} {} {}", byte_to_unit(color.r), byte_to_unit(color.g), byte_to_unit(color.b));
+            };
+
+            writeln!(text, "newmtl {}", material.name).unwrap();
+            writeln!(text, "Ka {}", color(material.ambient)).unwrap();
+            writeln!(text, "Kd {}", color(material.diffuse)).unwrap();
+            writeln!(text, "Ks {}", color(material.specular)).unwrap();
+            writeln!(text, "Ke {}", color(material.emission)).unwrap();
+            writeln!(text, "Ns {}", material.shininess).unwrap();
+            writeln!(text, "d {}", material.opacity).unwrap();
+            if let Some(roughness) = material.roughness {
+                writeln!(text, "Pr {}", roughness).unwrap();
+            }
+            if let Some(metalness) = material.metalness {
+                writeln!(text, "Pm {}", metalness).unwrap();
+            }
+
+            let maps = [
+                ("map_Kd", &material.diffuse_map),
+                ("map_Ks", &material.specular_map),
+                ("norm", &material.normal_map),
+                ("map_Ke", &material.emission_map),
+                ("map_Pr", &material.roughness_map),
+                ("map_Pm", &material.metalness_map),
+            ];
+            for (keyword, map) in maps {
+                if let Some(map) = map {
+                    writeln!(text, "{} {}", keyword, map).unwrap();
+                }
+            }
+            text.push('\n');
+        }
+        return text;
+    }
+
+    pub fn save_library(filename: &str, materials: &[ObjMaterial]) -> Result<(), String> {
+        if !save_file_text(filename, &ObjMaterial::write_library(materials)) {
+            return Err(format!("Failed to save MTL file: {}", filename));
+        }
+        return Ok(());
+    }
+
+    /// Material description from the colors and values of a raylib material (texture file names are unknown)
+    pub fn from_material(name: &str, material: &Material) -> ObjMaterial {
+        let albedo = material.color(MaterialMapIndex::Albedo).unwrap_or(Color::WHITE);
+        let mut result = ObjMaterial::new(name);
+        result.diffuse = Color::new(albedo.r, albedo.g, albedo.b, 255);
+        result.opacity = byte_to_unit(albedo.a);
+        result.specular = material.color(MaterialMapIndex::Specular).unwrap_or(result.specular);
+        result.emission = material.color(MaterialMapIndex::Emission).unwrap_or(result.emission);
+        result.metalness = material.value(MaterialMapIndex::Metalness);
+        result.roughness = material.value(MaterialMapIndex::Roughness);
+        return result;
+    }
+
+    /// Create a raylib material, map textures are loaded from `texture_directory` and owned by the material.
+    /// Textures that fail to load are left unset.
+    pub fn to_material(&self, texture_directory: &str) -> Material {
+        let mut material = load_material_default();
+        let diffuse = self.diffuse;
+        let opacity = unit_to_byte(self.opacity);
+        material.set_color(MaterialMapIndex::Albedo, Color::new(diffuse.r, diffuse.g, diffuse.b, opacity));
+        material.set_color(MaterialMapIndex::Specular, self.specular);
+        material.set_color(MaterialMapIndex::Emission, self.emission);
+        material.set_value(MaterialMapIndex::Metalness, self.metalness.unwrap_or(0.0));
+        material.set_value(MaterialMapIndex::Roughness, self.roughness.unwrap_or(0.5));
+
+        let maps = [
+            (MaterialMapIndex::Albedo, &self.diffuse_map),
+            (MaterialMapIndex::Specular, &self.specular_map),
+            (MaterialMapIndex::Normal, &self.normal_map),
+            (MaterialMapIndex::Emission, &self.emission_map),
+            (MaterialMapIndex::Roughness, &self.roughness_map),
+            (MaterialMapIndex::Metalness, &self.metalness_map),
+        ];
+        for (index, map) in maps {
+            let Some(map) = map else {
+                continue;
+            };
+            let path = Path::new(texture_directory).join(map);
+            let texture = Texture2D::load(&path.to_string_lossy());
+            if texture.is_valid() {
+                material.attach_texture(index, texture);
+            }
+        }
+
+        return material;
+    }
+}
+
+impl Default for ObjMaterial {
+    fn default() -> Self {
+        return Self::new("");
+    }
+}
+
+fn parse_scalar(args: &[&str]) -> Result<f32, String> {
+    let values = parse_floats(args)?;
+    if values.len() != 1 {
+        return Err(format!("expected 1 value, found {}", values.len()));
+    }
+    return Ok(values[0]);
+}
+
+fn parse_color(args: &[&str]) -> Result<Color, String> {
+    if matches!(args.first(), Some(&"spectral") | Some(&"xyz")) {
+        return Err(format!("unsupported '{}' color", args[0]));
+    }
+    let values = parse_floats(args)?;
+    let (r, g, b) = match values.len() {
+        1 => (values[0], values[0], values[0]),
+        3 => (values[0], values[1], values[2]),
+        count => return Err(format!("color has {} values, expected 1 or 3", count)),
+    };
+    return Ok(Color::new(unit_to_byte(r), unit_to_byte(g), unit_to_byte(b), 255));
+}
+
+/// Texture file name of a map statement, skipping options such as `-bm 0.5` or `-s 1 1 1`
+fn parse_map(args: &[&str]) -> Result<String, String> {
+    let mut i = 0;
+    while i < args.len() && args[i].starts_with('-') {
+        let option = args[i];
+        i += 1;
+        match option {
+            "-imfchan" | "-type" | "-blendu" | "-blendv" | "-clamp" | "-cc" => i += 1,
+            _ => {
+                while i < args.len() && args[i].parse::<f32>().is_ok() {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    if i >= args.len() {
+        return Err("texture map without a file name".to_string());
+    }
+    return Ok(args[i..].join(" "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quad(z: f32) -> MeshData {
+        return MeshData {
+            positions: vec![
+                Vector3::new(0.0, 0.0, z),
+                Vector3::new(1.0, 0.0, z),
+                Vector3::new(1.0, 1.0, z),
+                Vector3::new(0.0, 1.0, z),
+            ],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            ..MeshData::default()
+        };
+    }
+
+    fn tex_coords() -> Vec<Vector2> {
+        return vec![Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 0.5)];
+    }
+
+    #[test]
+    fn round_trip_mixed_attributes() {
+        let mut normals = quad(0.0);
+        normals.normals = Some(vec![Vector3::new(0.0, 0.0, 1.0); 4]);
+
+        let mut colored = quad(1.0);
+        colored.tex_coords = Some(tex_coords());
+        colored.tex_coords2 = Some(tex_coords().into_iter().rev().collect());
+        colored.colors = Some(vec![Color::RED, Color::new(0, 128, 255, 255), Color::new(10, 20, 30, 51), Color::WHITE]);
+
+        let mut textured = quad(2.0);
+        textured.tex_coords = Some(tex_coords());
+        textured.normals = Some(vec![Vector3::new(0.0, 1.0, 0.0); 4]);
+
+        let file = ObjFile {
+            material_libraries: vec!["scene.mtl".to_string()],
+            meshes: vec![
+                ObjMesh { name: "normals".to_string(), material: None, data: normals },
+                ObjMesh { name: "colored".to_string(), material: Some("paint".to_string()), data: colored },
+                ObjMesh { name: "plain".to_string(), material: None, data: quad(3.0) },
+                ObjMesh { name: "textured".to_string(), material: Some("wood".to_string()), data: textured },
+            ],
+        };
+
+        assert_eq!(ObjFile::parse(&file.write()), Ok(file));
+    }
+
+    #[test]
+    fn parse_polygons_and_relative_indices() {
+        let text = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nvt 0 0\nvt 1 1\nf 1/1 2/2 3/2 -1/-2\n";
+        let file = ObjFile::parse(text).unwrap();
+        assert_eq!(file.meshes.len(), 1);
+
+        let data = &file.meshes[0].data;
+        assert_eq!(data.positions.len(), 4);
+        assert_eq!(data.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(data.tex_coords.as_ref().unwrap()[3], Vector2::new(0.0, 1.0));
+        assert_eq!(data.normals, None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = ObjFile::parse("v 0 0 0\nf 1 2 3\n").unwrap_err();
+        assert_eq!(error, "line 2: vertex index 2 is out of range (1 defined)");
+        assert_eq!(ObjFile::parse("v 0 0\n").unwrap_err(), "line 1: vertex has 2 values, expected 3, 4, 6 or 7");
+        assert_eq!(ObjFile::parse("#vt2 0 0\n").unwrap_err(), "line 1: '#vt2' before any 'vt'");
+    }
+
+    #[test]
+    fn material_library_round_trip() {
+        let mut brick = ObjMaterial::new("brick");
+        brick.ambient = Color::new(51, 51, 51, 255);
+        brick.diffuse = Color::new(200, 100, 50, 255);
+        brick.specular = Color::WHITE;
+        brick.shininess = 32.0;
+        brick.opacity = 0.5;
+        brick.diffuse_map = Some("brick.png".to_string());
+        brick.normal_map = Some("brick_normal.png".to_string());
+
+        let mut metal = ObjMaterial::new("metal");
+        metal.emission = Color::new(0, 0, 255, 255);
+        metal.roughness = Some(0.25);
+        metal.metalness = Some(1.0);
+        metal.roughness_map = Some("metal_roughness.png".to_string());
+        metal.metalness_map = Some("metal_metalness.png".to_string());
+
+        let materials = vec![brick, metal];
+        assert_eq!(ObjMaterial::parse_library(&ObjMaterial::write_library(&materials)), Ok(materials));
+    }
+
+    #[test]
+    fn material_library_map_options() {
+        let text = "newmtl a\nTr 0.25\nmap_Kd -s 1 1 1 -bm 0.5 textures/a b.png\n";
+        let materials = ObjMaterial::parse_library(text).unwrap();
+        assert_eq!(materials[0].opacity, 0.75);
+        assert_eq!(materials[0].diffuse_map.as_deref(), Some("textures/a b.png"));
+        assert!(ObjMaterial::parse_library("Kd 1 1 1\n").is_err());
+    }
+}
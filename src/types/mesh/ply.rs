@@ -0,0 +1,592 @@
+use crate::{Color, MeshData, Vector2, Vector3, load_file_data, save_file_data};
+
+/// PLY file encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlyFormat {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+/// PLY property data type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlyType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl PlyType {
+    fn parse(name: &str) -> Option<PlyType> {
+        match name {
+            "char" | "int8" => return Some(PlyType::I8),
+            "uchar" | "uint8" => return Some(PlyType::U8),
+            "short" | "int16" => return Some(PlyType::I16),
+            "ushort" | "uint16" => return Some(PlyType::U16),
+            "int" | "int32" => return Some(PlyType::I32),
+            "uint" | "uint32" => return Some(PlyType::U32),
+            "float" | "float32" => return Some(PlyType::F32),
+            "double" | "float64" => return Some(PlyType::F64),
+            _ => return None,
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            PlyType::I8 | PlyType::U8 => return 1,
+            PlyType::I16 | PlyType::U16 => return 2,
+            PlyType::I32 | PlyType::U32 | PlyType::F32 => return 4,
+            PlyType::F64 => return 8,
+        }
+    }
+
+    fn is_float(self) -> bool {
+        return matches!(self, PlyType::F32 | PlyType::F64);
+    }
+}
+
+struct PlyProperty {
+    name: String,
+    kind: PlyType,
+    /// Type of the item count for list properties
+    list_count: Option<PlyType>,
+}
+
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<PlyProperty>,
+}
+
+/// Vertex attribute component a PLY vertex property is read into
+#[derive(Clone, Copy)]
+enum PlyTarget {
+    Position(usize),
+    Normal(usize),
+    TexCoord(usize),
+    TexCoord2(usize),
+    Color(usize),
+}
+
+impl PlyTarget {
+    fn from_name(name: &str) -> Option<PlyTarget> {
+        match name {
+            "x" => return Some(PlyTarget::Position(0)),
+            "y" => return Some(PlyTarget::Position(1)),
+            "z" => return Some(PlyTarget::Position(2)),
+            "nx" => return Some(PlyTarget::Normal(0)),
+            "ny" => return Some(PlyTarget::Normal(1)),
+            "nz" => return Some(PlyTarget::Normal(2)),
+            "s" | "u" | "texture_u" | "texture_s" => return Some(PlyTarget::TexCoord(0)),
+            "t" | "v" | "texture_v" | "texture_t" => return Some(PlyTarget::TexCoord(1)),
+            "s1" | "u1" | "texture_u1" | "texture_s1" => return Some(PlyTarget::TexCoord2(0)),
+            "t1" | "v1" | "texture_v1" | "texture_t1" => return Some(PlyTarget::TexCoord2(1)),
+            "red" | "diffuse_red" => return Some(PlyTarget::Color(0)),
+            "green" | "diffuse_green" => return Some(PlyTarget::Color(1)),
+            "blue" | "diffuse_blue" => return Some(PlyTarget::Color(2)),
+            "alpha" | "diffuse_alpha" => return Some(PlyTarget::Color(3)),
+            _ => return None,
+        }
+    }
+}
+
+/// Reader over the element data following the header
+struct PlyBody<'a> {
+    format: PlyFormat,
+    data: &'a [u8],
+    offset: usize,
+    /// Current line number (ASCII)
+    line: usize,
+    tokens: Vec<&'a str>,
+    token: usize,
+}
+
+impl<'a> PlyBody<'a> {
+    /// Error message located at the current line (ASCII) or byte offset (binary)
+    fn error(&self, message: String) -> String {
+        match self.format {
+            PlyFormat::Ascii => return format!("line {}: {}", self.line, message),
+            _ => return format!("byte {}: {}", self.offset, message),
+        }
+    }
+
+    /// Start reading an element instance, ASCII instances are stored one per line
+    fn begin_instance(&mut self) -> Result<(), String> {
+        if self.format != PlyFormat::Ascii {
+            return Ok(());
+        }
+
+        loop {
+            if self.offset >= self.data.len() {
+                return Err(self.error("unexpected end of file".to_string()));
+            }
+            let end = self.data[self.offset..].iter().position(|&c| c == b'\n').map_or(self.data.len(), |end| self.offset + end);
+            let line = std::str::from_utf8(&self.data[self.offset..end]).map_err(|_| self.error("invalid text".to_string()));
+            self.offset = end + 1;
+            self.line += 1;
+            self.tokens = line?.split_whitespace().collect();
+            self.token = 0;
+            if !self.tokens.is_empty() {
+                return Ok(());
+            }
+        }
+    }
+
+    fn end_instance(&mut self) -> Result<(), String> {
+        if self.format == PlyFormat::Ascii && self.token != self.tokens.len() {
+            let message = format!("expected {} values, found {}", self.token, self.tokens.len());
+            return Err(self.error(message));
+        }
+        return Ok(());
+    }
+
+    fn read(&mut self, kind: PlyType) -> Result<f64, String> {
+        if self.format == PlyFormat::Ascii {
+            let Some(token) = self.tokens.get(self.token) else {
+                return Err(self.error(format!("expected more than {} values", self.tokens.len())));
+            };
+            let value = match kind.is_float() {
+                true => token.parse::<f64>().ok(),
+                false => token.parse::<i64>().ok().map(|value| value as f64),
+            };
+            let Some(value) = value else {
+                return Err(self.error(format!("invalid {:?} value '{}'", kind, token)));
+            };
+            self.token += 1;
+            return Ok(value);
+        }
+
+        let size = kind.size();
+        if self.offset + size > self.data.len() {
+            return Err(self.error("unexpected end of file".to_string()));
+        }
+        let mut bytes = [0u8; 8];
+        bytes[..size].copy_from_slice(&self.data[self.offset..self.offset + size]);
+        if self.format == PlyFormat::BinaryBigEndian {
+            bytes[..size].reverse();
+        }
+        self.offset += size;
+
+        let value = match kind {
+            PlyType::I8 => bytes[0] as i8 as f64,
+            PlyType::U8 => bytes[0] as f64,
+            PlyType::I16 => i16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+            PlyType::U16 => u16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+            PlyType::I32 => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+            PlyType::U32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+            PlyType::F32 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+            PlyType::F64 => f64::from_le_bytes(bytes),
+        };
+        return Ok(value);
+    }
+
+    /// Read a list property: item count followed by the items
+    fn read_list(&mut self, count_kind: PlyType, kind: PlyType) -> Result<Vec<f64>, String> {
+        let count = self.read(count_kind)?;
+        if count < 0.0 {
+            return Err(self.error(format!("invalid list length {}", count)));
+        }
+
+        // NOTE: The count comes from the file, it is checked against the remaining data before allocating
+        let remaining = match self.format {
+            PlyFormat::Ascii => self.tokens.len() - self.token,
+            _ => (self.data.len() - self.offset) / kind.size(),
+        };
+        if count > remaining as f64 {
+            return Err(self.error(format!("list length {} exceeds the remaining data", count)));
+        }
+
+        let mut values = Vec::with_capacity(count as usize);
+        for _ in 0..count as usize {
+            values.push(self.read(kind)?);
+        }
+        return Ok(values);
+    }
+}
+
+/// Element declarations, format and size of the header
+fn parse_header(data: &[u8]) -> Result<(PlyFormat, Vec<PlyElement>, usize, usize), String> {
+    let mut format = None;
+    let mut elements: Vec<PlyElement> = Vec::new();
+    let mut offset = 0;
+    let mut line_number = 0;
+
+    loop {
+        if offset >= data.len() {
+            return Err(format!("line {}: missing 'end_header'", line_number));
+        }
+        let end = data[offset..].iter().position(|&c| c == b'\n').map_or(data.len(), |end| offset + end);
+        line_number += 1;
+        let line_error = |message: String| format!("line {}: {}", line_number, message);
+        let line = std::str::from_utf8(&data[offset..end]).map_err(|_| line_error("invalid header text".to_string()))?;
+        offset = end + 1;
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if line_number == 1 {
+            if tokens != ["ply"] {
+                return Err(line_error("not a PLY file (missing 'ply' magic)".to_string()));
+            }
+            continue;
+        }
+
+        match tokens.as_slice() {
+            [] => {}
+            ["comment", ..] | ["obj_info", ..] => {}
+            ["format", encoding, _version] => {
+                format = match *encoding {
+                    "ascii" => Some(PlyFormat::Ascii),
+                    "binary_little_endian" => Some(PlyFormat::BinaryLittleEndian),
+                    "binary_big_endian" => Some(PlyFormat::BinaryBigEndian),
+                    _ => return Err(line_error(format!("unknown format '{}'", encoding))),
+                };
+            }
+            ["element", name, count] => {
+                let count = count.parse().map_err(|_| line_error(format!("invalid element count '{}'", count)))?;
+                elements.push(PlyElement { name: name.to_string(), count, properties: Vec::new() });
+            }
+            ["property", "list", count_kind, kind, name] => {
+                let property = PlyProperty {
+                    name: name.to_string(),
+                    kind: PlyType::parse(kind).ok_or_else(|| line_error(format!("unknown type '{}'", kind)))?,
+                    list_count: Some(
+                        PlyType::parse(count_kind).ok_or_else(|| line_error(format!("unknown type '{}'", count_kind)))?,
+                    ),
+                };
+                let element = elements.last_mut().ok_or_else(|| line_error("property before any element".to_string()))?;
+                element.properties.push(property);
+            }
+            ["property", kind, name] => {
+                let property = PlyProperty {
+                    name: name.to_string(),
+                    kind: PlyType::parse(kind).ok_or_else(|| line_error(format!("unknown type '{}'", kind)))?,
+                    list_count: None,
+                };
+                let element = elements.last_mut().ok_or_else(|| line_error("property before any element".to_string()))?;
+                element.properties.push(property);
+            }
+            ["end_header"] => {
+                let format = format.ok_or_else(|| line_error("missing 'format' line".to_string()))?;
+                return Ok((format, elements, offset, line_number));
+            }
+            _ => return Err(line_error(format!("invalid header line '{}'", line.trim()))),
+        }
+    }
+}
+
+impl MeshData {
+    pub fn load_ply(filename: &str) -> Result<MeshData, String> {
+        let data = load_file_data(filename)?;
+        return MeshData::from_ply(&data).map_err(|error| format!("{}: {}", filename, error));
+    }
+
+    /// Parse an ASCII or binary PLY file, polygons are triangulated as fans.
+    /// Errors are reported as `line N: ...` (header and ASCII data) or `byte N: ...` (binary data).
+    pub fn from_ply(data: &[u8]) -> Result<MeshData, String> {
+        let (format, elements, offset, line) = parse_header(data)?;
+        let mut body = PlyBody { format, data, offset, line, tokens: Vec::new(), token: 0 };
+
+        let mut positions: Vec<[f64; 3]> = Vec::new();
+        let mut normals: Vec<[f64; 3]> = Vec::new();
+        let mut tex_coords: Vec<[f64; 2]> = Vec::new();
+        let mut tex_coords2: Vec<[f64; 2]> = Vec::new();
+        let mut colors: Vec<[u8; 4]> = Vec::new();
+        let mut faces: Vec<u32> = Vec::new();
+        let mut has_faces = false;
+        let mut vertex_properties: Vec<PlyTarget> = Vec::new();
+
+        for element in &elements {
+            match element.name.as_str() {
+                "vertex" => {
+                    let targets: Vec<Option<PlyTarget>> = element
+                        .properties
+                        .iter()
+                        .map(|property| property.list_count.map_or(PlyTarget::from_name(&property.name), |_| None))
+                        .collect();
+                    vertex_properties.extend(targets.iter().flatten());
+
+                    for _ in 0..element.count {
+                        body.begin_instance()?;
+                        let (mut position, mut normal) = ([0.0; 3], [0.0; 3]);
+                        let (mut tex_coord, mut tex_coord2) = ([0.0; 2], [0.0; 2]);
+                        let mut color = [255u8; 4];
+                        for (property, target) in element.properties.iter().zip(&targets) {
+                            if let Some(count_kind) = property.list_count {
+                                body.read_list(count_kind, property.kind)?;
+                                continue;
+                            }
+                            let value = body.read(property.kind)?;
+                            match target {
+                                Some(PlyTarget::Position(i)) => position[*i] = value,
+                                Some(PlyTarget::Normal(i)) => normal[*i] = value,
+                                Some(PlyTarget::TexCoord(i)) => tex_coord[*i] = value,
+                                Some(PlyTarget::TexCoord2(i)) => tex_coord2[*i] = value,
+                                Some(PlyTarget::Color(i)) => {
+                                    let value = if property.kind.is_float() { value * 255.0 } else { value };
+                                    color[*i] = value.round().clamp(0.0, 255.0) as u8;
+                                }
+                                None => {}
+                            }
+                        }
+                        body.end_instance()?;
+
+                        positions.push(position);
+                        normals.push(normal);
+                        tex_coords.push(tex_coord);
+                        tex_coords2.push(tex_coord2);
+                        colors.push(color);
+                    }
+                }
+                "face" => {
+                    has_faces = true;
+                    for _ in 0..element.count {
+                        body.begin_instance()?;
+                        for property in &element.properties {
+                            let Some(count_kind) = property.list_count else {
+                                body.read(property.kind)?;
+                                continue;
+                            };
+                            let values = body.read_list(count_kind, property.kind)?;
+                            if property.name != "vertex_indices" && property.name != "vertex_index" {
+                                continue;
+                            }
+                            if values.len() < 3 {
+                                return Err(body.error(format!("face has {} vertices, expected at least 3", values.len())));
+                            }
+                            if let Some(index) = values.iter().find(|&&index| index < 0.0 || index as usize >= positions.len()) {
+                                let message = format!("vertex index {} is out of range ({} vertices)", index, positions.len());
+                                return Err(body.error(message));
+                            }
+                            for i in 1..values.len() - 1 {
+                                faces.extend([values[0] as u32, values[i] as u32, values[i + 1] as u32]);
+                            }
+                        }
+                        body.end_instance()?;
+                    }
+                }
+                _ => {
+                    for _ in 0..element.count {
+                        body.begin_instance()?;
+                        for property in &element.properties {
+                            match property.list_count {
+                                Some(count_kind) => _ = body.read_list(count_kind, property.kind)?,
+                                None => _ = body.read(property.kind)?,
+                            }
+                        }
+                        body.end_instance()?;
+                    }
+                }
+            }
+        }
+
+        let has = |predicate: fn(&PlyTarget) -> bool, components: usize| {
+            return vertex_properties.iter().filter(|target| predicate(target)).count() >= components;
+        };
+        if !has(|target| matches!(target, PlyTarget::Position(_)), 3) {
+            return Err("vertex element is missing x, y or z properties".to_string());
+        }
+        if !has_faces {
+            return Err("file has no face element".to_string());
+        }
+
+        // NOTE: PLY texture coordinates start at the bottom of the image, raylib's at the top
+        let to_vector2 = |values: &[f64; 2]| Vector2::new(values[0] as f32, 1.0 - values[1] as f32);
+        let to_vector3 = |values: &[f64; 3]| Vector3::new(values[0] as f32, values[1] as f32, values[2] as f32);
+        return Ok(MeshData {
+            positions: positions.iter().map(to_vector3).collect(),
+            normals: has(|target| matches!(target, PlyTarget::Normal(_)), 3)
+                .then(|| normals.iter().map(to_vector3).collect()),
+            tex_coords: has(|target| matches!(target, PlyTarget::TexCoord(_)), 2)
+                .then(|| tex_coords.iter().map(to_vector2).collect()),
+            tex_coords2: has(|target| matches!(target, PlyTarget::TexCoord2(_)), 2)
+                .then(|| tex_coords2.iter().map(to_vector2).collect()),
+            colors: has(|target| matches!(target, PlyTarget::Color(0..=2)), 3)
+                .then(|| colors.iter().map(|color| Color::new(color[0], color[1], color[2], color[3])).collect()),
+            indices: faces,
+        });
+    }
+
+    /// Encode the mesh as a PLY file with float attributes, uchar colors and triangle faces
+    pub fn to_ply(&self, format: PlyFormat) -> Vec<u8> {
+        let mut header = String::from("ply\n");
+        header += match format {
+            PlyFormat::Ascii => "format ascii 1.0\n",
+            PlyFormat::BinaryLittleEndian => "format binary_little_endian 1.0\n",
+            PlyFormat::BinaryBigEndian => "format binary_big_endian 1.0\n",
+        };
+        header += &format!("element vertex {}\n", self.positions.len());
+        let mut vertex_properties = vec!["x", "y", "z"];
+        if self.normals.is_some() {
+            vertex_properties.extend(["nx", "ny", "nz"]);
+        }
+        if self.tex_coords.is_some() {
+            vertex_properties.extend(["s", "t"]);
+        }
+        if self.tex_coords2.is_some() {
+            vertex_properties.extend(["s1", "t1"]);
+        }
+        for property in vertex_properties {
+            header += &format!("property float {}\n", property);
+        }
+        if self.colors.is_some() {
+            header += "property uchar red\nproperty uchar green\nproperty uchar blue\nproperty uchar alpha\n";
+        }
+        header += &format!("element face {}\n", self.triangle_count());
+        header += "property list uchar uint vertex_indices\nend_header\n";
+
+        let mut writer = PlyWriter { format, data: header.into_bytes(), line_start: true };
+        for i in 0..self.positions.len() {
+            let position = self.positions[i];
+            writer.put_f32s(&[position.x, position.y, position.z]);
+            if let Some(normals) = &self.normals {
+                writer.put_f32s(&[normals[i].x, normals[i].y, normals[i].z]);
+            }
+            if let Some(tex_coords) = &self.tex_coords {
+                writer.put_f32s(&[tex_coords[i].x, 1.0 - tex_coords[i].y]);
+            }
+            if let Some(tex_coords2) = &self.tex_coords2 {
+                writer.put_f32s(&[tex_coords2[i].x, 1.0 - tex_coords2[i].y]);
+            }
+            if let Some(colors) = &self.colors {
+                let color = colors[i];
+                for value in [color.r, color.g, color.b, color.a] {
+                    writer.put_u8(value);
+                }
+            }
+            writer.end_line();
+        }
+        for triangle in self.indices.chunks_exact(3) {
+            writer.put_u8(3);
+            for &index in triangle {
+                writer.put_u32(index);
+            }
+            writer.end_line();
+        }
+
+        return writer.data;
+    }
+
+    pub fn save_ply(&self, filename: &str, format: PlyFormat) -> Result<(), String> {
+        if !save_file_data(filename, &self.to_ply(format)) {
+            return Err(format!("Failed to save PLY file: {}", filename));
+        }
+        return Ok(());
+    }
+}
+
+/// Element data encoder, ASCII values are separated by spaces with one element instance per line
+struct PlyWriter {
+    format: PlyFormat,
+    data: Vec<u8>,
+    line_start: bool,
+}
+
+impl PlyWriter {
+    fn put(&mut self, text: String, little_endian: &[u8], big_endian: &[u8]) {
+        match self.format {
+            PlyFormat::Ascii => {
+                if !self.line_start {
+                    self.data.push(b' ');
+                }
+                self.data.extend_from_slice(text.as_bytes());
+                self.line_start = false;
+            }
+            PlyFormat::BinaryLittleEndian => self.data.extend_from_slice(little_endian),
+            PlyFormat::BinaryBigEndian => self.data.extend_from_slice(big_endian),
+        }
+    }
+
+    fn put_f32s(&mut self, values: &[f32]) {
+        for &value in values {
+            self.put(value.to_string(), &value.to_le_bytes(), &value.to_be_bytes());
+        }
+    }
+
+    fn put_u8(&mut self, value: u8) {
+        self.put(value.to_string(), &[value], &[value]);
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        self.put(value.to_string(), &value.to_le_bytes(), &value.to_be_bytes());
+    }
+
+    fn end_line(&mut self) {
+        if self.format == PlyFormat::Ascii {
+            self.data.push(b'\n');
+            self.line_start = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle_fan() -> MeshData {
+        return MeshData {
+            positions: vec![
+                Vector3::new(0.0, 0.0, 0.0),
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(1.0, 1.0, 0.5),
+                Vector3::new(-0.25, 1.0, 0.0),
+            ],
+            normals: Some(vec![Vector3::new(0.0, 0.0, 1.0); 4]),
+            tex_coords: Some(vec![
+                Vector2::new(0.0, 1.0),
+                Vector2::new(1.0, 1.0),
+                Vector2::new(1.0, 0.0),
+                Vector2::new(0.0, 0.25),
+            ]),
+            tex_coords2: Some(vec![Vector2::new(0.5, 0.5); 4]),
+            colors: Some(vec![Color::RED, Color::GREEN, Color::BLUE, Color::new(1, 2, 3, 4)]),
+            indices: vec![0, 1, 2, 0, 2, 3],
+        };
+    }
+
+    #[test]
+    fn round_trip_all_formats() {
+        let mesh = triangle_fan();
+        for format in [PlyFormat::Ascii, PlyFormat::BinaryLittleEndian, PlyFormat::BinaryBigEndian] {
+            assert_eq!(MeshData::from_ply(&mesh.to_ply(format)), Ok(mesh.clone()), "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn round_trip_positions_only() {
+        let mesh = MeshData { positions: triangle_fan().positions, indices: vec![0, 1, 2], ..MeshData::default() };
+        assert_eq!(MeshData::from_ply(&mesh.to_ply(PlyFormat::BinaryLittleEndian)), Ok(mesh));
+    }
+
+    #[test]
+    fn parse_ascii_polygons_and_extra_elements() {
+        let text = "ply\nformat ascii 1.0\ncomment test\nelement vertex 4\nproperty float x\nproperty float y\n\
+                    property float z\nproperty uchar red\nproperty uchar green\nproperty uchar blue\n\
+                    element face 1\nproperty list uchar int vertex_indices\nelement edge 1\nproperty int vertex1\n\
+                    property int vertex2\nend_header\n0 0 0 255 0 0\n1 0 0 0 255 0\n1 1 0 0 0 255\n0 1 0 9 9 9\n\
+                    4 0 1 2 3\n0 1\n";
+        let mesh = MeshData::from_ply(text.as_bytes()).unwrap();
+        assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(mesh.colors.unwrap()[3], Color::new(9, 9, 9, 255));
+        assert_eq!(mesh.normals, None);
+    }
+
+    #[test]
+    fn reject_invalid_lists() {
+        let mut data = b"ply\nformat binary_little_endian 1.0\nelement vertex 0\nproperty float x\nproperty float y\n\
+                         property float z\nelement face 1\nproperty list uint uint vertex_indices\nend_header\n"
+            .to_vec();
+        data.extend_from_slice(&u32::MAX.to_le_bytes());
+        data.extend_from_slice(&[0; 12]);
+        let error = MeshData::from_ply(&data).unwrap_err();
+        assert_eq!(error, "byte 173: list length 4294967295 exceeds the remaining data");
+
+        let text = "ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nproperty float y\nproperty float z\n\
+                    element face 1\nproperty list uchar int vertex_indices\nend_header\n0 0 0\n3 0 0 1\n";
+        let error = MeshData::from_ply(text.as_bytes()).unwrap_err();
+        assert_eq!(error, "line 11: vertex index 1 is out of range (1 vertices)");
+    }
+}
@@ -0,0 +1,714 @@
+use crate::{Color, Matrix, MeshData, Vector2, Vector3, compute_smooth_normals};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::f32::consts::PI;
+
+/// Tolerance used to compare vertex attributes other than positions when welding
+const ATTRIBUTE_EPSILON: f32 = 1e-4;
+
+/// Normals recomputation mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalMode {
+    /// Average the normals of all faces sharing a vertex position
+    Smooth,
+    /// Use the face normal, shared vertices are split
+    Flat,
+}
+
+/// Texture coordinates projection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UvProjection {
+    /// Project along `normal` onto a plane, `scale` is the number of texture repeats per world unit
+    Planar { normal: Vector3, scale: f32 },
+    /// Project every face along its dominant axis (cube mapping), vertices on box edges are split
+    Box { scale: f32 },
+    /// Project around `center` using longitude (U) and latitude (V)
+    Spherical { center: Vector3 },
+}
+
+impl MeshData {
+    /// Merge several meshes into one, transforming each of them first.
+    /// Attributes missing from some of the meshes are filled with defaults (computed normals, zero UVs, white).
+    pub fn merge(parts: &[(&MeshData, Matrix)]) -> MeshData {
+        let mut result = MeshData {
+            positions: Vec::new(),
+            normals: parts.iter().any(|(part, _)| part.normals.is_some()).then(Vec::new),
+            tex_coords: parts.iter().any(|(part, _)| part.tex_coords.is_some()).then(Vec::new),
+            tex_coords2: parts.iter().any(|(part, _)| part.tex_coords2.is_some()).then(Vec::new),
+            colors: parts.iter().any(|(part, _)| part.colors.is_some()).then(Vec::new),
+            indices: Vec::new(),
+        };
+
+        for (part, transform) in parts {
+            let base = result.positions.len() as u32;
+            let count = part.vertex_count();
+            let normal_matrix = transform.invert().transpose();
+
+            result.positions.extend(part.positions.iter().map(|&position| transform.transform_point(position)));
+            if let Some(normals) = &mut result.normals {
+                let part_normals = match &part.normals {
+                    Some(part_normals) => part_normals.clone(),
+                    None => compute_smooth_normals(&part.positions, &part.indices),
+                };
+                normals.extend(part_normals.iter().map(|&normal| normal_matrix.transform_vector(normal).normalize()));
+            }
+            extend_attribute(&mut result.tex_coords, &part.tex_coords, count, Vector2::zero());
+            extend_attribute(&mut result.tex_coords2, &part.tex_coords2, count, Vector2::zero());
+            extend_attribute(&mut result.colors, &part.colors, count, Color::WHITE);
+
+            // NOTE: Mirroring transforms reverse the triangles winding
+            let mirrored = determinant3(transform) < 0.0;
+            for triangle in part.indices.chunks_exact(3) {
+                if mirrored {
+                    result.indices.extend([triangle[0] + base, triangle[2] + base, triangle[1] + base]);
+                } else {
+                    result.indices.extend(triangle.iter().map(|&index| index + base));
+                }
+            }
+        }
+
+        return result;
+    }
+
+    /// Merge duplicate vertices: positions closer than `tolerance` and matching normals, UVs and colors.
+    /// Triangles collapsed by the welding are removed.
+    pub fn weld(&mut self, tolerance: f32) {
+        let cell_size = tolerance.max(f32::EPSILON);
+        let cell = |position: Vector3| {
+            return (
+                (position.x / cell_size).floor() as i64,
+                (position.y / cell_size).floor() as i64,
+                (position.z / cell_size).floor() as i64,
+            );
+        };
+
+        let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+        let mut kept: Vec<usize> = Vec::new();
+        let mut remap = vec![0u32; self.vertex_count()];
+        for (vertex, remapped) in remap.iter_mut().enumerate() {
+            let (cx, cy, cz) = cell(self.positions[vertex]);
+            let mut found = None;
+            'search: for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        let Some(candidates) = grid.get(&(cx + dx, cy + dy, cz + dz)) else {
+                            continue;
+                        };
+                        for &candidate in candidates {
+                            if self.vertices_match(vertex, kept[candidate], tolerance) {
+                                found = Some(candidate);
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+
+            let new_index = match found {
+                Some(new_index) => new_index,
+                None => {
+                    kept.push(vertex);
+                    grid.entry((cx, cy, cz)).or_default().push(kept.len() - 1);
+                    kept.len() - 1
+                }
+            };
+            *remapped = new_index as u32;
+        }
+
+        let indices = self.indices.iter().map(|&index| remap[index as usize]).collect();
+        *self = self.select_vertices(&kept, indices);
+        self.remove_degenerate_triangles();
+    }
+
+    /// Recompute vertex normals from the triangles
+    pub fn recompute_normals(&mut self, mode: NormalMode) {
+        match mode {
+            NormalMode::Smooth => {
+                // NOTE: Vertices split along UV seams share the same position and must share the normal
+                let mut by_position: HashMap<[u32; 3], Vector3> = HashMap::new();
+                for triangle in self.indices.chunks_exact(3) {
+                    let normal = self.face_normal(triangle);
+                    for &index in triangle {
+                        *by_position.entry(position_key(self.positions[index as usize])).or_insert(Vector3::zero()) +=
+                            normal;
+                    }
+                }
+                let normals = self
+                    .positions
+                    .iter()
+                    .map(|&position| by_position.get(&position_key(position)).copied().unwrap_or(Vector3::zero()))
+                    .map(|normal| normal.normalize())
+                    .collect();
+                self.normals = Some(normals);
+            }
+            NormalMode::Flat => {
+                let sources: Vec<usize> = self.indices.iter().map(|&index| index as usize).collect();
+                let normals = self
+                    .indices
+                    .chunks_exact(3)
+                    .flat_map(|triangle| [self.face_normal(triangle).normalize(); 3])
+                    .collect();
+                *self = self.select_vertices(&sources, (0..sources.len() as u32).collect());
+                self.normals = Some(normals);
+            }
+        }
+    }
+
+    /// Reverse the winding order of every triangle and flip the normals
+    pub fn flip_winding(&mut self) {
+        for triangle in self.indices.chunks_exact_mut(3) {
+            triangle.swap(1, 2);
+        }
+        if let Some(normals) = &mut self.normals {
+            normals.iter_mut().for_each(|normal| *normal = -*normal);
+        }
+    }
+
+    /// Generate texture coordinates (first UV set) with the given projection
+    pub fn project_uvs(&mut self, projection: UvProjection) {
+        match projection {
+            UvProjection::Planar { normal, scale } => {
+                let (u_axis, v_axis) = plane_basis(normal);
+                let tex_coords = self
+                    .positions
+                    .iter()
+                    .map(|&position| Vector2::new(position.dot(u_axis) * scale, position.dot(v_axis) * scale))
+                    .collect();
+                self.tex_coords = Some(tex_coords);
+            }
+            UvProjection::Box { scale } => {
+                let mut split: HashMap<(u32, usize), u32> = HashMap::new();
+                let mut sources: Vec<usize> = Vec::new();
+                let mut axes: Vec<usize> = Vec::new();
+                let mut indices = Vec::with_capacity(self.indices.len());
+                for triangle in self.indices.chunks_exact(3) {
+                    let axis = dominant_axis(self.face_normal(triangle));
+                    for &index in triangle {
+                        let new_index = *split.entry((index, axis)).or_insert_with(|| {
+                            sources.push(index as usize);
+                            axes.push(axis);
+                            return (sources.len() - 1) as u32;
+                        });
+                        indices.push(new_index);
+                    }
+                }
+
+                *self = self.select_vertices(&sources, indices);
+                let tex_coords = self
+                    .positions
+                    .iter()
+                    .zip(axes)
+                    .map(|(&position, axis)| {
+                        let uv = match axis {
+                            0 => Vector2::new(position.z, position.y),
+                            1 => Vector2::new(position.x, position.z),
+                            _ => Vector2::new(position.x, position.y),
+                        };
+                        return uv * scale;
+                    })
+                    .collect();
+                self.tex_coords = Some(tex_coords);
+            }
+            UvProjection::Spherical { center } => {
+                let tex_coords = self
+                    .positions
+                    .iter()
+                    .map(|&position| {
+                        let direction = (position - center).normalize();
+                        let u = 0.5 + direction.z.atan2(direction.x) / (2.0 * PI);
+                        let v = 0.5 - direction.y.clamp(-1.0, 1.0).asin() / PI;
+                        return Vector2::new(u, v);
+                    })
+                    .collect();
+                self.tex_coords = Some(tex_coords);
+            }
+        }
+    }
+
+    /// Reduce the mesh to (at most) `target_triangle_count` triangles by collapsing edges,
+    /// using quadric error metrics to pick the collapses that change the surface the least.
+    /// Vertices are never moved, so UVs and colors stay valid. Vertices on open edges and vertices sharing their
+    /// position with another vertex (UV or normal seams) are never removed, so borders and seams stay closed.
+    /// NOTE: Weld the mesh first, unconnected triangles can not be simplified.
+    pub fn simplify(&mut self, target_triangle_count: usize) {
+        let mut triangles: Vec<[u32; 3]> =
+            self.indices.chunks_exact(3).map(|triangle| [triangle[0], triangle[1], triangle[2]]).collect();
+        let mut alive = vec![true; triangles.len()];
+        let mut live_count = triangles.len();
+        if live_count <= target_triangle_count {
+            return;
+        }
+
+        let vertex_count = self.vertex_count();
+        let mut quadrics = vec![Quadric::default(); vertex_count];
+        let mut vertex_triangles: Vec<Vec<usize>> = vec![Vec::new(); vertex_count];
+        let mut edge_faces: HashMap<(u32, u32), usize> = HashMap::new();
+        for (t, triangle) in triangles.iter().enumerate() {
+            let normal = self.face_normal(triangle);
+            let area = normal.length() * 0.5;
+            let unit = normal.normalize();
+            let plane = Quadric::from_plane(unit, -unit.dot(self.positions[triangle[0] as usize]), area as f64);
+            for (corner, &index) in triangle.iter().enumerate() {
+                quadrics[index as usize].add(&plane);
+                vertex_triangles[index as usize].push(t);
+
+                let next = triangle[(corner + 1) % 3];
+                *edge_faces.entry(edge_key(index, next)).or_insert(0) += 1;
+            }
+        }
+
+        // NOTE: Split vertices collapse independently on each side of a seam, locking them keeps the seam closed
+        let mut locked = vec![false; vertex_count];
+        for (&(a, b), &count) in &edge_faces {
+            if count == 1 {
+                locked[a as usize] = true;
+                locked[b as usize] = true;
+            }
+        }
+        let mut by_position: HashMap<[u32; 3], usize> = HashMap::new();
+        for (vertex, &position) in self.positions.iter().enumerate() {
+            if let Some(&first) = by_position.get(&position_key(position)) {
+                locked[first] = true;
+                locked[vertex] = true;
+            } else {
+                by_position.insert(position_key(position), vertex);
+            }
+        }
+
+        let mut versions = vec![0u32; vertex_count];
+        let mut removed = vec![false; vertex_count];
+        let mut heap = BinaryHeap::new();
+        for &(a, b) in edge_faces.keys() {
+            self.push_collapses(&mut heap, &quadrics, &versions, &locked, a as usize, b as usize);
+        }
+
+        while live_count > target_triangle_count {
+            let Some(Reverse(collapse)) = heap.pop() else {
+                break;
+            };
+            let (from, to) = (collapse.from, collapse.to);
+            if removed[from] || removed[to] || versions[from] != collapse.from_version || versions[to] != collapse.to_version {
+                continue;
+            }
+            if !self.collapse_keeps_orientation(&triangles, &alive, &vertex_triangles[from], from, to) {
+                continue;
+            }
+
+            for &t in &vertex_triangles[from].clone() {
+                if !alive[t] {
+                    continue;
+                }
+                if triangles[t].contains(&(to as u32)) {
+                    alive[t] = false;
+                    live_count -= 1;
+                } else {
+                    triangles[t].iter_mut().filter(|index| **index == from as u32).for_each(|index| *index = to as u32);
+                    vertex_triangles[to].push(t);
+                }
+            }
+
+            let from_quadric = quadrics[from];
+            quadrics[to].add(&from_quadric);
+            removed[from] = true;
+            versions[to] += 1;
+
+            let mut neighbours: Vec<usize> = vertex_triangles[to]
+                .iter()
+                .filter(|&&t| alive[t])
+                .flat_map(|&t| triangles[t])
+                .map(|index| index as usize)
+                .filter(|&index| index != to)
+                .collect();
+            neighbours.sort_unstable();
+            neighbours.dedup();
+            for neighbour in neighbours {
+                self.push_collapses(&mut heap, &quadrics, &versions, &locked, to, neighbour);
+            }
+        }
+
+        // Compact the remaining triangles and drop unused vertices
+        let mut remap: Vec<Option<u32>> = vec![None; vertex_count];
+        let mut sources = Vec::new();
+        let mut indices = Vec::with_capacity(live_count * 3);
+        for (t, triangle) in triangles.iter().enumerate() {
+            if !alive[t] {
+                continue;
+            }
+            for &index in triangle {
+                let new_index = *remap[index as usize].get_or_insert_with(|| {
+                    sources.push(index as usize);
+                    return (sources.len() - 1) as u32;
+                });
+                indices.push(new_index);
+            }
+        }
+        *self = self.select_vertices(&sources, indices);
+    }
+
+    /// Face normal, its length is twice the triangle area
+    fn face_normal(&self, triangle: &[u32]) -> Vector3 {
+        let a = self.positions[triangle[0] as usize];
+        let b = self.positions[triangle[1] as usize];
+        let c = self.positions[triangle[2] as usize];
+        return (b - a).cross(c - a);
+    }
+
+    fn vertices_match(&self, a: usize, b: usize, tolerance: f32) -> bool {
+        if self.positions[a].distance(self.positions[b]) > tolerance {
+            return false;
+        }
+        if let Some(normals) = &self.normals
+            && normals[a].distance(normals[b]) > ATTRIBUTE_EPSILON
+        {
+            return false;
+        }
+        for tex_coords in [&self.tex_coords, &self.tex_coords2].into_iter().flatten() {
+            if tex_coords[a].distance(tex_coords[b]) > ATTRIBUTE_EPSILON {
+                return false;
+            }
+        }
+        if let Some(colors) = &self.colors
+            && colors[a] != colors[b]
+        {
+            return false;
+        }
+        return true;
+    }
+
+    fn remove_degenerate_triangles(&mut self) {
+        let indices = self
+            .indices
+            .chunks_exact(3)
+            .filter(|triangle| triangle[0] != triangle[1] && triangle[1] != triangle[2] && triangle[0] != triangle[2])
+            .flatten()
+            .copied()
+            .collect();
+        self.indices = indices;
+    }
+
+    /// Queue the collapses of the edge (a, b) in both directions, locked vertices are never collapsed
+    fn push_collapses(
+        &self,
+        heap: &mut BinaryHeap<Reverse<Collapse>>,
+        quadrics: &[Quadric],
+        versions: &[u32],
+        locked: &[bool],
+        a: usize,
+        b: usize,
+    ) {
+        let mut combined = quadrics[a];
+        combined.add(&quadrics[b]);
+        for (from, to) in [(a, b), (b, a)] {
+            if locked[from] {
+                continue;
+            }
+            heap.push(Reverse(Collapse {
+                cost: combined.error(self.positions[to]),
+                from,
+                to,
+                from_version: versions[from],
+                to_version: versions[to],
+            }));
+        }
+    }
+
+    /// Check that moving `from` onto `to` does not flip any of the triangles that survive the collapse
+    fn collapse_keeps_orientation(
+        &self,
+        triangles: &[[u32; 3]],
+        alive: &[bool],
+        from_triangles: &[usize],
+        from: usize,
+        to: usize,
+    ) -> bool {
+        for &t in from_triangles {
+            if !alive[t] || triangles[t].contains(&(to as u32)) {
+                continue;
+            }
+            let before = self.face_normal(&triangles[t]);
+            let moved = triangles[t].map(|index| if index as usize == from { to as u32 } else { index });
+            let after = self.face_normal(&moved);
+            if before.dot(after) <= 0.0 {
+                return false;
+            }
+        }
+        return true;
+    }
+}
+
+fn extend_attribute<T: Copy>(target: &mut Option<Vec<T>>, source: &Option<Vec<T>>, count: usize, default: T) {
+    let Some(target) = target else {
+        return;
+    };
+    match source {
+        Some(source) => target.extend_from_slice(source),
+        None => target.extend(std::iter::repeat_n(default, count)),
+    }
+}
+
+/// Determinant of the rotation/scale part of a transform
+fn determinant3(matrix: &Matrix) -> f32 {
+    let x = Vector3::new(matrix.m0, matrix.m1, matrix.m2);
+    let y = Vector3::new(matrix.m4, matrix.m5, matrix.m6);
+    let z = Vector3::new(matrix.m8, matrix.m9, matrix.m10);
+    return x.cross(y).dot(z);
+}
+
+fn position_key(position: Vector3) -> [u32; 3] {
+    return [position.x.to_bits(), position.y.to_bits(), position.z.to_bits()];
+}
+
+fn edge_key(a: u32, b: u32) -> (u32, u32) {
+    return (a.min(b), a.max(b));
+}
+
+/// Two unit vectors spanning the plane perpendicular to `normal`
+fn plane_basis(normal: Vector3) -> (Vector3, Vector3) {
+    let normal = normal.normalize();
+    let helper = if normal.y.abs() < 0.99 { Vector3::new(0.0, 1.0, 0.0) } else { Vector3::new(1.0, 0.0, 0.0) };
+    let u_axis = helper.cross(normal).normalize();
+    let v_axis = normal.cross(u_axis);
+    return (u_axis, v_axis);
+}
+
+/// Index (0: X, 1: Y, 2: Z) of the largest component of a vector
+fn dominant_axis(vector: Vector3) -> usize {
+    let (x, y, z) = (vector.x.abs(), vector.y.abs(), vector.z.abs());
+    if x >= y && x >= z {
+        return 0;
+    }
+    if y >= z {
+        return 1;
+    }
+    return 2;
+}
+
+/// Symmetric 4x4 error quadric (upper triangle)
+#[derive(Debug, Clone, Copy, Default)]
+struct Quadric {
+    m: [f64; 10],
+}
+
+impl Quadric {
+    /// Quadric measuring the squared distance to the plane ax + by + cz + d = 0
+    fn from_plane(normal: Vector3, d: f32, weight: f64) -> Self {
+        let (a, b, c, d) = (normal.x as f64, normal.y as f64, normal.z as f64, d as f64);
+        let m = [a * a, a * b, a * c, a * d, b * b, b * c, b * d, c * c, c * d, d * d];
+        return Self { m: m.map(|value| value * weight) };
+    }
+
+    fn add(&mut self, other: &Quadric) {
+        for i in 0..10 {
+            self.m[i] += other.m[i];
+        }
+    }
+
+    fn error(&self, v: Vector3) -> f64 {
+        let (x, y, z) = (v.x as f64, v.y as f64, v.z as f64);
+        let m = &self.m;
+        return m[0] * x * x + 2.0 * m[1] * x * y + 2.0 * m[2] * x * z + 2.0 * m[3] * x + m[4] * y * y
+            + 2.0 * m[5] * y * z
+            + 2.0 * m[6] * y
+            + m[7] * z * z
+            + 2.0 * m[8] * z
+            + m[9];
+    }
+}
+
+/// Candidate edge collapse: `from` is merged into `to`
+#[derive(Debug, Clone, Copy)]
+struct Collapse {
+    cost: f64,
+    from: usize,
+    to: usize,
+    from_version: u32,
+    to_version: u32,
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.cost.total_cmp(&other.cost).then(self.from.cmp(&other.from)).then(self.to.cmp(&other.to));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// Flat `size`x`size` quad grid in the XY plane facing +Z, one vertex per grid point
+    fn grid(size: u32, origin_x: f32, u_offset: f32) -> MeshData {
+        let mut mesh = MeshData {
+            positions: Vec::new(),
+            normals: Some(Vec::new()),
+            tex_coords: Some(Vec::new()),
+            tex_coords2: None,
+            colors: None,
+            indices: Vec::new(),
+        };
+        for y in 0..=size {
+            for x in 0..=size {
+                mesh.positions.push(Vector3::new(origin_x + x as f32, y as f32, 0.0));
+                mesh.normals.as_mut().unwrap().push(Vector3::new(0.0, 0.0, 1.0));
+                mesh.tex_coords.as_mut().unwrap().push(Vector2::new(u_offset + x as f32, y as f32));
+            }
+        }
+        for y in 0..size {
+            for x in 0..size {
+                let (a, b) = (y * (size + 1) + x, y * (size + 1) + x + 1);
+                let (c, d) = (a + size + 1, b + size + 1);
+                mesh.indices.extend([a, b, d, a, d, c]);
+            }
+        }
+        return mesh;
+    }
+
+    fn join(a: &MeshData, b: &MeshData) -> MeshData {
+        return MeshData::merge(&[(a, Matrix::identity()), (b, Matrix::identity())]);
+    }
+
+    fn assert_valid_faces(mesh: &MeshData) {
+        for triangle in mesh.indices.chunks_exact(3) {
+            assert!(triangle[0] != triangle[1] && triangle[1] != triangle[2] && triangle[0] != triangle[2]);
+            let normal = mesh.face_normal(triangle);
+            assert!(normal.z > 1e-6, "degenerate or flipped triangle {:?}", triangle);
+        }
+    }
+
+    /// Position edges used by a single triangle
+    fn open_edges(mesh: &MeshData) -> Vec<([u32; 3], [u32; 3])> {
+        let mut counts: HashMap<([u32; 3], [u32; 3]), usize> = HashMap::new();
+        for triangle in mesh.indices.chunks_exact(3) {
+            for corner in 0..3 {
+                let a = position_key(mesh.positions[triangle[corner] as usize]);
+                let b = position_key(mesh.positions[triangle[(corner + 1) % 3] as usize]);
+                *counts.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+            }
+        }
+        return counts.into_iter().filter(|&(_, count)| count == 1).map(|(edge, _)| edge).collect();
+    }
+
+    #[test]
+    fn weld() {
+        // Two triangles of a quad with their own copy of the shared edge
+        let mut mesh = MeshData {
+            positions: [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0), (1.0, 1.001), (0.0, 1.0)]
+                .map(|(x, y)| Vector3::new(x, y, 0.0))
+                .to_vec(),
+            normals: None,
+            tex_coords: None,
+            tex_coords2: None,
+            colors: None,
+            indices: vec![0, 1, 2, 3, 4, 5],
+        };
+        let mut exact = mesh.clone();
+        exact.weld(0.0001);
+        assert_eq!(exact.vertex_count(), 5);
+
+        mesh.weld(0.01);
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.indices, [0, 1, 2, 0, 2, 3]);
+
+        // Different UVs keep seam vertices apart
+        let mut seam = join(&grid(1, 0.0, 0.0), &grid(1, 1.0, 5.0));
+        seam.weld(0.01);
+        assert_eq!(seam.vertex_count(), 8);
+
+        // Welding a triangle down to a point removes it
+        let mut tiny = grid(1, 0.0, 0.0);
+        tiny.tex_coords = None;
+        tiny.positions.iter_mut().for_each(|position| *position = *position * 0.001);
+        tiny.weld(0.01);
+        assert_eq!(tiny.vertex_count(), 1);
+        assert!(tiny.indices.is_empty());
+    }
+
+    #[test]
+    fn merge() {
+        let mut colored = grid(1, 0.0, 0.0);
+        colored.colors = Some(vec![Color::RED; 4]);
+        colored.normals = None;
+        let plain = grid(1, 0.0, 0.0);
+        let merged = MeshData::merge(&[
+            (&colored, Matrix::translate(0.0, 0.0, 2.0)),
+            (&plain, Matrix::scale(-1.0, 1.0, 1.0)),
+        ]);
+
+        assert_eq!(merged.vertex_count(), 8);
+        assert_eq!(merged.triangle_count(), 4);
+        assert_eq!(merged.positions[3], Vector3::new(1.0, 1.0, 2.0));
+        assert_eq!(merged.positions[5], Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(merged.indices[..6], [0, 1, 3, 0, 3, 2]);
+        // The mirrored part is rewound to keep facing +Z, and its indices are offset
+        assert_eq!(merged.indices[6..], [4, 7, 5, 4, 6, 7]);
+        let colors = merged.colors.unwrap();
+        assert_eq!(colors[..4], [Color::RED; 4]);
+        assert_eq!(colors[4..], [Color::WHITE; 4]);
+        // Missing normals are computed, mirrored normals stay unit length
+        let normals = merged.normals.unwrap();
+        assert!(normals.iter().all(|normal| (normal.z.abs() - 1.0).abs() < 1e-5));
+        assert_eq!(merged.tex_coords.unwrap().len(), 8);
+        assert!(merged.tex_coords2.is_none());
+    }
+
+    #[test]
+    fn flip_winding() {
+        let mut mesh = grid(1, 0.0, 0.0);
+        mesh.flip_winding();
+        assert_eq!(mesh.indices, [0, 3, 1, 0, 2, 3]);
+        assert!(mesh.normals.as_ref().unwrap().iter().all(|normal| normal.z == -1.0));
+        assert!(mesh.indices.chunks_exact(3).all(|triangle| mesh.face_normal(triangle).z < 0.0));
+        mesh.flip_winding();
+        assert_eq!(mesh, grid(1, 0.0, 0.0));
+    }
+
+    #[test]
+    fn simplify() {
+        let mut mesh = grid(8, 0.0, 0.0);
+        let border: HashSet<[u32; 3]> = open_edges(&mesh).into_iter().flat_map(|(a, b)| [a, b]).collect();
+        mesh.simplify(40);
+        assert!(mesh.triangle_count() <= 40);
+        // A border of 32 vertices needs at least 30 triangles
+        assert!(mesh.triangle_count() >= 30);
+        assert_valid_faces(&mesh);
+        let kept: HashSet<[u32; 3]> = mesh.positions.iter().map(|&position| position_key(position)).collect();
+        assert!(border.is_subset(&kept));
+        assert_eq!(mesh.normals.as_ref().unwrap().len(), mesh.vertex_count());
+
+        // Nothing to do below the target
+        let mut small = grid(2, 0.0, 0.0);
+        small.simplify(100);
+        assert_eq!(small, grid(2, 0.0, 0.0));
+    }
+
+    #[test]
+    fn simplify_keeps_seams_closed() {
+        // Two grids side by side with split vertices along x = 6, as left by a UV seam
+        let mut mesh = join(&grid(6, 0.0, 0.0), &grid(6, 6.0, 10.0));
+        mesh.weld(0.01);
+        let triangles = mesh.triangle_count();
+        mesh.simplify(triangles / 4);
+        assert!(mesh.triangle_count() < triangles);
+        assert_valid_faces(&mesh);
+
+        // Every open edge is on the outer border, none along the seam
+        let seam = 6f32.to_bits();
+        for (a, b) in open_edges(&mesh) {
+            assert!(!(a[0] == seam && b[0] == seam), "crack along the seam");
+        }
+    }
+}
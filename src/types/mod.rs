@@ -1,28 +1,63 @@
+mod animation;
+mod app;
+mod aseprite;
+mod atlas;
 mod audio;
 mod camera;
 mod color;
 mod config_flag;
 mod font;
+mod framebuffer;
 mod image;
 mod input;
+mod lights;
+mod material;
 mod math;
+mod mesh;
+mod model;
+mod particles;
 mod physics;
+mod post_process;
 mod rendering;
+mod shadow;
+mod skybox;
+mod sprite;
+mod sprite_import;
 mod texture;
+mod tiled;
+mod tilemap;
+mod tween;
 mod virtual_reality;
 mod graphics;
 
+pub use animation::*;
+pub use app::*;
+pub use aseprite::*;
+pub use atlas::*;
 pub use audio::*;
 pub use camera::*;
 pub use color::*;
 pub use config_flag::*;
 pub use font::*;
+pub use framebuffer::*;
 pub use image::*;
 pub use input::*;
+pub use lights::*;
+pub use material::*;
 pub use math::*;
+pub use mesh::*;
+pub use model::*;
+pub use particles::*;
 pub use physics::*;
+pub use post_process::*;
 pub use rendering::*;
+pub use shadow::*;
+pub use skybox::*;
+pub use sprite::*;
 pub use texture::*;
+pub use tiled::*;
+pub use tilemap::*;
+pub use tween::*;
 pub use virtual_reality::*;
 pub use graphics::*;
 use crate::{get_fps, get_frame_time, get_time, set_target_fps, wait_time};
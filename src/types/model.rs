@@ -0,0 +1,200 @@
+use crate::{
+    BoundingBox, Color, Material, Matrix, Mesh, Transform, Vector3, draw_model, draw_model_ex, draw_model_points,
+    draw_model_points_ex, draw_model_wires, draw_model_wires_ex, get_model_bounding_box, is_model_valid, load_model,
+    load_model_from_mesh, set_model_mesh_material, unload_model,
+};
+use std::{ptr, slice};
+
+/// Bone, skeletal animation bone
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct BoneInfo {
+    /// Bone name
+    pub name: [u8; 32],
+    /// Bone parent
+    pub parent: i32,
+}
+
+impl BoneInfo {
+    /// Bone name decoded from the NUL-terminated name buffer (empty if not valid UTF-8)
+    pub fn name(&self) -> &str {
+        return fixed_str(&self.name);
+    }
+
+    /// Parent bone index, `None` for root bones
+    pub fn parent(&self) -> Option<usize> {
+        if self.parent < 0 {
+            return None;
+        }
+        return Some(self.parent as usize);
+    }
+}
+
+/// Model, meshes, materials and animation data
+#[repr(C)]
+#[derive(Debug)]
+pub struct Model {
+    /// Local transform matrix
+    pub transform: Matrix,
+
+    /// Number of meshes
+    pub mesh_count: i32,
+    /// Number of materials
+    pub material_count: i32,
+    /// Meshes array
+    pub meshes: *mut Mesh,
+    /// Materials array
+    pub materials: *mut Material,
+    /// Mesh material number
+    pub mesh_material: *mut i32,
+
+    /// Animation data
+    /// Number of bones
+    pub bone_count: i32,
+    /// Bones information (skeleton)
+    pub bones: *mut BoneInfo,
+    /// Bones base transformation (pose)
+    pub bind_pose: *mut Transform,
+}
+
+impl Model {
+    // Loading
+
+    pub fn load(filename: &str) -> Model {
+        return load_model(filename);
+    }
+
+    pub fn from_mesh(mesh: Mesh) -> Model {
+        return load_model_from_mesh(mesh);
+    }
+
+    pub fn is_valid(&self) -> bool {
+        return is_model_valid(self.shallow_copy());
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        return get_model_bounding_box(self.shallow_copy());
+    }
+
+    // Data access
+
+    pub fn meshes(&self) -> &[Mesh] {
+        return unsafe { raw_slice(self.meshes, self.mesh_count) };
+    }
+
+    pub fn meshes_mut(&mut self) -> &mut [Mesh] {
+        return unsafe { raw_slice_mut(self.meshes, self.mesh_count) };
+    }
+
+    pub fn materials(&self) -> &[Material] {
+        return unsafe { raw_slice(self.materials, self.material_count) };
+    }
+
+    pub fn materials_mut(&mut self) -> &mut [Material] {
+        return unsafe { raw_slice_mut(self.materials, self.material_count) };
+    }
+
+    pub fn bones(&self) -> &[BoneInfo] {
+        return unsafe { raw_slice(self.bones, self.bone_count) };
+    }
+
+    pub fn bind_pose(&self) -> &[Transform] {
+        return unsafe { raw_slice(self.bind_pose, self.bone_count) };
+    }
+
+    /// Material index used by every mesh
+    pub fn mesh_materials(&self) -> &[i32] {
+        return unsafe { raw_slice(self.mesh_material, self.mesh_count) };
+    }
+
+    /// Material used by the mesh at `mesh_index`
+    pub fn mesh_material(&self, mesh_index: usize) -> Option<&Material> {
+        let material_index = *self.mesh_materials().get(mesh_index)?;
+        return self.materials().get(usize::try_from(material_index).ok()?);
+    }
+
+    /// Index of the bone named `name`
+    pub fn bone_index(&self, name: &str) -> Option<usize> {
+        return self.bones().iter().position(|bone| bone.name() == name);
+    }
+
+    pub fn set_mesh_material(&mut self, mesh_id: i32, material_id: i32) {
+        set_model_mesh_material(self, mesh_id, material_id);
+    }
+
+    // Drawing
+
+    pub fn draw(&self, position: Vector3, scale: f32, tint: Color) {
+        draw_model(self.shallow_copy(), position, scale, tint);
+    }
+
+    pub fn draw_ex(&self, position: Vector3, rotation_axis: Vector3, rotation_angle: f32, scale: Vector3, tint: Color) {
+        draw_model_ex(self.shallow_copy(), position, rotation_axis, rotation_angle, scale, tint);
+    }
+
+    pub fn draw_wires(&self, position: Vector3, scale: f32, tint: Color) {
+        draw_model_wires(self.shallow_copy(), position, scale, tint);
+    }
+
+    pub fn draw_wires_ex(
+        &self,
+        position: Vector3,
+        rotation_axis: Vector3,
+        rotation_angle: f32,
+        scale: Vector3,
+        tint: Color,
+    ) {
+        draw_model_wires_ex(self.shallow_copy(), position, rotation_axis, rotation_angle, scale, tint);
+    }
+
+    pub fn draw_points(&self, position: Vector3, scale: f32, tint: Color) {
+        draw_model_points(self.shallow_copy(), position, scale, tint);
+    }
+
+    pub fn draw_points_ex(
+        &self,
+        position: Vector3,
+        rotation_axis: Vector3,
+        rotation_angle: f32,
+        scale: Vector3,
+        tint: Color,
+    ) {
+        draw_model_points_ex(self.shallow_copy(), position, rotation_axis, rotation_angle, scale, tint);
+    }
+}
+
+impl Model {
+    /// Shallow copy sharing the meshes and materials, to pass the model by value to raylib functions.
+    /// NOTE: The copy must be moved into a raylib call, dropping it would unload the shared data
+    pub(crate) fn shallow_copy(&self) -> Model {
+        return unsafe { ptr::read(self) };
+    }
+}
+
+impl Drop for Model {
+    fn drop(&mut self) {
+        unload_model(unsafe { ptr::read(self) });
+    }
+}
+
+/// Decode a NUL-terminated fixed size name buffer (empty if not valid UTF-8)
+pub(crate) fn fixed_str(buffer: &[u8]) -> &str {
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    return std::str::from_utf8(&buffer[..len]).unwrap_or("");
+}
+
+/// Build a slice from a raylib array, empty if the array was never allocated
+pub(crate) unsafe fn raw_slice<'a, T>(ptr: *const T, count: i32) -> &'a [T] {
+    if ptr.is_null() || count <= 0 {
+        return &[];
+    }
+    return unsafe { slice::from_raw_parts(ptr, count as usize) };
+}
+
+/// Build a mutable slice from a raylib array, empty if the array was never allocated
+pub(crate) unsafe fn raw_slice_mut<'a, T>(ptr: *mut T, count: i32) -> &'a mut [T] {
+    if ptr.is_null() || count <= 0 {
+        return &mut [];
+    }
+    return unsafe { slice::from_raw_parts_mut(ptr, count as usize) };
+}
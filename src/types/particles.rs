@@ -0,0 +1,391 @@
+use crate::rlgl::get_texture_id_default;
+use crate::{
+    BlendMode, Camera, Color, PixelFormat, Rectangle, SpriteSheet, Texture, Texture2D, Vector2, Vector3,
+    begin_blend_mode, color_lerp, draw_billboard_pro, draw_rectangle_pro, draw_texture_pro, end_blend_mode,
+    get_random_value,
+};
+use std::f32::consts::PI;
+
+/// Space particles are simulated in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParticleSpace {
+    /// Screen or world 2D space, only x and y are used
+    #[default]
+    Space2D,
+    /// 3D world space, drawn as camera facing billboards
+    Space3D,
+}
+
+/// Area new particles are spawned in, around the emitter position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmissionShape {
+    Point,
+    /// Disc in the XY plane (2D) or XZ plane (3D)
+    Circle {
+        radius: f32,
+    },
+    /// Rectangle centered on the emitter in the XY plane (2D) or XZ plane (3D)
+    Rectangle {
+        width: f32,
+        height: f32,
+    },
+    /// Point spawning particles in random directions up to `angle` degrees away from the emitter direction
+    Cone {
+        angle: f32,
+    },
+}
+
+/// Particles emitted at once
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticleBurst {
+    /// Emitter time of the first burst in seconds
+    pub time: f32,
+    pub count: u32,
+    /// Seconds between repeated bursts, 0 to burst once
+    pub interval: f32,
+}
+
+/// Live particle of an emitter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    pub position: Vector3,
+    pub velocity: Vector3,
+    /// Rotation in degrees
+    pub rotation: f32,
+    /// Rotation speed in degrees per second
+    pub angular_velocity: f32,
+    /// Seconds since the particle was spawned
+    pub age: f32,
+    pub lifetime: f32,
+    /// Sprite frame index, used when frames are not animated over the particle life
+    pub frame: usize,
+}
+
+impl Particle {
+    /// Fraction of the lifetime elapsed, between 0.0 and 1.0
+    pub fn life(&self) -> f32 {
+        if self.lifetime <= 0.0 {
+            return 1.0;
+        }
+        return (self.age / self.lifetime).clamp(0.0, 1.0);
+    }
+}
+
+/// Particle emitter.
+///
+/// Ranges are `(min, max)` pairs picked with `get_random_value()`, call `set_random_seed()` for repeatable effects.
+/// Size and color curves are `(life, value)` keys with life between 0.0 and 1.0, interpolated linearly.
+#[derive(Debug, Clone)]
+pub struct ParticleEmitter {
+    pub space: ParticleSpace,
+    pub position: Vector3,
+    pub shape: EmissionShape,
+    /// Emission direction, normalized when spawning particles
+    pub direction: Vector3,
+    pub emitting: bool,
+    /// Particles spawned per second
+    pub rate: f32,
+    pub bursts: Vec<ParticleBurst>,
+    pub max_particles: usize,
+    /// Lifetime range in seconds
+    pub lifetime: (f32, f32),
+    /// Initial speed range along the emission direction
+    pub speed: (f32, f32),
+    pub gravity: Vector3,
+    /// Fraction of the velocity lost per second
+    pub drag: f32,
+    /// Initial rotation range in degrees
+    pub rotation: (f32, f32),
+    /// Rotation speed range in degrees per second
+    pub angular_velocity: (f32, f32),
+    pub size_over_life: Vec<(f32, f32)>,
+    pub color_over_life: Vec<(f32, Color)>,
+    /// Particle texture, a white square when `None`
+    pub texture: Option<Texture2D>,
+    /// Texture areas of the sprite frames, the whole texture when empty
+    pub frames: Vec<Rectangle>,
+    /// Play the frames over the particle life instead of picking a random frame per particle
+    pub animate_frames: bool,
+    pub blend_mode: BlendMode,
+    particles: Vec<Particle>,
+    time: f32,
+    spawn_accumulator: f32,
+}
+
+impl ParticleEmitter {
+    pub fn new(space: ParticleSpace) -> Self {
+        let direction = match space {
+            ParticleSpace::Space2D => Vector3::new(0.0, -1.0, 0.0),
+            ParticleSpace::Space3D => Vector3::new(0.0, 1.0, 0.0),
+        };
+        return Self {
+            space,
+            position: Vector3::zero(),
+            shape: EmissionShape::Point,
+            direction,
+            emitting: true,
+            rate: 10.0,
+            bursts: Vec::new(),
+            max_particles: 1000,
+            lifetime: (1.0, 1.0),
+            speed: (1.0, 1.0),
+            gravity: Vector3::zero(),
+            drag: 0.0,
+            rotation: (0.0, 0.0),
+            angular_velocity: (0.0, 0.0),
+            size_over_life: vec![(0.0, 1.0)],
+            color_over_life: vec![(0.0, Color::WHITE), (1.0, Color::new(255, 255, 255, 0))],
+            texture: None,
+            frames: Vec::new(),
+            animate_frames: false,
+            blend_mode: BlendMode::Alpha,
+            particles: Vec::new(),
+            time: 0.0,
+            spawn_accumulator: 0.0,
+        };
+    }
+
+    /// Use the texture and frame areas of a sprite sheet, trimming and rotated frames are ignored
+    pub fn set_sprite_sheet(&mut self, sheet: &SpriteSheet) {
+        self.texture = Some(sheet.texture);
+        self.frames = sheet.frames().iter().map(|frame| frame.source).collect();
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        return &self.particles;
+    }
+
+    pub fn particle_count(&self) -> usize {
+        return self.particles.len();
+    }
+
+    /// Seconds since the emitter started, used to schedule bursts
+    pub fn time(&self) -> f32 {
+        return self.time;
+    }
+
+    /// Remove every particle and restart the burst schedule
+    pub fn reset(&mut self) {
+        self.particles.clear();
+        self.time = 0.0;
+        self.spawn_accumulator = 0.0;
+    }
+
+    /// Spawn `count` particles now, up to `max_particles`
+    pub fn emit(&mut self, count: u32) {
+        for _ in 0..count {
+            if self.particles.len() >= self.max_particles {
+                return;
+            }
+            let particle = self.spawn();
+            self.particles.push(particle);
+        }
+    }
+
+    /// Spawn new particles and advance the live ones
+    pub fn update(&mut self, delta_time: f32) {
+        let previous = self.time;
+        self.time += delta_time;
+
+        if self.emitting {
+            self.spawn_accumulator += self.rate * delta_time;
+            let count = self.spawn_accumulator.floor();
+            self.spawn_accumulator -= count;
+            self.emit(count as u32);
+
+            let mut count = 0;
+            for burst in &self.bursts {
+                count += burst_count(burst, previous, self.time);
+            }
+            self.emit(count);
+        }
+
+        let damping = (1.0 - self.drag * delta_time).max(0.0);
+        for particle in &mut self.particles {
+            particle.velocity += self.gravity * delta_time;
+            particle.velocity *= damping;
+            particle.position += particle.velocity * delta_time;
+            particle.rotation += particle.angular_velocity * delta_time;
+            particle.age += delta_time;
+        }
+        self.particles.retain(|particle| particle.age < particle.lifetime);
+    }
+
+    /// Size of a particle at its current age
+    pub fn particle_size(&self, particle: &Particle) -> f32 {
+        return sample(&self.size_over_life, particle.life(), 1.0, |a, b, t| a + (b - a) * t);
+    }
+
+    /// Color of a particle at its current age
+    pub fn particle_color(&self, particle: &Particle) -> Color {
+        return sample(&self.color_over_life, particle.life(), Color::WHITE, color_lerp);
+    }
+
+    /// Draw the particles in 2D, call inside `Camera2D::begin_mode()` for world particles
+    pub fn draw(&self) {
+        begin_blend_mode(self.blend_mode);
+        for particle in &self.particles {
+            let size = self.particle_size(particle);
+            let color = self.particle_color(particle);
+            let position = Vector2::new(particle.position.x, particle.position.y);
+            match self.texture {
+                Some(texture) => {
+                    let source = self.frame_source(texture, particle);
+                    let width = size * source.width / source.height;
+                    let dest = Rectangle::new(position.x, position.y, width, size);
+                    let origin = Vector2::new(width / 2.0, size / 2.0);
+                    draw_texture_pro(texture, source, dest, origin, particle.rotation, color);
+                }
+                None => {
+                    let dest = Rectangle::new(position.x, position.y, size, size);
+                    draw_rectangle_pro(dest, Vector2::new(size / 2.0, size / 2.0), particle.rotation, color);
+                }
+            }
+        }
+        end_blend_mode();
+    }
+
+    /// Draw the particles as billboards, call inside `Camera3D::begin_mode()`
+    pub fn draw_3d(&self, camera: Camera) {
+        // NOTE: Untextured particles use the 1x1 white default texture
+        let texture = self.texture.unwrap_or(Texture2D(Texture {
+            id: get_texture_id_default(),
+            width: 1,
+            height: 1,
+            mipmaps: 1,
+            format: PixelFormat::UncompressedR8g8b8a8,
+        }));
+        let up = Vector3::new(0.0, 1.0, 0.0);
+
+        // NOTE: Particles are not depth sorted, additive blending avoids ordering artifacts
+        begin_blend_mode(self.blend_mode);
+        for particle in &self.particles {
+            let size = self.particle_size(particle);
+            let source = self.frame_source(texture, particle);
+            let size = Vector2::new(size * source.width / source.height, size);
+            let origin = size / 2.0;
+            let color = self.particle_color(particle);
+            draw_billboard_pro(
+                camera,
+                texture,
+                source,
+                particle.position,
+                up,
+                size,
+                origin,
+                particle.rotation,
+                color,
+            );
+        }
+        end_blend_mode();
+    }
+
+    fn frame_source(&self, texture: Texture2D, particle: &Particle) -> Rectangle {
+        if self.frames.is_empty() {
+            return Rectangle::new(0.0, 0.0, texture.width() as f32, texture.height() as f32);
+        }
+        let index = match self.animate_frames {
+            true => (particle.life() * self.frames.len() as f32) as usize,
+            false => particle.frame,
+        };
+        return self.frames[index.min(self.frames.len() - 1)];
+    }
+
+    fn spawn(&self) -> Particle {
+        let direction = self.direction.normalize();
+        let offset = match self.shape {
+            EmissionShape::Point | EmissionShape::Cone { .. } => (0.0, 0.0),
+            EmissionShape::Circle { radius } => {
+                // NOTE: Square root of the distance spreads points evenly over the disc
+                let angle = random_range((0.0, 2.0 * PI));
+                let distance = radius * random_range((0.0, 1.0)).sqrt();
+                (angle.cos() * distance, angle.sin() * distance)
+            }
+            EmissionShape::Rectangle { width, height } => (
+                random_range((-width / 2.0, width / 2.0)),
+                random_range((-height / 2.0, height / 2.0)),
+            ),
+        };
+        let offset = match self.space {
+            ParticleSpace::Space2D => Vector3::new(offset.0, offset.1, 0.0),
+            ParticleSpace::Space3D => Vector3::new(offset.0, 0.0, offset.1),
+        };
+        let direction = match self.shape {
+            EmissionShape::Cone { angle } => self.cone_direction(direction, angle.to_radians()),
+            _ => direction,
+        };
+
+        return Particle {
+            position: self.position + offset,
+            velocity: direction * random_range(self.speed),
+            rotation: random_range(self.rotation),
+            angular_velocity: random_range(self.angular_velocity),
+            age: 0.0,
+            lifetime: random_range(self.lifetime),
+            frame: get_random_value(0, self.frames.len().saturating_sub(1) as i32) as usize,
+        };
+    }
+
+    /// Random direction at most `angle` radians away from `axis`
+    fn cone_direction(&self, axis: Vector3, angle: f32) -> Vector3 {
+        match self.space {
+            ParticleSpace::Space2D => {
+                let (sin, cos) = random_range((-angle, angle)).sin_cos();
+                return Vector3::new(axis.x * cos - axis.y * sin, axis.x * sin + axis.y * cos, 0.0);
+            }
+            ParticleSpace::Space3D => {
+                // NOTE: Uniform cosine picks directions evenly over the cone cap
+                let cos_theta = 1.0 - random_range((0.0, 1.0)) * (1.0 - angle.min(PI).cos());
+                let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+                let phi = random_range((0.0, 2.0 * PI));
+                let helper = match axis.y.abs() < 0.99 {
+                    true => Vector3::new(0.0, 1.0, 0.0),
+                    false => Vector3::new(1.0, 0.0, 0.0),
+                };
+                let tangent = axis.cross(helper).normalize();
+                let bitangent = axis.cross(tangent);
+                return axis * cos_theta + tangent * (sin_theta * phi.cos()) + bitangent * (sin_theta * phi.sin());
+            }
+        }
+    }
+}
+
+/// Particles of `burst` scheduled in the `[from, to)` time span
+fn burst_count(burst: &ParticleBurst, from: f32, to: f32) -> u32 {
+    if burst.interval <= 0.0 {
+        return if from <= burst.time && burst.time < to {
+            burst.count
+        } else {
+            0
+        };
+    }
+    // Bursts scheduled before `time`
+    let scheduled = |time: f32| -> u32 { ((time - burst.time) / burst.interval).ceil().max(0.0) as u32 };
+    return (scheduled(to) - scheduled(from)) * burst.count;
+}
+
+/// Random value in the `(min, max)` range
+fn random_range(range: (f32, f32)) -> f32 {
+    const STEPS: i32 = 1 << 16;
+    let t = get_random_value(0, STEPS) as f32 / STEPS as f32;
+    return range.0 + (range.1 - range.0) * t;
+}
+
+/// Curve value at `t` interpolating between the surrounding keys
+fn sample<T: Copy>(keys: &[(f32, T)], t: f32, default: T, lerp: impl Fn(T, T, f32) -> T) -> T {
+    let Some(&(first_time, first)) = keys.first() else {
+        return default;
+    };
+    if t <= first_time {
+        return first;
+    }
+    for pair in keys.windows(2) {
+        let ((from_time, from), (to_time, to)) = (pair[0], pair[1]);
+        if t <= to_time {
+            let span = to_time - from_time;
+            let amount = if span > 0.0 { (t - from_time) / span } else { 1.0 };
+            return lerp(from, to, amount);
+        }
+    }
+    return keys[keys.len() - 1].1;
+}
@@ -1,4 +1,4 @@
-use crate::Vector3;
+use crate::{Camera3D, Matrix, Vector3, Vector4};
 
 /// Bounding box
 #[repr(C)]
@@ -33,3 +33,74 @@ pub struct RayCollision {
     /// Surface normal of hit
     pub normal: Vector3,
 }
+
+/// Frustum, the six planes (left, right, bottom, top, near, far) bounding a camera view volume.
+/// Planes are stored as (normal.x, normal.y, normal.z, distance) with normals pointing inside.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    pub planes: [Vector4; 6],
+}
+
+impl Frustum {
+    /// Frustum of a view-projection matrix (`view.multiply(projection)`)
+    pub fn from_matrix(view_projection: Matrix) -> Self {
+        let m = view_projection;
+        let rows = [
+            [m.m0, m.m4, m.m8, m.m12],
+            [m.m1, m.m5, m.m9, m.m13],
+            [m.m2, m.m6, m.m10, m.m14],
+        ];
+        let last = [m.m3, m.m7, m.m11, m.m15];
+
+        let plane = |row: [f32; 4], sign: f32| {
+            let (x, y, z, w) = (last[0] + sign * row[0], last[1] + sign * row[1], last[2] + sign * row[2], last[3] + sign * row[3]);
+            let length = (x * x + y * y + z * z).sqrt();
+            if length == 0.0 {
+                return Vector4 { x, y, z, w };
+            }
+            return Vector4 { x: x / length, y: y / length, z: z / length, w: w / length };
+        };
+
+        return Self {
+            planes: [
+                plane(rows[0], 1.0),
+                plane(rows[0], -1.0),
+                plane(rows[1], 1.0),
+                plane(rows[1], -1.0),
+                plane(rows[2], 1.0),
+                plane(rows[2], -1.0),
+            ],
+        };
+    }
+
+    /// Frustum of a 3d camera, using the same projection as `begin_mode_3d()` for a viewport aspect ratio
+    pub fn from_camera(camera: &Camera3D, aspect: f32) -> Self {
+        return Self::from_matrix(camera.matrix().multiply(camera.projection_matrix(aspect)));
+    }
+
+    /// Signed distance from a plane to a point, negative when outside
+    fn distance(plane: &Vector4, point: Vector3) -> f32 {
+        return plane.x * point.x + plane.y * point.y + plane.z * point.z + plane.w;
+    }
+
+    pub fn contains_point(&self, point: Vector3) -> bool {
+        return self.planes.iter().all(|plane| Self::distance(plane, point) >= 0.0);
+    }
+
+    pub fn intersects_sphere(&self, center: Vector3, radius: f32) -> bool {
+        return self.planes.iter().all(|plane| Self::distance(plane, center) >= -radius);
+    }
+
+    /// Conservative box test: boxes near the frustum corners may be reported visible
+    pub fn intersects_box(&self, bounds: &BoundingBox) -> bool {
+        return self.planes.iter().all(|plane| {
+            // Corner furthest along the plane normal
+            let corner = Vector3 {
+                x: if plane.x >= 0.0 { bounds.max.x } else { bounds.min.x },
+                y: if plane.y >= 0.0 { bounds.max.y } else { bounds.min.y },
+                z: if plane.z >= 0.0 { bounds.max.z } else { bounds.min.z },
+            };
+            return Self::distance(plane, corner) >= 0.0;
+        });
+    }
+}
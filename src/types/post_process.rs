@@ -0,0 +1,656 @@
+use crate::rlgl::{self, FramebufferAttachTextureType, FramebufferAttachType};
+use crate::{
+    Color, PixelFormat, Rectangle, RenderTexture, RenderTexture2D, SHADER_FS_HEADER, SHADER_VS_HEADER, Shader,
+    ShaderLocation, ShaderUniformDataType, Texture, Texture2D, Vector2, Vector3, Vector4, begin_texture_mode,
+    clear_background, draw_texture_pro, end_texture_mode, get_render_height, get_render_width, get_screen_height,
+    get_screen_width, get_time, is_window_resized, set_shader_value_texture,
+};
+
+const FULLSCREEN_VS: &str = r#"
+ATTRIBUTE vec3 vertexPosition;
+ATTRIBUTE vec2 vertexTexCoord;
+ATTRIBUTE vec4 vertexColor;
+
+uniform mat4 mvp;
+
+VARYING vec2 fragTexCoord;
+VARYING vec4 fragColor;
+
+void main()
+{
+    fragTexCoord = vertexTexCoord;
+    fragColor = vertexColor;
+    gl_Position = mvp*vec4(vertexPosition, 1.0);
+}
+"#;
+
+// NOTE: Built-in effects are written after SHADER_FS_HEADER and these inputs
+const EFFECT_FS_INPUTS: &str = r#"
+VARYING vec2 fragTexCoord;
+VARYING vec4 fragColor;
+"#;
+
+const BLOOM_BLUR_FS: &str = r#"
+uniform sampler2D texture0;
+uniform vec2 resolution;
+uniform vec2 direction;
+uniform float threshold;
+uniform float spread;
+
+vec3 bright(vec2 uv)
+{
+    vec3 color = TEXTURE(texture0, uv).rgb;
+    float luminance = dot(color, vec3(0.2126, 0.7152, 0.0722));
+    return color*max(luminance - threshold, 0.0)/max(luminance, 0.0001);
+}
+
+void main()
+{
+    vec2 offset = direction*spread/resolution;
+    vec3 sum = bright(fragTexCoord)*0.227027;
+    sum += (bright(fragTexCoord + offset) + bright(fragTexCoord - offset))*0.1945946;
+    sum += (bright(fragTexCoord + offset*2.0) + bright(fragTexCoord - offset*2.0))*0.1216216;
+    sum += (bright(fragTexCoord + offset*3.0) + bright(fragTexCoord - offset*3.0))*0.054054;
+    sum += (bright(fragTexCoord + offset*4.0) + bright(fragTexCoord - offset*4.0))*0.016216;
+    OUTPUT = vec4(sum, 1.0);
+}
+"#;
+
+const BLOOM_COMBINE_FS: &str = r#"
+uniform sampler2D texture0;
+uniform sampler2D effectInput;
+uniform float intensity;
+
+void main()
+{
+    vec4 scene = TEXTURE(effectInput, fragTexCoord);
+    vec3 bloom = TEXTURE(texture0, fragTexCoord).rgb;
+    OUTPUT = vec4(scene.rgb + bloom*intensity, scene.a);
+}
+"#;
+
+const TONE_MAPPING_FS: &str = r#"
+uniform sampler2D texture0;
+uniform float exposure;
+
+void main()
+{
+    vec4 color = TEXTURE(texture0, fragTexCoord);
+    vec3 x = color.rgb*exposure;
+
+    // ACES filmic curve approximation (Krzysztof Narkowicz)
+    vec3 mapped = clamp((x*(2.51*x + 0.03))/(x*(2.43*x + 0.59) + 0.14), 0.0, 1.0);
+    OUTPUT = vec4(mapped, color.a);
+}
+"#;
+
+const VIGNETTE_FS: &str = r#"
+uniform sampler2D texture0;
+uniform vec2 resolution;
+uniform float radius;
+uniform float softness;
+
+void main()
+{
+    vec4 color = TEXTURE(texture0, fragTexCoord);
+    vec2 position = (fragTexCoord - 0.5)*vec2(resolution.x/resolution.y, 1.0);
+    float vignette = smoothstep(radius, radius - softness, length(position));
+    OUTPUT = vec4(color.rgb*vignette, color.a);
+}
+"#;
+
+const CRT_FS: &str = r#"
+uniform sampler2D texture0;
+uniform vec2 resolution;
+uniform float curvature;
+uniform float scanlines;
+
+void main()
+{
+    vec2 uv = fragTexCoord*2.0 - 1.0;
+    vec2 offset = uv.yx*curvature;
+    uv = (uv + uv*offset*offset)*0.5 + 0.5;
+
+    if ((uv.x < 0.0) || (uv.x > 1.0) || (uv.y < 0.0) || (uv.y > 1.0))
+    {
+        OUTPUT = vec4(0.0, 0.0, 0.0, 1.0);
+        return;
+    }
+
+    vec4 color = TEXTURE(texture0, uv);
+    float scanline = sin(uv.y*resolution.y*3.14159265)*0.5 + 0.5;
+    OUTPUT = vec4(color.rgb*mix(1.0, scanline, scanlines), color.a);
+}
+"#;
+
+const PIXELATION_FS: &str = r#"
+uniform sampler2D texture0;
+uniform vec2 resolution;
+uniform float pixelSize;
+
+void main()
+{
+    vec2 cell = vec2(pixelSize)/resolution;
+    OUTPUT = TEXTURE(texture0, (floor(fragTexCoord/cell) + 0.5)*cell);
+}
+"#;
+
+const FXAA_FS: &str = r#"
+uniform sampler2D texture0;
+uniform vec2 resolution;
+
+const float SPAN_MAX = 8.0;
+const float REDUCE_MUL = 1.0/8.0;
+const float REDUCE_MIN = 1.0/128.0;
+
+void main()
+{
+    vec2 texel = 1.0/resolution;
+    vec4 center = TEXTURE(texture0, fragTexCoord);
+    vec3 luma = vec3(0.299, 0.587, 0.114);
+
+    float lumaNW = dot(TEXTURE(texture0, fragTexCoord + vec2(-1.0, -1.0)*texel).rgb, luma);
+    float lumaNE = dot(TEXTURE(texture0, fragTexCoord + vec2(1.0, -1.0)*texel).rgb, luma);
+    float lumaSW = dot(TEXTURE(texture0, fragTexCoord + vec2(-1.0, 1.0)*texel).rgb, luma);
+    float lumaSE = dot(TEXTURE(texture0, fragTexCoord + vec2(1.0, 1.0)*texel).rgb, luma);
+    float lumaM = dot(center.rgb, luma);
+    float lumaMin = min(lumaM, min(min(lumaNW, lumaNE), min(lumaSW, lumaSE)));
+    float lumaMax = max(lumaM, max(max(lumaNW, lumaNE), max(lumaSW, lumaSE)));
+
+    vec2 dir = vec2(-((lumaNW + lumaNE) - (lumaSW + lumaSE)), (lumaNW + lumaSW) - (lumaNE + lumaSE));
+    float dirReduce = max((lumaNW + lumaNE + lumaSW + lumaSE)*(0.25*REDUCE_MUL), REDUCE_MIN);
+    float rcpDirMin = 1.0/(min(abs(dir.x), abs(dir.y)) + dirReduce);
+    dir = clamp(dir*rcpDirMin, vec2(-SPAN_MAX), vec2(SPAN_MAX))*texel;
+
+    vec3 rgbA = 0.5*(TEXTURE(texture0, fragTexCoord + dir*(1.0/3.0 - 0.5)).rgb +
+        TEXTURE(texture0, fragTexCoord + dir*(2.0/3.0 - 0.5)).rgb);
+    vec3 rgbB = rgbA*0.5 + 0.25*(TEXTURE(texture0, fragTexCoord - dir*0.5).rgb +
+        TEXTURE(texture0, fragTexCoord + dir*0.5).rgb);
+    float lumaB = dot(rgbB, luma);
+
+    OUTPUT = vec4(((lumaB < lumaMin) || (lumaB > lumaMax))? rgbA : rgbB, center.a);
+}
+"#;
+
+const COLOR_GRADING_FS: &str = r#"
+uniform sampler2D texture0;
+uniform sampler2D lut;
+uniform float lutSize;
+uniform float intensity;
+
+void main()
+{
+    vec4 color = TEXTURE(texture0, fragTexCoord);
+    vec3 c = clamp(color.rgb, 0.0, 1.0);
+
+    // LUT laid out as a horizontal strip of blue slices, red along x and green along y in each slice
+    float blue = c.b*(lutSize - 1.0);
+    float slice0 = floor(blue);
+    float slice1 = min(slice0 + 1.0, lutSize - 1.0);
+    float x = (c.r*(lutSize - 1.0) + 0.5)/(lutSize*lutSize);
+    float y = (c.g*(lutSize - 1.0) + 0.5)/lutSize;
+    vec3 graded = mix(TEXTURE(lut, vec2(x + slice0/lutSize, y)).rgb, TEXTURE(lut, vec2(x + slice1/lutSize, y)).rgb,
+        blue - slice0);
+
+    OUTPUT = vec4(mix(color.rgb, graded, intensity), color.a);
+}
+"#;
+
+/// Value of a post-processing uniform, uploaded before every draw of the pass
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PostProcessUniform {
+    Float(f32),
+    Vec2(Vector2),
+    Vec3(Vector3),
+    Vec4(Vector4),
+    Int(i32),
+    /// Texture bound to a sampler uniform, it must stay loaded while the pass is used
+    Texture(Texture2D),
+}
+
+/// Fullscreen shader pass, drawing its input texture (`texture0`) through the shader.
+///
+/// Besides the uniforms set with `set_uniform()`, the stack provides to every pass:
+/// - `vec2 resolution`: render size in pixels
+/// - `float time`: time since the window was initialized in seconds
+/// - `sampler2D effectInput`: input of the effect the pass belongs to (for multi-pass effects)
+#[derive(Debug)]
+pub struct PostProcessPass {
+    shader: Shader,
+    uniforms: Vec<(ShaderLocation, PostProcessUniform)>,
+    resolution: ShaderLocation,
+    time: ShaderLocation,
+    effect_input: ShaderLocation,
+}
+
+impl PostProcessPass {
+    pub fn new(shader: Shader) -> Self {
+        return Self {
+            resolution: shader.uniform_location("resolution"),
+            time: shader.uniform_location("time"),
+            effect_input: shader.uniform_location("effectInput"),
+            uniforms: Vec::new(),
+            shader,
+        };
+    }
+
+    /// Pass from a fragment shader, using a vertex shader that outputs `fragTexCoord` and `fragColor`
+    pub fn from_fragment(fs_code: &str) -> Self {
+        return Self::new(Shader::from_memory(
+            &format!("{}{}", SHADER_VS_HEADER, FULLSCREEN_VS),
+            fs_code,
+        ));
+    }
+
+    /// Pass from the body of a built-in effect shader
+    fn builtin(fs_body: &str) -> Self {
+        return Self::from_fragment(&format!("{}{}{}", SHADER_FS_HEADER, EFFECT_FS_INPUTS, fs_body));
+    }
+
+    pub fn shader(&self) -> &Shader {
+        return &self.shader;
+    }
+
+    /// Set a uniform value, returns false if the shader does not use the uniform
+    pub fn set_uniform(&mut self, name: &str, value: PostProcessUniform) -> bool {
+        let location = self.shader.uniform_location(name);
+        if !location.is_valid() {
+            return false;
+        }
+        match self.uniforms.iter_mut().find(|(uniform, _)| *uniform == location) {
+            Some((_, current)) => *current = value,
+            None => self.uniforms.push((location, value)),
+        }
+        return true;
+    }
+
+    /// Draw `input` through the shader, the current target must have the size of the input
+    fn draw(&self, input: Texture2D, effect_input: Texture2D, dest: Rectangle) {
+        let width = input.width() as f32;
+        let height = input.height() as f32;
+
+        self.shader.begin_mode();
+        self.set(self.resolution, PostProcessUniform::Vec2(Vector2::new(width, height)));
+        self.set(self.time, PostProcessUniform::Float(get_time() as f32));
+        self.set(self.effect_input, PostProcessUniform::Texture(effect_input));
+        for &(location, value) in &self.uniforms {
+            self.set(location, value);
+        }
+        // NOTE: Render textures are stored upside down, flip them when drawing
+        draw_texture_pro(
+            input,
+            Rectangle::new(0.0, 0.0, width, -height),
+            dest,
+            Vector2::new(0.0, 0.0),
+            0.0,
+            Color::WHITE,
+        );
+        self.shader.end_mode();
+    }
+
+    fn set(&self, location: ShaderLocation, value: PostProcessUniform) {
+        if !location.is_valid() {
+            return;
+        }
+        match value {
+            PostProcessUniform::Float(value) => self.shader.set(location, &value, ShaderUniformDataType::Float),
+            PostProcessUniform::Vec2(value) => self.shader.set(location, &value, ShaderUniformDataType::Vec2),
+            PostProcessUniform::Vec3(value) => self.shader.set(location, &value, ShaderUniformDataType::Vec3),
+            PostProcessUniform::Vec4(value) => self.shader.set(location, &value, ShaderUniformDataType::Vec4),
+            PostProcessUniform::Int(value) => self.shader.set(location, &value, ShaderUniformDataType::Int),
+            PostProcessUniform::Texture(texture) => set_shader_value_texture(self.shader.clone(), location, texture),
+        }
+    }
+}
+
+/// Post-processing effect made of one or more passes run in order
+#[derive(Debug)]
+pub struct PostProcessEffect {
+    pub name: String,
+    /// Disabled effects are skipped by the stack
+    pub enabled: bool,
+    passes: Vec<PostProcessPass>,
+}
+
+impl PostProcessEffect {
+    /// Single pass effect
+    pub fn new(name: &str, shader: Shader) -> Self {
+        return Self::from_passes(name, vec![PostProcessPass::new(shader)]);
+    }
+
+    pub fn from_passes(name: &str, passes: Vec<PostProcessPass>) -> Self {
+        return Self {
+            name: name.to_string(),
+            enabled: true,
+            passes,
+        };
+    }
+
+    pub fn passes(&self) -> &[PostProcessPass] {
+        return &self.passes;
+    }
+
+    pub fn passes_mut(&mut self) -> &mut [PostProcessPass] {
+        return &mut self.passes;
+    }
+
+    /// Set a uniform on every pass using it
+    pub fn set_uniform(&mut self, name: &str, value: PostProcessUniform) {
+        for pass in &mut self.passes {
+            pass.set_uniform(name, value);
+        }
+    }
+
+    /// Bloom: bright areas above `threshold` luminance are blurred and added back with `intensity`.
+    /// Uniforms: `threshold`, `intensity`, `spread` (blur tap distance in pixels, 1.5 by default)
+    pub fn bloom(threshold: f32, intensity: f32) -> Self {
+        let mut horizontal = PostProcessPass::builtin(BLOOM_BLUR_FS);
+        horizontal.set_uniform("direction", PostProcessUniform::Vec2(Vector2::new(1.0, 0.0)));
+        horizontal.set_uniform("threshold", PostProcessUniform::Float(threshold));
+
+        // NOTE: The vertical blur runs on already thresholded colors
+        let mut vertical = PostProcessPass::builtin(BLOOM_BLUR_FS);
+        vertical.set_uniform("direction", PostProcessUniform::Vec2(Vector2::new(0.0, 1.0)));
+        vertical.set_uniform("threshold", PostProcessUniform::Float(0.0));
+
+        let mut effect = Self::from_passes(
+            "bloom",
+            vec![horizontal, vertical, PostProcessPass::builtin(BLOOM_COMBINE_FS)],
+        );
+        effect.set_uniform("spread", PostProcessUniform::Float(1.5));
+        effect.set_uniform("intensity", PostProcessUniform::Float(intensity));
+        return effect;
+    }
+
+    /// ACES filmic tone mapping of the colors scaled by `exposure`.
+    /// Uniforms: `exposure`
+    pub fn tone_mapping(exposure: f32) -> Self {
+        let mut effect = Self::from_passes("tone_mapping", vec![PostProcessPass::builtin(TONE_MAPPING_FS)]);
+        effect.set_uniform("exposure", PostProcessUniform::Float(exposure));
+        return effect;
+    }
+
+    /// Darken the screen borders, from `radius` (in screen heights from the center) over `softness`.
+    /// Uniforms: `radius`, `softness`
+    pub fn vignette(radius: f32, softness: f32) -> Self {
+        let mut effect = Self::from_passes("vignette", vec![PostProcessPass::builtin(VIGNETTE_FS)]);
+        effect.set_uniform("radius", PostProcessUniform::Float(radius));
+        effect.set_uniform("softness", PostProcessUniform::Float(softness));
+        return effect;
+    }
+
+    /// CRT monitor: barrel distortion by `curvature` and scanlines darkening by `scanlines` [0..1].
+    /// Uniforms: `curvature`, `scanlines`
+    pub fn crt(curvature: f32, scanlines: f32) -> Self {
+        let mut effect = Self::from_passes("crt", vec![PostProcessPass::builtin(CRT_FS)]);
+        effect.set_uniform("curvature", PostProcessUniform::Float(curvature));
+        effect.set_uniform("scanlines", PostProcessUniform::Float(scanlines));
+        return effect;
+    }
+
+    /// Pixelation into square cells of `pixel_size` pixels.
+    /// Uniforms: `pixelSize`
+    pub fn pixelation(pixel_size: f32) -> Self {
+        let mut effect = Self::from_passes("pixelation", vec![PostProcessPass::builtin(PIXELATION_FS)]);
+        effect.set_uniform("pixelSize", PostProcessUniform::Float(pixel_size));
+        return effect;
+    }
+
+    /// Fast approximate anti-aliasing
+    pub fn fxaa() -> Self {
+        return Self::from_passes("fxaa", vec![PostProcessPass::builtin(FXAA_FS)]);
+    }
+
+    /// Color grading with a lookup table texture: a strip of N blue slices of NxN (e.g. 256x16),
+    /// red increasing along x and green along y. The texture must stay loaded while the effect is used.
+    /// Uniforms: `intensity` (blend with the original colors, 1.0 by default)
+    pub fn color_grading(lut: Texture2D) -> Self {
+        let mut effect = Self::from_passes("color_grading", vec![PostProcessPass::builtin(COLOR_GRADING_FS)]);
+        effect.set_uniform("lut", PostProcessUniform::Texture(lut));
+        effect.set_uniform("lutSize", PostProcessUniform::Float(lut.height() as f32));
+        effect.set_uniform("intensity", PostProcessUniform::Float(1.0));
+        return effect;
+    }
+}
+
+/// Texture read by a pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Scene,
+    Target(usize),
+}
+
+/// Ordered list of post-processing effects applied to the scene drawn between `begin()` and `end()`.
+/// Render targets have the render size and are recreated when the window is resized.
+/// Effects are pushed in order, e.g. bloom then tone mapping then vignette.
+#[derive(Debug)]
+pub struct PostProcessStack {
+    effects: Vec<PostProcessEffect>,
+    scene: Option<RenderTexture2D>,
+    /// Ping-pong targets, a third one is created for multi-pass effects reading their input
+    targets: Vec<RenderTexture2D>,
+    width: i32,
+    height: i32,
+    hdr: bool,
+}
+
+impl Default for PostProcessStack {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl PostProcessStack {
+    /// Stack with HDR render targets where supported
+    pub fn new() -> Self {
+        return Self {
+            effects: Vec::new(),
+            scene: None,
+            targets: Vec::new(),
+            width: 0,
+            height: 0,
+            hdr: true,
+        };
+    }
+
+    /// Render the scene and intermediate passes to half-float (or float) targets so bloom and tone mapping see
+    /// values above 1.0 (enabled by default). Falls back to 8-bit targets when float targets are not renderable.
+    pub fn hdr(mut self, hdr: bool) -> Self {
+        self.hdr = hdr;
+        self.unload();
+        return self;
+    }
+
+    /// Add an effect at the end of the stack, returns its index
+    pub fn push(&mut self, effect: PostProcessEffect) -> usize {
+        self.effects.push(effect);
+        return self.effects.len() - 1;
+    }
+
+    pub fn insert(&mut self, index: usize, effect: PostProcessEffect) {
+        self.effects.insert(index, effect);
+    }
+
+    pub fn remove(&mut self, index: usize) -> PostProcessEffect {
+        return self.effects.remove(index);
+    }
+
+    pub fn effects(&self) -> &[PostProcessEffect] {
+        return &self.effects;
+    }
+
+    pub fn effects_mut(&mut self) -> &mut [PostProcessEffect] {
+        return &mut self.effects;
+    }
+
+    /// First effect with the given name
+    pub fn effect_mut(&mut self, name: &str) -> Option<&mut PostProcessEffect> {
+        return self.effects.iter_mut().find(|effect| effect.name == name);
+    }
+
+    /// Scene texture of the last frame, `None` before the first `begin()`
+    pub fn scene(&self) -> Option<Texture2D> {
+        return self.scene.map(|scene| scene.texture());
+    }
+
+    /// Begin drawing the scene
+    pub fn begin(&mut self) {
+        let (width, height) = (get_render_width(), get_render_height());
+        if self.scene.is_none() || is_window_resized() || width != self.width || height != self.height {
+            self.resize(width, height);
+        }
+        begin_texture_mode(self.scene.unwrap());
+    }
+
+    /// End drawing the scene and draw it through the enabled effects to the screen.
+    /// NOTE: Must be called between `begin_drawing()` and `end_drawing()`
+    pub fn end(&mut self) {
+        end_texture_mode();
+
+        let screen = Rectangle::new(0.0, 0.0, get_screen_width() as f32, get_screen_height() as f32);
+        let target_area = Rectangle::new(0.0, 0.0, self.width as f32, self.height as f32);
+        let passes: Vec<(usize, usize)> = self
+            .effects
+            .iter()
+            .enumerate()
+            .filter(|(_, effect)| effect.enabled)
+            .flat_map(|(index, effect)| (0..effect.passes.len()).map(move |pass| (index, pass)))
+            .collect();
+
+        let mut current = Source::Scene;
+        let mut effect_input = Source::Scene;
+        for (index, &(effect, pass)) in passes.iter().enumerate() {
+            if pass == 0 {
+                effect_input = current;
+            }
+            let input = self.texture(current);
+            let input_of_effect = self.texture(effect_input);
+            let pass = &self.effects[effect].passes[pass];
+
+            if index == passes.len() - 1 {
+                pass.draw(input, input_of_effect, screen);
+                return;
+            }
+
+            // NOTE: The target must not be the pass input nor the effect input still needed by later passes
+            let target = (0..3)
+                .find(|&target| ![current, effect_input].contains(&Source::Target(target)))
+                .unwrap();
+            if target == self.targets.len() {
+                self.targets.push(load_target(self.width, self.height, self.hdr, false));
+            }
+
+            begin_texture_mode(self.targets[target]);
+            clear_background(Color::BLANK);
+            // NOTE: Passes replace the target content, alpha is kept as output by the shader
+            rlgl::disable_color_blend();
+            pass.draw(input, input_of_effect, target_area);
+            rlgl::enable_color_blend();
+            end_texture_mode();
+            current = Source::Target(target);
+        }
+
+        // No enabled effects, draw the scene as is
+        let scene = self.texture(Source::Scene);
+        draw_texture_pro(
+            scene,
+            Rectangle::new(0.0, 0.0, self.width as f32, -self.height as f32),
+            screen,
+            Vector2::new(0.0, 0.0),
+            0.0,
+            Color::WHITE,
+        );
+    }
+
+    fn texture(&self, source: Source) -> Texture2D {
+        match source {
+            Source::Scene => return self.scene.unwrap().texture(),
+            Source::Target(index) => return self.targets[index].texture(),
+        }
+    }
+
+    /// Recreate the render targets with a new size
+    fn resize(&mut self, width: i32, height: i32) {
+        self.unload();
+        self.width = width;
+        self.height = height;
+        self.scene = Some(load_target(width, height, self.hdr, true));
+    }
+
+    fn unload(&mut self) {
+        if let Some(scene) = self.scene.take() {
+            scene.unload();
+        }
+        for target in self.targets.drain(..) {
+            target.unload();
+        }
+    }
+}
+
+impl Drop for PostProcessStack {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+/// Render target for the scene or a pass, with a depth renderbuffer for the scene
+fn load_target(width: i32, height: i32, hdr: bool, depth: bool) -> RenderTexture2D {
+    if hdr {
+        for format in [
+            PixelFormat::UncompressedR16g16b16a16,
+            PixelFormat::UncompressedR32g32b32a32,
+        ] {
+            if let Some(target) = load_float_target(width, height, format, depth) {
+                return target;
+            }
+        }
+    }
+    return RenderTexture2D::load(width, height);
+}
+
+/// Render texture with a float color format, `None` if it cannot be rendered to
+fn load_float_target(width: i32, height: i32, format: PixelFormat, depth: bool) -> Option<RenderTexture2D> {
+    let id = rlgl::load_framebuffer();
+    if id == 0 {
+        return None;
+    }
+
+    let color = Texture {
+        id: rlgl::load_texture(None, width, height, format, 1),
+        width,
+        height,
+        mipmaps: 1,
+        format,
+    };
+    rlgl::framebuffer_attach(
+        id,
+        color.id,
+        FramebufferAttachType::ColorChannel0,
+        FramebufferAttachTextureType::Texture2D,
+        0,
+    );
+    // NOTE: Depth renderbuffers have no matching pixel format, it is never used to upload data
+    let mut depth_buffer = Texture { id: 0, ..color };
+    if depth {
+        depth_buffer.id = rlgl::load_texture_depth(width, height, true);
+        rlgl::framebuffer_attach(
+            id,
+            depth_buffer.id,
+            FramebufferAttachType::Depth,
+            FramebufferAttachTextureType::Renderbuffer,
+            0,
+        );
+    }
+
+    // NOTE: Float formats unsupported by the driver leave the color texture without storage
+    if color.id == 0 || !rlgl::framebuffer_complete(id) {
+        rlgl::unload_texture(color.id);
+        rlgl::unload_framebuffer(id);
+        return None;
+    }
+    return Some(RenderTexture2D(RenderTexture {
+        id,
+        texture: color,
+        depth: depth_buffer,
+    }));
+}
@@ -1,39 +1,4 @@
-use crate::{begin_shader_mode, end_shader_mode, get_shader_location, is_shader_valid, load_shader, load_shader_from_memory, set_shader_value, set_shader_value_matrix, set_shader_value_v, unload_shader, Color, Matrix, Texture2D, Transform};
-
-/// Material map index
-#[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum MaterialMapIndex {
-    /// Albedo material (same as: MATERIAL_MAP_DIFFUSE)
-    Albedo = 0,
-    /// Metalness material (same as: MATERIAL_MAP_SPECULAR)
-    Metalness,
-    /// Normal material
-    Normal,
-    /// Roughness material
-    Roughness,
-    /// Ambient occlusion material
-    Occlusion,
-    /// Emission material
-    Emission,
-    /// Heightmap material
-    Height,
-    /// Cubemap material (NOTE: Uses GL_TEXTURE_CUBE_MAP)
-    Cubemap,
-    /// Irradiance material (NOTE: Uses GL_TEXTURE_CUBE_MAP)
-    Irradiance,
-    /// Prefilter material (NOTE: Uses GL_TEXTURE_CUBE_MAP)
-    Prefilter,
-    /// Brdf material
-    Brdf,
-}
-
-impl MaterialMapIndex {
-    #[allow(non_upper_case_globals)]
-    pub const Diffuse: Self = Self::Albedo;
-    #[allow(non_upper_case_globals)]
-    pub const Specular: Self = Self::Metalness;
-}
+use crate::{begin_shader_mode, end_shader_mode, get_shader_location, get_shader_location_attrib, is_shader_valid, load_shader, load_shader_from_memory, set_shader_value, set_shader_value_matrix, set_shader_value_v, unload_shader, Matrix};
 
 /// Shader location index
 #[repr(u32)]
@@ -154,54 +119,16 @@ pub enum ShaderAttributeDataType {
     Vec4,
 }
 
-/// Mesh, vertex data and vao/vbo
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct Mesh {
-    /// Number of vertices stored in arrays
-    pub vertex_count: i32,
-    /// Number of triangles stored (indexed or not)
-    pub triangle_count: i32,
-
-    /// Vertex and Attribute data
-    /// Vertex position (XYZ - 3 components per vertex) (shader-location = 0)
-    pub vertices: *mut f32,
-    /// Vertex texture coordinates (UV - 2 components per vertex) (shader-location = 1)
-    pub tex_coords: *mut f32,
-    /// Vertex texture second coordinates (UV - 2 components per vertex) (shader-location = 5)
-    pub tex_coords2: *mut f32,
-    /// Vertex normals (XYZ - 3 components per vertex) (shader-location = 2)
-    pub normals: *mut f32,
-    /// Vertex tangents (XYZW - 4 components per vertex) (shader-location = 4)
-    pub tangents: *mut f32,
-    /// Vertex colors (RGBA - 4 components per vertex) (shader-location = 3)
-    pub colors: *mut u8,
-    /// Vertex indices (in case vertex data comes indexed)
-    pub indices: *mut u16,
-
-    /// Animation vertex data
-    /// Animated vertex positions (after bones transformations)
-    pub anim_vertices: *mut f32,
-    /// Animated normals (after bones transformations)
-    pub anim_normals: *mut f32,
-    /// Vertex bone ids, max 255 bone ids, up to 4 bones influence by vertex (skinning) (shader-location = 6)
-    pub bone_ids: *mut u8,
-    /// Vertex bone weight, up to 4 bones influence by vertex (skinning) (shader-location = 7)
-    pub bone_weights: *mut f32,
-    /// Bones animated transformation matrices
-    pub bone_matrices: *mut Matrix,
-    /// Number of bones
-    pub bone_count: i32,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShaderLocation(i32);
 
-    /// OpenGL identifiers
-    /// OpenGL Vertex Array Object id
-    pub vao_id: u32,
-    /// OpenGL Vertex Buffer Objects id (default vertex data)
-    pub vbo_id: *mut u32,
+impl ShaderLocation {
+    /// Check if the location was found in the shader (not -1)
+    pub fn is_valid(&self) -> bool {
+        return self.0 >= 0;
+    }
 }
 
-pub struct ShaderLocation(i32);
-
 impl From<i32> for ShaderLocation {
     fn from(location: i32) -> Self {
         return Self(location);
@@ -250,7 +177,24 @@ impl Shader {
     }
 
     pub fn attrib_location(&self, attrib_name: &str) -> ShaderLocation {
-        return get_shader_location(self.clone(), attrib_name);
+        return get_shader_location_attrib(self.clone(), attrib_name);
+    }
+
+    /// Location stored in the shader locations array (used by raylib to bind default attributes and uniforms),
+    /// -1 if the shader has no locations array
+    pub fn location_index(&self, index: ShaderLocationIndex) -> ShaderLocation {
+        if self.locs.is_null() {
+            return ShaderLocation(-1);
+        }
+        return ShaderLocation(unsafe { *self.locs.add(index as usize) } as i32);
+    }
+
+    /// Does nothing if the shader has no locations array
+    pub fn set_location_index(&mut self, index: ShaderLocationIndex, location: ShaderLocation) {
+        if self.locs.is_null() {
+            return;
+        }
+        unsafe { *self.locs.add(index as usize) = location.0 as u32 };
     }
 
     pub fn set<T>(&self, location: ShaderLocation, value: &T, uniform_type: ShaderUniformDataType) {
@@ -278,88 +222,26 @@ impl Drop for Shader {
     }
 }
 
-/// MaterialMap
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct MaterialMap {
-    /// Material map texture
-    pub texture: Texture2D,
-    /// Material map color
-    pub color: Color,
-    /// Material map value
-    pub value: f32,
-}
+// NOTE: Built-in shaders are written once, the headers map ATTRIBUTE, VARYING, TEXTURE, TEXTURE_CUBE, TEXTURE_CUBE_LOD
+// and OUTPUT to the GLSL version of the platform
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const SHADER_VS_HEADER: &str = "#version 330\n#define ATTRIBUTE in\n#define VARYING out\n";
 
-/// Material, includes shader and maps
-#[repr(C)]
-#[derive(Debug)]
-pub struct Material {
-    /// Material shader
-    pub shader: Shader,
-    /// Material maps array
-    pub maps: *mut MaterialMap,
-    /// Material generic parameters (if required)
-    pub params: [f32; 4],
-}
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const SHADER_FS_HEADER: &str = concat!(
+    "#version 330\n#define VARYING in\n#define TEXTURE texture\n#define TEXTURE_CUBE texture\n",
+    "#define TEXTURE_CUBE_LOD textureLod\n#define OUTPUT finalColor\nout vec4 finalColor;\n"
+);
 
-impl Clone for Material {
-    fn clone(&self) -> Self {
-        return Self { shader: self.shader.clone(), maps: self.maps, params: self.params };
-    }
-}
+#[cfg(target_arch = "wasm32")]
+pub(crate) const SHADER_VS_HEADER: &str = "#version 100\n#define ATTRIBUTE attribute\n#define VARYING varying\n";
 
-/// Bone, skeletal animation bone
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct BoneInfo {
-    /// Bone name
-    pub name: [u8; 32],
-    /// Bone parent
-    pub parent: i32,
-}
-
-/// Model, meshes, materials and animation data
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct Model {
-    /// Local transform matrix
-    pub transform: Matrix,
-
-    /// Number of meshes
-    pub mesh_count: i32,
-    /// Number of materials
-    pub material_count: i32,
-    /// Meshes array
-    pub meshes: *mut Mesh,
-    /// Materials array
-    pub materials: *mut Material,
-    /// Mesh material number
-    pub mesh_material: i32,
-
-    /// Animation data
-    /// Number of bones
-    pub bone_count: i32,
-    /// Bones information (skeleton)
-    pub bones: *mut BoneInfo,
-    /// Bones base transformation (pose)
-    pub bind_pose: *mut Transform,
-}
-
-/// ModelAnimation
-#[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub struct ModelAnimation {
-    /// Number of bones
-    pub bone_count: i32,
-    /// Number of animation frames
-    pub frame_count: i32,
-    /// Bones information (skeleton)
-    pub bones: *mut BoneInfo,
-    /// Poses array by frame
-    pub frame_poses: *mut Transform,
-    /// Animation name
-    pub name: [u8; 32],
-}
+// NOTE: GLSL 100 has no explicit level of detail in fragment shaders, it is used as a bias
+#[cfg(target_arch = "wasm32")]
+pub(crate) const SHADER_FS_HEADER: &str = concat!(
+    "#version 100\nprecision mediump float;\n#define VARYING varying\n#define TEXTURE texture2D\n",
+    "#define TEXTURE_CUBE textureCube\n#define TEXTURE_CUBE_LOD textureCube\n#define OUTPUT gl_FragColor\n"
+);
 
 /// Color blending modes (pre-defined)
 #[repr(i32)]
@@ -377,8 +259,29 @@ pub enum BlendMode {
     SubtractColors,
     /// Blend premultiplied textures considering alpha
     AlphaPremultiply,
-    /// Blend textures using custom src/dst factors (use rlSetBlendFactors())
+    /// Blend textures using custom src/dst factors (use `rlgl::set_blend_factors()`)
     Custom,
-    /// Blend textures using custom rgb/alpha separate src/dst factors (use rlSetBlendFactorsSeparate())
+    /// Blend textures using custom rgb/alpha separate src/dst factors (use `rlgl::set_blend_factors_separate()`)
     CustomSeparate,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::ManuallyDrop;
+    use std::ptr;
+
+    #[test]
+    fn location_index_without_locations() {
+        // NOTE: Never unloaded, there is no GL context in tests
+        let mut shader = ManuallyDrop::new(Shader { id: 0, locs: ptr::null_mut() });
+        shader.set_location_index(ShaderLocationIndex::VertexPosition, ShaderLocation(3));
+        assert_eq!(shader.location_index(ShaderLocationIndex::VertexPosition), ShaderLocation(-1));
+
+        let mut locs = [0u32; 32];
+        let mut shader = ManuallyDrop::new(Shader { id: 0, locs: locs.as_mut_ptr() });
+        shader.set_location_index(ShaderLocationIndex::VertexTexCoord01, ShaderLocation(3));
+        assert_eq!(shader.location_index(ShaderLocationIndex::VertexTexCoord01), ShaderLocation(3));
+        assert_eq!(locs[1], 3);
+    }
+}
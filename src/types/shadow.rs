@@ -0,0 +1,296 @@
+use crate::rlgl;
+use crate::{
+    Camera3D, CameraProjection, Color, DepthAttachment, Framebuffer, FramebufferBuilder, MaterialMapIndex, Matrix,
+    SHADER_FS_HEADER, SHADER_VS_HEADER, Shader, ShaderUniformDataType, Texture2D, Vector3, clear_background,
+    color_normalize,
+};
+
+const SHADOW_VS: &str = r#"
+ATTRIBUTE vec3 vertexPosition;
+ATTRIBUTE vec2 vertexTexCoord;
+ATTRIBUTE vec3 vertexNormal;
+ATTRIBUTE vec4 vertexColor;
+
+uniform mat4 mvp;
+uniform mat4 matModel;
+uniform mat4 matNormal;
+uniform mat4 lightVP;
+
+VARYING vec3 fragPosition;
+VARYING vec2 fragTexCoord;
+VARYING vec4 fragColor;
+VARYING vec3 fragNormal;
+VARYING vec4 fragShadowPosition;
+
+void main()
+{
+    vec4 worldPosition = matModel*vec4(vertexPosition, 1.0);
+    fragPosition = worldPosition.xyz;
+    fragTexCoord = vertexTexCoord;
+    fragColor = vertexColor;
+    fragNormal = normalize(vec3(matNormal*vec4(vertexNormal, 0.0)));
+    fragShadowPosition = lightVP*worldPosition;
+    gl_Position = mvp*vec4(vertexPosition, 1.0);
+}
+"#;
+
+const SHADOW_FS: &str = r#"
+VARYING vec3 fragPosition;
+VARYING vec2 fragTexCoord;
+VARYING vec4 fragColor;
+VARYING vec3 fragNormal;
+VARYING vec4 fragShadowPosition;
+
+uniform sampler2D texture0;
+uniform vec4 colDiffuse;
+
+uniform sampler2D shadowMap;
+uniform float shadowMapResolution;
+uniform float shadowBias;
+uniform int lightType;
+uniform vec3 lightPosition;
+uniform vec3 lightDirection;
+uniform float lightCutoff;
+uniform vec4 lightColor;
+uniform vec4 ambient;
+uniform vec3 viewPos;
+
+// Fraction of the 3x3 PCF samples in shadow
+float shadowAmount(vec3 normal, vec3 toLight)
+{
+    vec3 position = fragShadowPosition.xyz/fragShadowPosition.w*0.5 + 0.5;
+    if ((position.z > 1.0) || (position.x < 0.0) || (position.x > 1.0) || (position.y < 0.0) || (position.y > 1.0)) return 0.0;
+
+    float bias = max(shadowBias*(1.0 - dot(normal, toLight)), shadowBias*0.1);
+    vec2 texelSize = vec2(1.0/shadowMapResolution);
+    float shadow = 0.0;
+    for (int x = -1; x <= 1; x++)
+    {
+        for (int y = -1; y <= 1; y++)
+        {
+            float depth = TEXTURE(shadowMap, position.xy + vec2(float(x), float(y))*texelSize).r;
+            if (position.z - bias > depth) shadow += 1.0;
+        }
+    }
+    return shadow/9.0;
+}
+
+void main()
+{
+    vec4 texelColor = TEXTURE(texture0, fragTexCoord)*colDiffuse*fragColor;
+    vec3 normal = normalize(fragNormal);
+    vec3 toLight = -normalize(lightDirection);
+    float attenuation = 1.0;
+
+    // Spot light, smooth falloff over the outer 10% of the cone
+    if (lightType == 1)
+    {
+        toLight = normalize(lightPosition - fragPosition);
+        float cosine = dot(-toLight, normalize(lightDirection));
+        attenuation = smoothstep(lightCutoff, mix(lightCutoff, 1.0, 0.1), cosine);
+    }
+
+    float diffuse = max(dot(normal, toLight), 0.0);
+    vec3 viewDirection = normalize(viewPos - fragPosition);
+    float specular = 0.0;
+    if (diffuse > 0.0) specular = pow(max(dot(normal, normalize(toLight + viewDirection)), 0.0), 32.0);
+
+    float lit = (1.0 - shadowAmount(normal, toLight))*attenuation;
+    vec3 color = texelColor.rgb*(ambient.rgb + lightColor.rgb*diffuse*lit) + lightColor.rgb*specular*lit*0.5;
+    OUTPUT = vec4(color, texelColor.a);
+}
+"#;
+
+/// Texture unit the shadow map is bound to: the cubemap material map unit, below the 8 units guaranteed by
+/// OpenGL ES 2.0 and WebGL.
+/// NOTE: DrawMesh() binds each material map to the unit of its index, materials drawn with the shadow map
+/// must not have a cubemap map
+const SHADOW_MAP_SLOT: i32 = MaterialMapIndex::Cubemap as i32;
+
+/// Light casting shadows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShadowLight {
+    /// Light shining along `direction`, shadows are cast in a square of `size` world units around `center`
+    Directional {
+        direction: Vector3,
+        center: Vector3,
+        size: f32,
+    },
+    /// Cone light at `position` shining along `direction`, `angle` is the cone half angle in degrees
+    Spot {
+        position: Vector3,
+        direction: Vector3,
+        angle: f32,
+    },
+}
+
+/// Shadow map rendered from the view of a light.
+///
+/// Draw the shadow casters between `begin()` and `end()`, then draw the scene with a shader set up by
+/// `apply()`, such as the built-in lighting shader from `ShadowMap::load_shader()`.
+/// Custom shaders receive the uniforms:
+/// - `mat4 lightVP`: world to light clip space matrix
+/// - `sampler2D shadowMap`: depth texture, bound to the unit of `MaterialMapIndex::Cubemap`
+/// - `float shadowMapResolution`, `float shadowBias`
+/// - `int lightType` (0: directional, 1: spot), `vec3 lightPosition`, `vec3 lightDirection`,
+///   `float lightCutoff` (cosine of the spot half angle), `vec4 lightColor`, `vec4 ambient`, `vec3 viewPos`
+#[derive(Debug)]
+pub struct ShadowMap {
+    framebuffer: Framebuffer,
+    light: ShadowLight,
+    /// Light color
+    pub color: Color,
+    /// Ambient color, applied to shadowed and lit areas
+    pub ambient: Color,
+    /// Depth bias against shadow acne, in normalized depth units
+    pub bias: f32,
+}
+
+impl ShadowMap {
+    /// Shadow map of `resolution`x`resolution` texels
+    pub fn new(resolution: i32, light: ShadowLight) -> Result<Self, String> {
+        let framebuffer = FramebufferBuilder::new(resolution, resolution)
+            .depth(DepthAttachment::Texture)
+            .build()?;
+        return Ok(Self {
+            framebuffer,
+            light,
+            color: Color::WHITE,
+            ambient: Color::new(40, 40, 40, 255),
+            bias: 0.0015,
+        });
+    }
+
+    /// Load the built-in lighting shader (Blinn-Phong with one light and 3x3 PCF shadows)
+    pub fn load_shader() -> Shader {
+        let vs_code = format!("{}{}", SHADER_VS_HEADER, SHADOW_VS);
+        let fs_code = format!("{}{}", SHADER_FS_HEADER, SHADOW_FS);
+        return Shader::from_memory(&vs_code, &fs_code);
+    }
+
+    pub fn light(&self) -> ShadowLight {
+        return self.light;
+    }
+
+    pub fn set_light(&mut self, light: ShadowLight) {
+        self.light = light;
+    }
+
+    pub fn resolution(&self) -> i32 {
+        return self.framebuffer.width();
+    }
+
+    pub fn depth_texture(&self) -> Texture2D {
+        return *self.framebuffer.depth().unwrap();
+    }
+
+    /// Camera looking from the light, used for the depth pass
+    pub fn camera(&self) -> Camera3D {
+        match self.light {
+            ShadowLight::Directional {
+                direction,
+                center,
+                size,
+            } => {
+                let direction = direction.normalize();
+                return Camera3D {
+                    // NOTE: Casters up to `size` units behind the center are kept in front of the near plane
+                    position: center - direction * size,
+                    target: center,
+                    up: up_vector(direction),
+                    fov_y: size,
+                    projection: CameraProjection::Orthographic,
+                };
+            }
+            ShadowLight::Spot {
+                position,
+                direction,
+                angle,
+            } => {
+                let direction = direction.normalize();
+                return Camera3D {
+                    position,
+                    target: position + direction,
+                    up: up_vector(direction),
+                    fov_y: angle * 2.0,
+                    projection: CameraProjection::Perspective,
+                };
+            }
+        }
+    }
+
+    /// World to light clip space matrix
+    pub fn light_matrix(&self) -> Matrix {
+        let camera = self.camera();
+        return camera.matrix().multiply(camera.projection_matrix(1.0));
+    }
+
+    /// Begin the depth pass from the light
+    pub fn begin(&self) {
+        self.framebuffer.begin();
+        clear_background(Color::WHITE);
+        self.camera().begin_mode();
+    }
+
+    /// End the depth pass
+    pub fn end(&self) {
+        self.camera().end_mode();
+        self.framebuffer.end();
+    }
+
+    /// Bind the shadow map and upload the light uniforms, call before drawing with the shader every frame
+    pub fn apply(&self, shader: &Shader, camera: &Camera3D) {
+        let (light_type, position, direction, cutoff) = match self.light {
+            ShadowLight::Directional { direction, center, .. } => (0, center, direction, -1.0),
+            ShadowLight::Spot {
+                position,
+                direction,
+                angle,
+            } => (1, position, direction, angle.to_radians().cos()),
+        };
+        let set_float =
+            |name: &str, value: f32| shader.set(shader.uniform_location(name), &value, ShaderUniformDataType::Float);
+        let set_vector =
+            |name: &str, value: Vector3| shader.set(shader.uniform_location(name), &value, ShaderUniformDataType::Vec3);
+
+        shader.set_matrix(shader.uniform_location("lightVP"), self.light_matrix());
+        shader.set(
+            shader.uniform_location("shadowMap"),
+            &SHADOW_MAP_SLOT,
+            ShaderUniformDataType::Int,
+        );
+        set_float("shadowMapResolution", self.resolution() as f32);
+        set_float("shadowBias", self.bias);
+        shader.set(
+            shader.uniform_location("lightType"),
+            &light_type,
+            ShaderUniformDataType::Int,
+        );
+        set_vector("lightPosition", position);
+        set_vector("lightDirection", direction.normalize());
+        set_float("lightCutoff", cutoff);
+        shader.set(
+            shader.uniform_location("lightColor"),
+            &color_normalize(self.color),
+            ShaderUniformDataType::Vec4,
+        );
+        shader.set(
+            shader.uniform_location("ambient"),
+            &color_normalize(self.ambient),
+            ShaderUniformDataType::Vec4,
+        );
+        set_vector("viewPos", camera.position);
+
+        rlgl::active_texture_slot(SHADOW_MAP_SLOT);
+        rlgl::enable_texture(self.depth_texture().0.id);
+        rlgl::active_texture_slot(0);
+    }
+}
+
+/// Up vector for a camera looking along `direction`, avoiding a degenerate view when looking straight up or down
+fn up_vector(direction: Vector3) -> Vector3 {
+    if direction.y.abs() > 0.99 {
+        return Vector3::new(0.0, 0.0, 1.0);
+    }
+    return Vector3::new(0.0, 1.0, 0.0);
+}
@@ -0,0 +1,578 @@
+use crate::rlgl::{self, FramebufferAttachTextureType, FramebufferAttachType};
+use crate::{
+    Image, Material, MaterialMapIndex, Matrix, PixelFormat, SHADER_FS_HEADER, SHADER_VS_HEADER, Shader,
+    ShaderLocationIndex, ShaderUniformDataType, Texture, Texture2D, TextureCubemap, TextureFilter, TextureWrap,
+    Vector3, get_pixel_data_size,
+};
+
+const SKYBOX_VS: &str = r#"
+ATTRIBUTE vec3 vertexPosition;
+
+uniform mat4 matProjection;
+uniform mat4 matView;
+
+VARYING vec3 fragPosition;
+
+void main()
+{
+    fragPosition = vertexPosition;
+
+    // NOTE: w = 0.0 drops the view translation, the skybox follows the camera
+    vec4 position = matView*vec4(vertexPosition, 0.0);
+    gl_Position = matProjection*vec4(position.xyz, 1.0);
+}
+"#;
+
+const SKYBOX_FS: &str = r#"
+VARYING vec3 fragPosition;
+
+uniform samplerCube environmentMap;
+uniform int toneMapping;
+
+void main()
+{
+    vec3 color = TEXTURE_CUBE(environmentMap, fragPosition).rgb;
+    if (toneMapping == 1)
+    {
+        color = color/(color + vec3(1.0));
+        color = pow(color, vec3(1.0/2.2));
+    }
+    OUTPUT = vec4(color, 1.0);
+}
+"#;
+
+/// Unit cube seen from its center, used to render the cubemap faces
+const CUBE_VS: &str = r#"
+ATTRIBUTE vec3 vertexPosition;
+
+uniform mat4 matProjection;
+uniform mat4 matView;
+
+VARYING vec3 fragPosition;
+
+void main()
+{
+    fragPosition = vertexPosition;
+    gl_Position = matProjection*matView*vec4(vertexPosition, 1.0);
+}
+"#;
+
+const EQUIRECTANGULAR_FS: &str = r#"
+VARYING vec3 fragPosition;
+
+uniform sampler2D equirectangularMap;
+
+void main()
+{
+    vec3 direction = normalize(fragPosition);
+    vec2 uv = vec2(atan(direction.z, direction.x)*0.1591549, asin(direction.y)*0.3183099) + 0.5;
+
+    // NOTE: Images are loaded top row first, the panorama top is at v = 0
+    OUTPUT = vec4(TEXTURE(equirectangularMap, vec2(uv.x, 1.0 - uv.y)).rgb, 1.0);
+}
+"#;
+
+const IRRADIANCE_FS: &str = r#"
+VARYING vec3 fragPosition;
+
+uniform samplerCube environmentMap;
+
+const float PI = 3.14159265358979;
+
+void main()
+{
+    vec3 normal = normalize(fragPosition);
+    vec3 up = (abs(normal.y) < 0.999)? vec3(0.0, 1.0, 0.0) : vec3(0.0, 0.0, 1.0);
+    vec3 right = normalize(cross(up, normal));
+    up = cross(normal, right);
+
+    // Cosine weighted convolution over the hemisphere
+    vec3 irradiance = vec3(0.0);
+    float samples = 0.0;
+    for (float phi = 0.0; phi < 2.0*PI; phi += 0.025)
+    {
+        for (float theta = 0.0; theta < 0.5*PI; theta += 0.025)
+        {
+            vec3 tangentSample = vec3(sin(theta)*cos(phi), sin(theta)*sin(phi), cos(theta));
+            vec3 direction = tangentSample.x*right + tangentSample.y*up + tangentSample.z*normal;
+            irradiance += TEXTURE_CUBE(environmentMap, direction).rgb*cos(theta)*sin(theta);
+            samples += 1.0;
+        }
+    }
+
+    OUTPUT = vec4(PI*irradiance/samples, 1.0);
+}
+"#;
+
+/// GGX importance sampling shared by the prefilter and BRDF shaders
+const GGX_SAMPLING: &str = r#"
+const float PI = 3.14159265358979;
+const int SAMPLE_COUNT = 1024;
+
+// Radical inverse in base 2, without the bit operations missing from GLSL 100
+float vanDerCorput(int index)
+{
+    float result = 0.0;
+    float denominator = 1.0;
+    float value = float(index);
+    for (int i = 0; i < 16; i++)
+    {
+        if (value <= 0.0) break;
+        denominator *= 2.0;
+        result += mod(value, 2.0)/denominator;
+        value = floor(value/2.0);
+    }
+    return result;
+}
+
+vec3 importanceSampleGGX(int index, vec3 normal, float roughness)
+{
+    vec2 xi = vec2(float(index)/float(SAMPLE_COUNT), vanDerCorput(index));
+    float a = roughness*roughness;
+    float phi = 2.0*PI*xi.x;
+    float cosTheta = sqrt((1.0 - xi.y)/(1.0 + (a*a - 1.0)*xi.y));
+    float sinTheta = sqrt(1.0 - cosTheta*cosTheta);
+
+    vec3 up = (abs(normal.z) < 0.999)? vec3(0.0, 0.0, 1.0) : vec3(1.0, 0.0, 0.0);
+    vec3 tangent = normalize(cross(up, normal));
+    vec3 bitangent = cross(normal, tangent);
+    return normalize(tangent*cos(phi)*sinTheta + bitangent*sin(phi)*sinTheta + normal*cosTheta);
+}
+"#;
+
+const PREFILTER_FS: &str = r#"
+VARYING vec3 fragPosition;
+
+uniform samplerCube environmentMap;
+uniform float roughness;
+
+void main()
+{
+    // NOTE: The view direction is assumed equal to the normal and the reflection direction
+    vec3 normal = normalize(fragPosition);
+    vec3 color = vec3(0.0);
+    float totalWeight = 0.0;
+
+    for (int i = 0; i < SAMPLE_COUNT; i++)
+    {
+        vec3 halfway = importanceSampleGGX(i, normal, roughness);
+        vec3 toLight = normalize(2.0*dot(normal, halfway)*halfway - normal);
+        float NdotL = max(dot(normal, toLight), 0.0);
+        if (NdotL > 0.0)
+        {
+            color += TEXTURE_CUBE(environmentMap, toLight).rgb*NdotL;
+            totalWeight += NdotL;
+        }
+    }
+
+    OUTPUT = vec4(color/totalWeight, 1.0);
+}
+"#;
+
+const QUAD_VS: &str = r#"
+ATTRIBUTE vec3 vertexPosition;
+ATTRIBUTE vec2 vertexTexCoord;
+
+VARYING vec2 fragTexCoord;
+
+void main()
+{
+    fragTexCoord = vertexTexCoord;
+    gl_Position = vec4(vertexPosition, 1.0);
+}
+"#;
+
+const BRDF_FS: &str = r#"
+VARYING vec2 fragTexCoord;
+
+float geometrySchlickGGX(float NdotV, float roughness)
+{
+    float k = roughness*roughness/2.0;
+    return NdotV/(NdotV*(1.0 - k) + k);
+}
+
+// Scale and bias to F0 of the specular response, for a view angle (x) and a roughness (y)
+void main()
+{
+    float NdotV = max(fragTexCoord.x, 0.001);
+    float roughness = fragTexCoord.y;
+    vec3 view = vec3(sqrt(1.0 - NdotV*NdotV), 0.0, NdotV);
+    vec3 normal = vec3(0.0, 0.0, 1.0);
+    float scale = 0.0;
+    float bias = 0.0;
+
+    for (int i = 0; i < SAMPLE_COUNT; i++)
+    {
+        vec3 halfway = importanceSampleGGX(i, normal, roughness);
+        vec3 toLight = normalize(2.0*dot(view, halfway)*halfway - view);
+        float NdotL = max(toLight.z, 0.0);
+        float NdotH = max(halfway.z, 0.0);
+        float VdotH = max(dot(view, halfway), 0.0);
+        if (NdotL > 0.0)
+        {
+            float geometry = geometrySchlickGGX(NdotV, roughness)*geometrySchlickGGX(NdotL, roughness);
+            float visibility = geometry*VdotH/(NdotH*NdotV);
+            float fresnel = pow(1.0 - VdotH, 5.0);
+            scale += (1.0 - fresnel)*visibility;
+            bias += fresnel*visibility;
+        }
+    }
+
+    OUTPUT = vec4(scale/float(SAMPLE_COUNT), bias/float(SAMPLE_COUNT), 0.0, 1.0);
+}
+"#;
+
+/// Format of the generated cubemaps and BRDF texture (half floats keep HDR values)
+const HDR_FORMAT: PixelFormat = PixelFormat::UncompressedR16g16b16a16;
+
+/// Cubemap drawn around the camera
+#[derive(Debug)]
+pub struct Skybox {
+    cubemap: TextureCubemap,
+    shader: Shader,
+    /// Tone map and gamma correct the cubemap colors, for HDR cubemaps
+    pub tone_mapping: bool,
+}
+
+impl Skybox {
+    /// Skybox drawing a cubemap, the cubemap stays owned by the caller
+    pub fn new(cubemap: TextureCubemap) -> Self {
+        let shader = load_shader(SKYBOX_VS, SKYBOX_FS);
+        return Self {
+            cubemap,
+            shader,
+            tone_mapping: false,
+        };
+    }
+
+    pub fn cubemap(&self) -> TextureCubemap {
+        return self.cubemap;
+    }
+
+    pub fn set_cubemap(&mut self, cubemap: TextureCubemap) {
+        self.cubemap = cubemap;
+    }
+
+    /// Draw the skybox, call first between `begin_mode_3d()` and `end_mode_3d()`.
+    /// NOTE: The depth buffer is not written, everything drawn afterwards is in front of it
+    pub fn draw(&self) {
+        rlgl::draw_render_batch_active();
+
+        rlgl::enable_shader(self.shader.id);
+        rlgl::set_uniform_matrix(
+            self.location(ShaderLocationIndex::MatrixProjection),
+            rlgl::get_matrix_projection(),
+        );
+        rlgl::set_uniform_matrix(
+            self.location(ShaderLocationIndex::MatrixView),
+            rlgl::get_matrix_modelview(),
+        );
+        rlgl::set_uniform(
+            self.shader.uniform_location("toneMapping").into(),
+            &[self.tone_mapping as i32],
+            ShaderUniformDataType::Int,
+        );
+        rlgl::set_uniform(
+            self.shader.uniform_location("environmentMap").into(),
+            &[0],
+            ShaderUniformDataType::Int,
+        );
+        rlgl::active_texture_slot(0);
+        rlgl::enable_texture_cubemap(self.cubemap.0.id);
+
+        rlgl::disable_backface_culling();
+        rlgl::disable_depth_mask();
+        rlgl::load_draw_cube();
+        rlgl::enable_depth_mask();
+        rlgl::enable_backface_culling();
+
+        rlgl::disable_texture_cubemap();
+        rlgl::disable_shader();
+    }
+
+    fn location(&self, index: ShaderLocationIndex) -> i32 {
+        return self.shader.location_index(index).into();
+    }
+}
+
+impl TextureCubemap {
+    /// Convert an equirectangular panorama (e.g. an HDR image) into a cubemap with faces of `size` pixels
+    pub fn from_equirectangular(image: &Image, size: i32) -> Result<TextureCubemap, String> {
+        let panorama = Texture2D::from_image(*image);
+        if !panorama.is_valid() {
+            return Err("cubemap: could not load the panorama texture".to_string());
+        }
+        panorama.set_filter(TextureFilter::Bilinear);
+
+        let cubemap = match create_cubemap(size, 1) {
+            Ok(cubemap) => cubemap,
+            Err(error) => {
+                panorama.unload();
+                return Err(error);
+            }
+        };
+        let shader = load_shader(CUBE_VS, EQUIRECTANGULAR_FS);
+        let result = render_cubemap(&shader, cubemap, 0, (panorama.0.id, false), || {});
+
+        panorama.unload();
+        if let Err(error) = result {
+            cubemap.unload();
+            return Err(error);
+        }
+        return Ok(cubemap);
+    }
+}
+
+/// Image-based lighting textures precomputed from an environment cubemap, used by the PBR lit shader
+#[derive(Debug, Clone, Copy)]
+pub struct ImageBasedLighting {
+    /// Diffuse irradiance
+    pub irradiance: TextureCubemap,
+    /// Specular reflections prefiltered for increasing roughness in each mipmap level
+    pub prefilter: TextureCubemap,
+    /// Split-sum BRDF integration lookup table
+    pub brdf: Texture2D,
+}
+
+impl ImageBasedLighting {
+    pub const IRRADIANCE_SIZE: i32 = 32;
+    pub const PREFILTER_SIZE: i32 = 128;
+    pub const PREFILTER_MIPMAPS: i32 = 5;
+    pub const BRDF_SIZE: i32 = 512;
+
+    /// Precompute the lighting textures of an environment cubemap.
+    /// NOTE: Requires rendering to half float textures, not supported on web (WebGL 1)
+    pub fn generate(environment: TextureCubemap) -> Result<Self, String> {
+        let irradiance = create_cubemap(Self::IRRADIANCE_SIZE, 1)?;
+        let source = (environment.0.id, true);
+        let shader = load_shader(CUBE_VS, IRRADIANCE_FS);
+        if let Err(error) = render_cubemap(&shader, irradiance, 0, source, || {}) {
+            irradiance.unload();
+            return Err(error);
+        }
+
+        let prefilter = match Self::prefilter(source) {
+            Ok(prefilter) => prefilter,
+            Err(error) => {
+                irradiance.unload();
+                return Err(error);
+            }
+        };
+
+        let brdf = match Self::brdf() {
+            Ok(brdf) => brdf,
+            Err(error) => {
+                irradiance.unload();
+                prefilter.unload();
+                return Err(error);
+            }
+        };
+        return Ok(Self {
+            irradiance,
+            prefilter,
+            brdf,
+        });
+    }
+
+    fn prefilter(source: (u32, bool)) -> Result<TextureCubemap, String> {
+        let prefilter = create_cubemap(Self::PREFILTER_SIZE, Self::PREFILTER_MIPMAPS)?;
+        let shader = load_shader(CUBE_VS, &format!("{}{}", GGX_SAMPLING, PREFILTER_FS));
+        let roughness_location = shader.uniform_location("roughness").into();
+
+        for mip in 0..Self::PREFILTER_MIPMAPS {
+            let roughness = mip as f32 / (Self::PREFILTER_MIPMAPS - 1) as f32;
+            let set_roughness = || rlgl::set_uniform(roughness_location, &[roughness], ShaderUniformDataType::Float);
+            if let Err(error) = render_cubemap(&shader, prefilter, mip, source, set_roughness) {
+                prefilter.unload();
+                return Err(error);
+            }
+        }
+        return Ok(prefilter);
+    }
+
+    fn brdf() -> Result<Texture2D, String> {
+        let size = Self::BRDF_SIZE;
+        let id = rlgl::load_texture(None, size, size, HDR_FORMAT, 1);
+        if id == 0 {
+            return Err("ibl: could not create the BRDF texture".to_string());
+        }
+        let brdf = Texture2D(Texture {
+            id,
+            width: size,
+            height: size,
+            mipmaps: 1,
+            format: HDR_FORMAT,
+        });
+        brdf.set_filter(TextureFilter::Bilinear);
+        brdf.set_wrap(TextureWrap::Clamp);
+
+        let framebuffer = rlgl::load_framebuffer();
+        rlgl::framebuffer_attach(
+            framebuffer,
+            id,
+            FramebufferAttachType::ColorChannel0,
+            FramebufferAttachTextureType::Texture2D,
+            0,
+        );
+        if framebuffer == 0 || !rlgl::framebuffer_complete(framebuffer) {
+            rlgl::unload_framebuffer(framebuffer);
+            brdf.unload();
+            return Err("ibl: cannot render to the BRDF texture".to_string());
+        }
+
+        let shader = load_shader(QUAD_VS, &format!("{}{}", GGX_SAMPLING, BRDF_FS));
+        rlgl::draw_render_batch_active();
+        rlgl::enable_shader(shader.id);
+        rlgl::enable_framebuffer(framebuffer);
+        rlgl::viewport(0, 0, size, size);
+        rlgl::clear_screen_buffers();
+        rlgl::load_draw_quad();
+        rlgl::disable_shader();
+        rlgl::disable_framebuffer();
+        rlgl::unload_framebuffer(framebuffer);
+        rlgl::viewport(0, 0, rlgl::get_framebuffer_width(), rlgl::get_framebuffer_height());
+        return Ok(brdf);
+    }
+
+    /// Attach the textures to the `Irradiance`, `Prefilter` and `Brdf` maps of a material.
+    /// The textures stay owned by `ImageBasedLighting`, the `Prefilter` map value is set to its last mipmap level.
+    pub fn apply(&self, material: &mut Material) {
+        material.set_texture(MaterialMapIndex::Irradiance, Texture2D(self.irradiance.0));
+        material.set_texture(MaterialMapIndex::Prefilter, Texture2D(self.prefilter.0));
+        material.set_texture(MaterialMapIndex::Brdf, self.brdf);
+        material.set_value(MaterialMapIndex::Prefilter, (self.prefilter.mipmaps() - 1) as f32);
+    }
+
+    pub fn unload(&self) {
+        self.irradiance.unload();
+        self.prefilter.unload();
+        self.brdf.unload();
+    }
+}
+
+fn load_shader(vs_body: &str, fs_body: &str) -> Shader {
+    return Shader::from_memory(
+        &format!("{}{}", SHADER_VS_HEADER, vs_body),
+        &format!("{}{}", SHADER_FS_HEADER, fs_body),
+    );
+}
+
+/// Empty HDR cubemap with `mipmaps` levels
+fn create_cubemap(size: i32, mipmaps: i32) -> Result<TextureCubemap, String> {
+    // NOTE: rlLoadTextureCubemap() does not allocate 16/32-bit float faces without data, zeroed faces are uploaded
+    let data_size: i32 = (0..mipmaps)
+        .map(|mip| 6 * get_pixel_data_size((size >> mip).max(1), (size >> mip).max(1), HDR_FORMAT))
+        .sum();
+    let zeros = vec![0u8; data_size as usize];
+    let id = rlgl::load_texture_cubemap(Some(&zeros), size, HDR_FORMAT, mipmaps);
+    if id == 0 {
+        return Err(format!("cubemap: could not create a {}x{} cubemap", size, size));
+    }
+    return Ok(TextureCubemap(Texture {
+        id,
+        width: size,
+        height: size,
+        mipmaps,
+        format: HDR_FORMAT,
+    }));
+}
+
+/// Render the faces of a cubemap mipmap level with a shader drawing a unit cube seen from its center.
+/// `source` is the texture bound to the first sampler (id, is a cubemap), `setup` sets extra uniforms.
+fn render_cubemap(
+    shader: &Shader,
+    cubemap: TextureCubemap,
+    mip: i32,
+    source: (u32, bool),
+    setup: impl Fn(),
+) -> Result<(), String> {
+    // Face views in GL_TEXTURE_CUBE_MAP_POSITIVE_X + face order
+    let origin = Vector3::new(0.0, 0.0, 0.0);
+    let faces = [
+        (
+            FramebufferAttachTextureType::CubemapPositiveX,
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+        ),
+        (
+            FramebufferAttachTextureType::CubemapNegativeX,
+            Vector3::new(-1.0, 0.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+        ),
+        (
+            FramebufferAttachTextureType::CubemapPositiveY,
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ),
+        (
+            FramebufferAttachTextureType::CubemapNegativeY,
+            Vector3::new(0.0, -1.0, 0.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        ),
+        (
+            FramebufferAttachTextureType::CubemapPositiveZ,
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, -1.0, 0.0),
+        ),
+        (
+            FramebufferAttachTextureType::CubemapNegativeZ,
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.0, -1.0, 0.0),
+        ),
+    ];
+
+    let framebuffer = rlgl::load_framebuffer();
+    if framebuffer == 0 {
+        return Err("cubemap: render textures are not supported".to_string());
+    }
+
+    rlgl::draw_render_batch_active();
+    rlgl::enable_shader(shader.id);
+    let projection = Matrix::perspective(90.0_f32.to_radians(), 1.0, 0.01, 1000.0);
+    rlgl::set_uniform_matrix(
+        shader.location_index(ShaderLocationIndex::MatrixProjection).into(),
+        projection,
+    );
+    setup();
+
+    rlgl::active_texture_slot(0);
+    match source {
+        (id, true) => rlgl::enable_texture_cubemap(id),
+        (id, false) => rlgl::enable_texture(id),
+    }
+    rlgl::disable_backface_culling();
+    let size = (cubemap.size() >> mip).max(1);
+    rlgl::viewport(0, 0, size, size);
+
+    let mut result = Ok(());
+    for (face, target, up) in faces {
+        rlgl::framebuffer_attach(
+            framebuffer,
+            cubemap.0.id,
+            FramebufferAttachType::ColorChannel0,
+            face,
+            mip,
+        );
+        if !rlgl::framebuffer_complete(framebuffer) {
+            result = Err(format!("cubemap: cannot render to {:?} cubemaps", HDR_FORMAT));
+            break;
+        }
+
+        let view = Matrix::look_at(origin, target, up);
+        rlgl::set_uniform_matrix(shader.location_index(ShaderLocationIndex::MatrixView).into(), view);
+        rlgl::enable_framebuffer(framebuffer);
+        rlgl::clear_screen_buffers();
+        rlgl::load_draw_cube();
+    }
+
+    rlgl::enable_backface_culling();
+    match source {
+        (_, true) => rlgl::disable_texture_cubemap(),
+        (_, false) => rlgl::disable_texture(),
+    }
+    rlgl::disable_shader();
+    rlgl::disable_framebuffer();
+    rlgl::unload_framebuffer(framebuffer);
+    rlgl::viewport(0, 0, rlgl::get_framebuffer_width(), rlgl::get_framebuffer_height());
+    return result;
+}
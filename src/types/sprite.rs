@@ -0,0 +1,701 @@
+use crate::{AtlasLayout, Color, NPatchInfo, NPatchLayout, Rectangle, Texture2D, Vector2};
+use std::collections::BTreeMap;
+
+/// Frame of a sprite sheet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpriteFrame {
+    /// Area of the texture holding the frame pixels
+    pub source: Rectangle,
+    /// Pixels are stored rotated 90 degrees clockwise, `source` holds the rotated size
+    pub rotated: bool,
+    /// Position of the stored pixels in the untrimmed frame
+    pub offset: Vector2,
+    /// Untrimmed frame size
+    pub size: Vector2,
+    /// Display time in seconds, used by animations built from tags
+    pub duration: f32,
+}
+
+impl SpriteFrame {
+    /// Untrimmed frame covering `source`
+    pub fn new(source: Rectangle) -> Self {
+        return Self {
+            source,
+            rotated: false,
+            offset: Vector2::zero(),
+            size: Vector2::new(source.width.abs(), source.height.abs()),
+            duration: 0.1,
+        };
+    }
+
+    /// Size of the stored pixels once drawn upright
+    pub fn trimmed_size(&self) -> Vector2 {
+        let (width, height) = (self.source.width.abs(), self.source.height.abs());
+        if self.rotated {
+            return Vector2::new(height, width);
+        }
+        return Vector2::new(width, height);
+    }
+}
+
+/// Texture split into frames, from a regular grid or a packed atlas
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpriteSheet {
+    pub texture: Texture2D,
+    frames: Vec<SpriteFrame>,
+    names: BTreeMap<String, usize>,
+    tags: Vec<SpriteTag>,
+    slices: Vec<SpriteSlice>,
+}
+
+impl SpriteSheet {
+    /// Sheet with the given frames, frames can be named afterwards with `set_name()`
+    pub fn new(texture: Texture2D, frames: Vec<SpriteFrame>) -> Self {
+        return Self {
+            texture,
+            frames,
+            names: BTreeMap::new(),
+            tags: Vec::new(),
+            slices: Vec::new(),
+        };
+    }
+
+    /// Frames of `frame_width`x`frame_height` read row by row, skipping `margin` pixels around the texture
+    /// and `spacing` pixels between frames
+    pub fn from_grid(texture: Texture2D, frame_width: i32, frame_height: i32, margin: i32, spacing: i32) -> Self {
+        let mut frames = Vec::new();
+        if frame_width > 0 && frame_height > 0 {
+            let columns = (texture.width() - margin * 2 + spacing) / (frame_width + spacing);
+            let rows = (texture.height() - margin * 2 + spacing) / (frame_height + spacing);
+            for row in 0..rows {
+                for column in 0..columns {
+                    let x = margin + column * (frame_width + spacing);
+                    let y = margin + row * (frame_height + spacing);
+                    let source = Rectangle::new(x as f32, y as f32, frame_width as f32, frame_height as f32);
+                    frames.push(SpriteFrame::new(source));
+                }
+            }
+        }
+        return Self::new(texture, frames);
+    }
+
+    /// One frame per atlas region, in name order and named after the region
+    pub fn from_atlas(texture: Texture2D, layout: &AtlasLayout) -> Self {
+        let mut sheet = Self::new(texture, Vec::new());
+        for (name, region) in layout.regions() {
+            let mut frame = SpriteFrame::new(region.rect);
+            frame.rotated = region.rotated;
+            frame.size = frame.trimmed_size();
+            sheet.names.insert(name.to_string(), sheet.frames.len());
+            sheet.frames.push(frame);
+        }
+        return sheet;
+    }
+
+    pub fn frames(&self) -> &[SpriteFrame] {
+        return &self.frames;
+    }
+
+    pub fn frame(&self, index: usize) -> Option<&SpriteFrame> {
+        return self.frames.get(index);
+    }
+
+    pub fn frame_count(&self) -> usize {
+        return self.frames.len();
+    }
+
+    /// Append a frame, returns its index
+    pub fn push(&mut self, frame: SpriteFrame) -> usize {
+        self.frames.push(frame);
+        return self.frames.len() - 1;
+    }
+
+    /// Index of the frame named `name`
+    pub fn find(&self, name: &str) -> Option<usize> {
+        return self.names.get(name).copied();
+    }
+
+    pub fn set_name(&mut self, name: &str, index: usize) {
+        self.names.insert(name.to_string(), index);
+    }
+
+    // Tags
+
+    pub fn tags(&self) -> &[SpriteTag] {
+        return &self.tags;
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&SpriteTag> {
+        return self.tags.iter().find(|tag| tag.name == name);
+    }
+
+    pub fn add_tag(&mut self, tag: SpriteTag) {
+        self.tags.push(tag);
+    }
+
+    /// Animation playing the frames of the tag named `name` with their durations.
+    /// Tags with a repeat count play once through every repetition.
+    pub fn animation(&self, name: &str) -> Option<SpriteAnimation> {
+        let tag = self.tag(name)?;
+        if tag.from > tag.to || tag.to >= self.frames.len() {
+            return None;
+        }
+
+        let forward: Vec<usize> = (tag.from..=tag.to).collect();
+        let backward: Vec<usize> = forward.iter().rev().copied().collect();
+        let (first, second) = match tag.direction {
+            SpriteTagDirection::Forward | SpriteTagDirection::PingPong => (forward, backward),
+            SpriteTagDirection::Reverse | SpriteTagDirection::PingPongReverse => (backward, forward),
+        };
+        let ping_pong = matches!(tag.direction, SpriteTagDirection::PingPong | SpriteTagDirection::PingPongReverse);
+
+        let (sequence, mode) = match (tag.repeat, ping_pong) {
+            (None, false) => (first, SpriteAnimationMode::Loop),
+            (None, true) => (first, SpriteAnimationMode::PingPong),
+            (Some(count), false) => (first.repeat(count.max(1) as usize), SpriteAnimationMode::Once),
+            // NOTE: Every pass counts as a repetition and the turning frame is not shown twice
+            (Some(count), true) => {
+                let mut sequence = first.clone();
+                for pass in 1..count.max(1) {
+                    let frames = if pass % 2 == 1 { &second } else { &first };
+                    sequence.extend(frames.iter().skip(1));
+                }
+                (sequence, SpriteAnimationMode::Once)
+            }
+        };
+        let frames = sequence.into_iter().map(|frame| (frame, self.frames[frame].duration)).collect();
+        return Some(SpriteAnimation::new(frames, mode));
+    }
+
+    // Slices
+
+    pub fn slices(&self) -> &[SpriteSlice] {
+        return &self.slices;
+    }
+
+    pub fn slice(&self, name: &str) -> Option<&SpriteSlice> {
+        return self.slices.iter().find(|slice| slice.name == name);
+    }
+
+    pub fn add_slice(&mut self, slice: SpriteSlice) {
+        self.slices.push(slice);
+    }
+
+    /// Nine-patch of the slice named `name` on `frame`, for `Texture2D::draw_n_patch()`.
+    /// Slices without a center stretch as a whole, slices on rotated frames are not supported.
+    pub fn n_patch(&self, name: &str, frame: usize) -> Option<NPatchInfo> {
+        let sprite_frame = self.frame(frame)?;
+        let key = self.slice(name)?.key(frame)?;
+        if sprite_frame.rotated {
+            return None;
+        }
+
+        // NOTE: Slice bounds are in untrimmed frame space
+        let bounds = key.bounds;
+        let source = Rectangle::new(
+            sprite_frame.source.x + bounds.x - sprite_frame.offset.x,
+            sprite_frame.source.y + bounds.y - sprite_frame.offset.y,
+            bounds.width,
+            bounds.height,
+        );
+        let center = key.center.unwrap_or(Rectangle::new(0.0, 0.0, bounds.width, bounds.height));
+        return Some(NPatchInfo {
+            source,
+            left: center.x as i32,
+            top: center.y as i32,
+            right: (bounds.width - center.x - center.width) as i32,
+            bottom: (bounds.height - center.y - center.height) as i32,
+            layout: NPatchLayout::NinePatch as i32,
+        });
+    }
+}
+
+/// Playback direction of a sprite tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpriteTagDirection {
+    #[default]
+    Forward,
+    Reverse,
+    PingPong,
+    /// Ping-pong starting from the last frame
+    PingPongReverse,
+}
+
+/// Named range of frames, such as an Aseprite tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpriteTag {
+    pub name: String,
+    /// First frame index
+    pub from: usize,
+    /// Last frame index, inclusive
+    pub to: usize,
+    pub direction: SpriteTagDirection,
+    /// Number of passes to play before stopping (loops forever if not set)
+    pub repeat: Option<u32>,
+}
+
+/// Shape of a slice from `frame` onward
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpriteSliceKey {
+    pub frame: usize,
+    /// Slice area in untrimmed frame space
+    pub bounds: Rectangle,
+    /// Nine-patch center, relative to `bounds`
+    pub center: Option<Rectangle>,
+    /// Pivot point, relative to `bounds`
+    pub pivot: Option<Vector2>,
+}
+
+/// Named area of a sprite, such as an Aseprite slice
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpriteSlice {
+    pub name: String,
+    /// Keys sorted by frame
+    pub keys: Vec<SpriteSliceKey>,
+}
+
+impl SpriteSlice {
+    /// Key in effect on `frame`, the first key applies to the frames before it
+    pub fn key(&self, frame: usize) -> Option<&SpriteSliceKey> {
+        return self.keys.iter().rev().find(|key| key.frame <= frame).or(self.keys.first());
+    }
+}
+
+/// Playback mode of a sprite animation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpriteAnimationMode {
+    /// Restart from the first frame after the last one
+    #[default]
+    Loop,
+    /// Play forward then backward
+    PingPong,
+    /// Stop on the last frame
+    Once,
+}
+
+/// Change reported to sprite animation callbacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpriteAnimationEvent {
+    /// A new frame is shown, holds its position in the animation (not the sheet frame index)
+    FrameChanged(usize),
+    /// A looping or ping-pong animation started a new cycle
+    Looped,
+    /// A `Once` animation reached its last frame
+    Finished,
+}
+
+type SpriteAnimationCallback = Box<dyn FnMut(SpriteAnimationEvent)>;
+
+/// Sequence of sprite sheet frames with per-frame durations
+pub struct SpriteAnimation {
+    /// Sheet frame index and duration in seconds of every frame
+    frames: Vec<(usize, f32)>,
+    pub mode: SpriteAnimationMode,
+    /// Playback rate multiplier
+    pub speed: f32,
+    position: usize,
+    time: f32,
+    reverse: bool,
+    playing: bool,
+    finished: bool,
+    callbacks: Vec<SpriteAnimationCallback>,
+}
+
+impl std::fmt::Debug for SpriteAnimation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f
+            .debug_struct("SpriteAnimation")
+            .field("frames", &self.frames)
+            .field("mode", &self.mode)
+            .field("speed", &self.speed)
+            .field("position", &self.position)
+            .field("time", &self.time)
+            .field("playing", &self.playing)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive();
+    }
+}
+
+impl SpriteAnimation {
+    /// Animation from sheet frame indices and durations in seconds, playing from the first frame
+    pub fn new(frames: Vec<(usize, f32)>, mode: SpriteAnimationMode) -> Self {
+        return Self {
+            frames,
+            mode,
+            speed: 1.0,
+            position: 0,
+            time: 0.0,
+            reverse: false,
+            playing: true,
+            finished: false,
+            callbacks: Vec::new(),
+        };
+    }
+
+    /// Every frame shown for `1/fps` seconds
+    pub fn from_frames(frames: &[usize], fps: f32, mode: SpriteAnimationMode) -> Self {
+        let duration = 1.0 / fps.max(f32::EPSILON);
+        return Self::new(frames.iter().map(|&frame| (frame, duration)).collect(), mode);
+    }
+
+    /// Sheet frames `first` to `last` inclusive, every frame shown for `1/fps` seconds
+    pub fn from_range(first: usize, last: usize, fps: f32, mode: SpriteAnimationMode) -> Self {
+        let frames: Vec<usize> = (first..=last).collect();
+        return Self::from_frames(&frames, fps, mode);
+    }
+
+    pub fn frames(&self) -> &[(usize, f32)] {
+        return &self.frames;
+    }
+
+    /// Set the duration in seconds of the frame at `position`
+    pub fn set_frame_duration(&mut self, position: usize, duration: f32) {
+        if let Some(frame) = self.frames.get_mut(position) {
+            frame.1 = duration;
+        }
+    }
+
+    /// Total duration of one pass through the frames in seconds
+    pub fn duration(&self) -> f32 {
+        return self.frames.iter().map(|(_, duration)| duration).sum();
+    }
+
+    /// Sheet frame index currently shown
+    pub fn current_frame(&self) -> usize {
+        return self.frames.get(self.position).map(|(frame, _)| *frame).unwrap_or(0);
+    }
+
+    /// Position of the current frame in the animation
+    pub fn position(&self) -> usize {
+        return self.position;
+    }
+
+    /// Jump to the frame at `position`
+    pub fn set_position(&mut self, position: usize) {
+        self.position = position.min(self.frames.len().saturating_sub(1));
+        self.time = 0.0;
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Rewind to the first frame and play
+    pub fn restart(&mut self) {
+        self.position = 0;
+        self.time = 0.0;
+        self.reverse = false;
+        self.finished = false;
+        self.playing = true;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        return self.playing;
+    }
+
+    /// `Once` animation reached its last frame
+    pub fn is_finished(&self) -> bool {
+        return self.finished;
+    }
+
+    /// Call `callback` on every frame change, loop and end of the animation
+    pub fn on_event(&mut self, callback: impl FnMut(SpriteAnimationEvent) + 'static) {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    /// Advance playback by `delta_time` seconds
+    pub fn update(&mut self, delta_time: f32) {
+        if !self.playing || self.finished || self.frames.is_empty() {
+            return;
+        }
+
+        let mut events = Vec::new();
+        self.time += delta_time * self.speed;
+        loop {
+            // NOTE: Zero durations are clamped so a large time step cannot loop forever
+            let duration = self.frames[self.position].1.max(0.001);
+            if self.time < duration {
+                break;
+            }
+            self.time -= duration;
+
+            let last = self.frames.len() - 1;
+            match self.mode {
+                SpriteAnimationMode::Loop => {
+                    if self.position == last {
+                        self.position = 0;
+                        events.push(SpriteAnimationEvent::Looped);
+                    } else {
+                        self.position += 1;
+                    }
+                }
+                SpriteAnimationMode::PingPong => {
+                    if last == 0 {
+                        events.push(SpriteAnimationEvent::Looped);
+                        continue;
+                    }
+                    if self.reverse && self.position == 0 {
+                        self.reverse = false;
+                        events.push(SpriteAnimationEvent::Looped);
+                    } else if !self.reverse && self.position == last {
+                        self.reverse = true;
+                    }
+                    if self.reverse {
+                        self.position -= 1;
+                    } else {
+                        self.position += 1;
+                    }
+                }
+                SpriteAnimationMode::Once => {
+                    if self.position == last {
+                        self.time = 0.0;
+                        self.finished = true;
+                        self.playing = false;
+                        events.push(SpriteAnimationEvent::Finished);
+                        break;
+                    }
+                    self.position += 1;
+                }
+            }
+            events.push(SpriteAnimationEvent::FrameChanged(self.position));
+        }
+
+        for event in events {
+            for callback in &mut self.callbacks {
+                callback(event);
+            }
+        }
+    }
+}
+
+/// Placement of a sprite sheet frame on screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sprite {
+    pub position: Vector2,
+    /// Pivot as a fraction of the untrimmed frame size, (0.5, 0.5) is the center
+    pub origin: Vector2,
+    /// Rotation around the origin in degrees
+    pub rotation: f32,
+    pub scale: Vector2,
+    pub flip_x: bool,
+    pub flip_y: bool,
+    pub tint: Color,
+}
+
+impl Default for Sprite {
+    fn default() -> Self {
+        return Self::new(Vector2::zero());
+    }
+}
+
+impl Sprite {
+    pub fn new(position: Vector2) -> Self {
+        return Self {
+            position,
+            origin: Vector2::zero(),
+            rotation: 0.0,
+            scale: Vector2::one(),
+            flip_x: false,
+            flip_y: false,
+            tint: Color::WHITE,
+        };
+    }
+
+    /// Draw the frame of `sheet` at index `frame`
+    pub fn draw(&self, sheet: &SpriteSheet, frame: usize) {
+        let Some(frame) = sheet.frame(frame) else {
+            return;
+        };
+        let size = frame.trimmed_size();
+        let scale = Vector2::new(self.scale.x.abs(), self.scale.y.abs());
+
+        // Pivot relative to the top-left corner of the stored pixels, in frame pixels
+        let left = if self.flip_x {
+            frame.size.x - frame.offset.x - size.x
+        } else {
+            frame.offset.x
+        };
+        let top = if self.flip_y {
+            frame.size.y - frame.offset.y - size.y
+        } else {
+            frame.offset.y
+        };
+        let pivot_x = self.origin.x * frame.size.x - left;
+        let pivot_y = self.origin.y * frame.size.y - top;
+
+        let mut source = frame.source;
+        source.width = source.width.abs();
+        source.height = source.height.abs();
+        if !frame.rotated {
+            if self.flip_x {
+                source.width = -source.width;
+            }
+            if self.flip_y {
+                source.height = -source.height;
+            }
+            let dest = Rectangle::new(self.position.x, self.position.y, size.x * scale.x, size.y * scale.y);
+            let origin = Vector2::new(pivot_x * scale.x, pivot_y * scale.y);
+            sheet.texture.draw_pro(source, dest, origin, self.rotation, self.tint);
+            return;
+        }
+
+        // NOTE: Frames stored rotated clockwise are drawn rotated back by 90 degrees,
+        // texture x runs along the frame height and texture y along the frame width
+        if self.flip_x {
+            source.height = -source.height;
+        }
+        if self.flip_y {
+            source.width = -source.width;
+        }
+        let dest = Rectangle::new(self.position.x, self.position.y, size.y * scale.y, size.x * scale.x);
+        let origin = Vector2::new((size.y - pivot_y) * scale.y, pivot_x * scale.x);
+        sheet
+            .texture
+            .draw_pro(source, dest, origin, self.rotation - 90.0, self.tint);
+    }
+
+    /// Draw the current frame of `animation`
+    pub fn draw_animation(&self, sheet: &SpriteSheet, animation: &SpriteAnimation) {
+        self.draw(sheet, animation.current_frame());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PixelFormat, Texture};
+    use std::{cell::RefCell, rc::Rc};
+
+    /// Record the events of an animation
+    fn record(animation: &mut SpriteAnimation) -> Rc<RefCell<Vec<SpriteAnimationEvent>>> {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let recorded = events.clone();
+        animation.on_event(move |event| recorded.borrow_mut().push(event));
+        return events;
+    }
+
+    fn three_frames(mode: SpriteAnimationMode) -> SpriteAnimation {
+        return SpriteAnimation::new(vec![(10, 1.0), (11, 1.0), (12, 1.0)], mode);
+    }
+
+    #[test]
+    fn loop_animation() {
+        use SpriteAnimationEvent::*;
+        let mut animation = three_frames(SpriteAnimationMode::Loop);
+        let events = record(&mut animation);
+
+        animation.update(0.5);
+        assert_eq!(animation.position(), 0);
+        assert!(events.borrow().is_empty());
+
+        animation.update(0.5);
+        assert_eq!(animation.current_frame(), 11);
+        animation.update(2.0);
+        assert_eq!(animation.current_frame(), 10);
+        assert_eq!(*events.borrow(), [FrameChanged(1), FrameChanged(2), Looped, FrameChanged(0)]);
+    }
+
+    #[test]
+    fn ping_pong_animation() {
+        use SpriteAnimationEvent::*;
+        let mut animation = three_frames(SpriteAnimationMode::PingPong);
+        let events = record(&mut animation);
+
+        animation.update(5.0);
+        assert_eq!(animation.position(), 1);
+        let expected = [FrameChanged(1), FrameChanged(2), FrameChanged(1), FrameChanged(0), Looped, FrameChanged(1)];
+        assert_eq!(*events.borrow(), expected);
+
+        // A single frame loops without changing frame
+        let mut animation = SpriteAnimation::new(vec![(3, 1.0)], SpriteAnimationMode::PingPong);
+        let events = record(&mut animation);
+        animation.update(2.0);
+        assert_eq!(animation.current_frame(), 3);
+        assert_eq!(*events.borrow(), [Looped, Looped]);
+    }
+
+    #[test]
+    fn once_animation() {
+        use SpriteAnimationEvent::*;
+        let mut animation = three_frames(SpriteAnimationMode::Once);
+        let events = record(&mut animation);
+
+        animation.update(2.5);
+        assert_eq!(animation.position(), 2);
+        assert!(!animation.is_finished());
+
+        // The last frame is shown for its whole duration before finishing
+        animation.update(1.0);
+        assert!(animation.is_finished());
+        assert!(!animation.is_playing());
+        animation.update(10.0);
+        assert_eq!(animation.position(), 2);
+        assert_eq!(*events.borrow(), [FrameChanged(1), FrameChanged(2), Finished]);
+
+        animation.restart();
+        assert_eq!((animation.position(), animation.is_finished(), animation.is_playing()), (0, false, true));
+    }
+
+    #[test]
+    fn large_time_steps() {
+        let mut animation = SpriteAnimation::from_range(0, 2, 10.0, SpriteAnimationMode::Loop);
+        let events = record(&mut animation);
+        animation.update(100.05);
+        assert_eq!(animation.position(), 1);
+        let loops = events.borrow().iter().filter(|&&event| event == SpriteAnimationEvent::Looped).count();
+        assert_eq!(loops, 333);
+
+        // Zero durations still advance a bounded number of frames
+        let mut animation = SpriteAnimation::new(vec![(0, 0.0), (1, 0.0)], SpriteAnimationMode::Loop);
+        animation.update(1.0);
+        assert!(animation.position() < 2);
+    }
+
+    fn sheet() -> SpriteSheet {
+        let format = PixelFormat::UncompressedR8g8b8a8;
+        let texture = Texture2D(Texture { id: 0, width: 64, height: 16, mipmaps: 1, format });
+        let frame = SpriteFrame::new(Rectangle::new(0.0, 0.0, 16.0, 16.0));
+        let frames = (0..4).map(|index| SpriteFrame { duration: (index + 1) as f32, ..frame }).collect();
+        let mut sheet = SpriteSheet::new(texture, frames);
+        let tags = [
+            ("walk", 0, 2, SpriteTagDirection::Forward, None),
+            ("back", 0, 2, SpriteTagDirection::Reverse, None),
+            ("swing", 0, 2, SpriteTagDirection::PingPong, None),
+            ("repeat", 1, 2, SpriteTagDirection::Forward, Some(3)),
+            ("bounce", 0, 2, SpriteTagDirection::PingPong, Some(3)),
+            ("bounce_reverse", 0, 2, SpriteTagDirection::PingPongReverse, Some(2)),
+            ("broken", 2, 4, SpriteTagDirection::Forward, None),
+        ];
+        for (name, from, to, direction, repeat) in tags {
+            sheet.add_tag(SpriteTag { name: name.to_string(), from, to, direction, repeat });
+        }
+        return sheet;
+    }
+
+    #[test]
+    fn tag_animations() {
+        let sheet = sheet();
+        let sequence = |name: &str| {
+            let animation = sheet.animation(name).unwrap();
+            let frames: Vec<usize> = animation.frames().iter().map(|&(frame, _)| frame).collect();
+            return (frames, animation.mode);
+        };
+
+        assert_eq!(sequence("walk"), (vec![0, 1, 2], SpriteAnimationMode::Loop));
+        assert_eq!(sequence("back"), (vec![2, 1, 0], SpriteAnimationMode::Loop));
+        assert_eq!(sequence("swing"), (vec![0, 1, 2], SpriteAnimationMode::PingPong));
+        assert_eq!(sequence("repeat"), (vec![1, 2, 1, 2, 1, 2], SpriteAnimationMode::Once));
+        // Ping-pong passes do not show the turning frame twice
+        assert_eq!(sequence("bounce"), (vec![0, 1, 2, 1, 0, 1, 2], SpriteAnimationMode::Once));
+        assert_eq!(sequence("bounce_reverse"), (vec![2, 1, 0, 1, 2], SpriteAnimationMode::Once));
+
+        let repeat = sheet.animation("repeat").unwrap();
+        assert_eq!(repeat.frames()[..2], [(1, 2.0), (2, 3.0)]);
+        assert!(sheet.animation("broken").is_none());
+        assert!(sheet.animation("missing").is_none());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::json::Json;
+use crate::{
+    Rectangle, SpriteFrame, SpriteSheet, SpriteSlice, SpriteSliceKey, SpriteTag, SpriteTagDirection, Texture2D, Vector2,
+};
+use std::path::Path;
+
+impl SpriteSheet {
+    /// Sheet from an Aseprite JSON export (hash or array frames), with frame durations, tags and slices
+    pub fn from_aseprite_json(texture: Texture2D, text: &str) -> Result<Self, String> {
+        return read_aseprite(texture, &Json::parse(text)?);
+    }
+
+    /// Sheet from a TexturePacker JSON export, in the hash or array format
+    pub fn from_texture_packer_json(texture: Texture2D, text: &str) -> Result<Self, String> {
+        return read_texture_packer(texture, &Json::parse(text)?);
+    }
+
+    /// Load an Aseprite JSON export and the image it references, relative to the JSON file
+    pub fn load_aseprite(filename: &str) -> Result<Self, String> {
+        return load_export(filename, read_aseprite);
+    }
+
+    /// Load a TexturePacker JSON export and the image it references, relative to the JSON file
+    pub fn load_texture_packer(filename: &str) -> Result<Self, String> {
+        return load_export(filename, read_texture_packer);
+    }
+}
+
+fn read_aseprite(texture: Texture2D, root: &Json) -> Result<SpriteSheet, String> {
+    let mut sheet = SpriteSheet::new(texture, Vec::new());
+    read_frames(&mut sheet, root)?;
+    let Some(meta) = root.get("meta") else {
+        return Ok(sheet);
+    };
+    for tag in meta.get("frameTags").and_then(Json::as_array).unwrap_or_default() {
+        sheet.add_tag(read_tag(tag)?);
+    }
+    for slice in meta.get("slices").and_then(Json::as_array).unwrap_or_default() {
+        sheet.add_slice(read_slice(slice)?);
+    }
+    return Ok(sheet);
+}
+
+fn read_texture_packer(texture: Texture2D, root: &Json) -> Result<SpriteSheet, String> {
+    let mut sheet = SpriteSheet::new(texture, Vec::new());
+    read_frames(&mut sheet, root)?;
+    return Ok(sheet);
+}
+
+/// Read an export and load the texture named by `meta.image`, the texture is unloaded if the export is invalid
+fn load_export(
+    filename: &str,
+    read: fn(Texture2D, &Json) -> Result<SpriteSheet, String>,
+) -> Result<SpriteSheet, String> {
+    let text =
+        std::fs::read_to_string(filename).map_err(|e| format!("sprite sheet: failed to read {}: {}", filename, e))?;
+    let root = Json::parse(&text)?;
+    let image = root
+        .get("meta")
+        .and_then(|meta| meta.get("image"))
+        .and_then(Json::as_str)
+        .ok_or("sprite sheet: missing \"meta.image\"")?;
+    let path = Path::new(filename).parent().unwrap_or(Path::new("")).join(image);
+    let texture = Texture2D::load(&path.to_string_lossy());
+    if !texture.is_valid() {
+        return Err(format!("sprite sheet: failed to load {}", path.display()));
+    }
+    return read(texture, &root).inspect_err(|_| texture.unload());
+}
+
+fn number(value: &Json, key: &str) -> Result<f32, String> {
+    return value
+        .get(key)
+        .and_then(Json::as_f64)
+        .map(|number| number as f32)
+        .ok_or_else(|| format!("sprite sheet: missing or invalid \"{}\"", key));
+}
+
+/// `{"x", "y", "w", "h"}` rectangle
+fn rectangle(value: Option<&Json>) -> Result<Rectangle, String> {
+    let value = value.ok_or("sprite sheet: missing rectangle")?;
+    return Ok(Rectangle::new(
+        number(value, "x")?,
+        number(value, "y")?,
+        number(value, "w")?,
+        number(value, "h")?,
+    ));
+}
+
+/// Frames keyed by name (hash format) or listed with a `filename` (array format)
+fn read_frames(sheet: &mut SpriteSheet, root: &Json) -> Result<(), String> {
+    let frames = root.get("frames").ok_or("sprite sheet: missing \"frames\"")?;
+    if let Some(members) = frames.as_object() {
+        for (name, value) in members {
+            let index = sheet.push(read_frame(value)?);
+            sheet.set_name(name, index);
+        }
+        return Ok(());
+    }
+
+    let frames = frames.as_array().ok_or("sprite sheet: invalid \"frames\"")?;
+    for value in frames {
+        let index = sheet.push(read_frame(value)?);
+        if let Some(name) = value.get("filename").and_then(Json::as_str) {
+            sheet.set_name(name, index);
+        }
+    }
+    return Ok(());
+}
+
+fn read_frame(value: &Json) -> Result<SpriteFrame, String> {
+    let mut source = rectangle(value.get("frame"))?;
+    let rotated = value.get("rotated").and_then(Json::as_bool).unwrap_or(false);
+    // NOTE: Rotated frames list the upright size, the texture area has width and height swapped
+    if rotated {
+        std::mem::swap(&mut source.width, &mut source.height);
+    }
+
+    let mut frame = SpriteFrame::new(source);
+    frame.rotated = rotated;
+    frame.size = frame.trimmed_size();
+    if let Some(trim) = value.get("spriteSourceSize") {
+        let trim = rectangle(Some(trim))?;
+        frame.offset = Vector2::new(trim.x, trim.y);
+    }
+    if let Some(size) = value.get("sourceSize") {
+        frame.size = Vector2::new(number(size, "w")?, number(size, "h")?);
+    }
+    if let Some(duration) = value.get("duration").and_then(Json::as_f64) {
+        frame.duration = duration as f32 / 1000.0;
+    }
+    return Ok(frame);
+}
+
+fn read_tag(value: &Json) -> Result<SpriteTag, String> {
+    let name = value
+        .get("name")
+        .and_then(Json::as_str)
+        .ok_or("sprite sheet: tag without a name")?;
+    let direction = match value.get("direction").and_then(Json::as_str).unwrap_or("forward") {
+        "forward" => SpriteTagDirection::Forward,
+        "reverse" => SpriteTagDirection::Reverse,
+        "pingpong" => SpriteTagDirection::PingPong,
+        "pingpong_reverse" => SpriteTagDirection::PingPongReverse,
+        other => return Err(format!("sprite sheet: unknown tag direction \"{}\"", other)),
+    };
+    // NOTE: Aseprite writes the repeat count as a string
+    let repeat = match value.get("repeat") {
+        Some(Json::String(repeat)) => repeat.parse::<u32>().ok(),
+        Some(Json::Number(repeat)) => Some(*repeat as u32),
+        _ => None,
+    };
+    return Ok(SpriteTag {
+        name: name.to_string(),
+        from: number(value, "from")? as usize,
+        to: number(value, "to")? as usize,
+        direction,
+        repeat: repeat.filter(|&count| count > 0),
+    });
+}
+
+fn read_slice(value: &Json) -> Result<SpriteSlice, String> {
+    let name = value
+        .get("name")
+        .and_then(Json::as_str)
+        .ok_or("sprite sheet: slice without a name")?;
+    let mut keys = Vec::new();
+    for key in value.get("keys").and_then(Json::as_array).unwrap_or_default() {
+        let center = match key.get("center") {
+            Some(center) => Some(rectangle(Some(center))?),
+            None => None,
+        };
+        let pivot = match key.get("pivot") {
+            Some(pivot) => Some(Vector2::new(number(pivot, "x")?, number(pivot, "y")?)),
+            None => None,
+        };
+        keys.push(SpriteSliceKey {
+            frame: number(key, "frame")? as usize,
+            bounds: rectangle(key.get("bounds"))?,
+            center,
+            pivot,
+        });
+    }
+    keys.sort_by_key(|key| key.frame);
+    return Ok(SpriteSlice {
+        name: name.to_string(),
+        keys,
+    });
+}
@@ -66,7 +66,7 @@ pub struct Texture {
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct Texture2D(Texture);
+pub struct Texture2D(pub(crate) Texture);
 
 impl Texture2D {
     // Loading
@@ -87,6 +87,14 @@ impl Texture2D {
         return is_texture_valid(*self);
     }
 
+    pub fn width(&self) -> i32 {
+        return self.0.width;
+    }
+
+    pub fn height(&self) -> i32 {
+        return self.0.height;
+    }
+
     pub fn update(&self, pixels: &[Color]) {
         update_texture(*self, pixels);
     }
@@ -138,12 +146,29 @@ impl Texture2D {
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct TextureCubemap(Texture);
+pub struct TextureCubemap(pub(crate) Texture);
 
 impl TextureCubemap {
     pub fn load(image: Image, layout: CubemapLayout) -> TextureCubemap {
         return load_texture_cubemap(image, layout);
     }
+
+    pub fn unload(&self) {
+        unload_texture(Texture2D(self.0));
+    }
+
+    pub fn is_valid(&self) -> bool {
+        return self.0.id != 0;
+    }
+
+    /// Face width and height
+    pub fn size(&self) -> i32 {
+        return self.0.width;
+    }
+
+    pub fn mipmaps(&self) -> i32 {
+        return self.0.mipmaps;
+    }
 }
 
 #[repr(C)]
@@ -156,7 +181,7 @@ pub struct RenderTexture {
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct RenderTexture2D(RenderTexture);
+pub struct RenderTexture2D(pub(crate) RenderTexture);
 
 impl RenderTexture2D {
     pub fn load(width: i32, height: i32) -> RenderTexture2D {
@@ -170,4 +195,9 @@ impl RenderTexture2D {
     pub fn unload(self) {
         unload_render_texture(self);
     }
+
+    /// Color buffer texture
+    pub fn texture(&self) -> Texture2D {
+        return Texture2D(self.0.texture);
+    }
 }
@@ -0,0 +1,616 @@
+mod tmj;
+mod tmx;
+
+use crate::{Color, Rectangle, Texture2D, Vector2, decode_data_base64, decompress_data};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const FLIP_HORIZONTAL: u32 = 0x8000_0000;
+const FLIP_VERTICAL: u32 = 0x4000_0000;
+const FLIP_DIAGONAL: u32 = 0x2000_0000;
+const FLIP_HEXAGONAL: u32 = 0x1000_0000;
+const GID_MASK: u32 = !(FLIP_HORIZONTAL | FLIP_VERTICAL | FLIP_DIAGONAL | FLIP_HEXAGONAL);
+
+/// Tile placed in a layer cell or on a tile object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TiledTile {
+    /// Global tile id, without the flip flags
+    pub gid: u32,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+    /// Flipped over the top-left to bottom-right diagonal, applied before the other flips
+    pub flip_diagonal: bool,
+}
+
+impl TiledTile {
+    /// Tile from a global id with flip flags, `None` for an empty cell
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        let gid = raw & GID_MASK;
+        if gid == 0 {
+            return None;
+        }
+        return Some(Self {
+            gid,
+            flip_horizontal: raw & FLIP_HORIZONTAL != 0,
+            flip_vertical: raw & FLIP_VERTICAL != 0,
+            flip_diagonal: raw & FLIP_DIAGONAL != 0,
+        });
+    }
+}
+
+/// Tileset cut from a single image
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiledTileset {
+    /// Global id of the first tile
+    pub first_gid: u32,
+    pub name: String,
+    pub tile_width: i32,
+    pub tile_height: i32,
+    pub spacing: i32,
+    pub margin: i32,
+    pub columns: i32,
+    pub tile_count: u32,
+    /// Drawing offset of every tile in pixels
+    pub offset: Vector2,
+    /// Image path, resolved against the map or tileset file
+    pub image: String,
+    /// Image texture, loaded by `TiledMap::load()`
+    pub texture: Option<Texture2D>,
+}
+
+impl TiledTileset {
+    pub fn contains(&self, gid: u32) -> bool {
+        return gid >= self.first_gid && gid < self.first_gid + self.tile_count;
+    }
+
+    /// Area of the tileset image holding the tile with global id `gid`
+    pub fn source_rect(&self, gid: u32) -> Rectangle {
+        let index = gid.saturating_sub(self.first_gid) as i32;
+        let columns = self.columns.max(1);
+        let x = self.margin + (index % columns) * (self.tile_width + self.spacing);
+        let y = self.margin + (index / columns) * (self.tile_height + self.spacing);
+        return Rectangle::new(x as f32, y as f32, self.tile_width as f32, self.tile_height as f32);
+    }
+}
+
+/// Layer of tiles. Infinite maps are merged into the smallest area holding every chunk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiledTileLayer {
+    pub name: String,
+    /// Visible in the editor, including the parent groups
+    pub visible: bool,
+    /// Opacity including the parent groups
+    pub opacity: f32,
+    /// Offset in pixels including the parent groups
+    pub offset: Vector2,
+    /// Cell of the first stored tile, negative cells are possible on infinite maps
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    tiles: Vec<u32>,
+}
+
+impl TiledTileLayer {
+    /// Tile at map cell `x`, `y`
+    pub fn tile(&self, x: i32, y: i32) -> Option<TiledTile> {
+        let (x, y) = (x - self.x, y - self.y);
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return None;
+        }
+        return TiledTile::from_raw(self.tiles[y as usize * self.width as usize + x as usize]);
+    }
+
+    /// Global ids with flip flags, row by row
+    pub fn raw_tiles(&self) -> &[u32] {
+        return &self.tiles;
+    }
+}
+
+/// Shape of an object, in map pixels including the layer offsets
+#[derive(Debug, Clone, PartialEq)]
+pub enum TiledShape {
+    /// Rectangle, text or tile object
+    Rectangle(Rectangle),
+    /// Ellipse inside a rectangle
+    Ellipse(Rectangle),
+    Point(Vector2),
+    Polygon(Vec<Vector2>),
+    Polyline(Vec<Vector2>),
+}
+
+/// Object of an object layer. The object rotation is not applied to its shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiledObject {
+    pub id: u32,
+    pub name: String,
+    /// Class (or type in older files)
+    pub class: String,
+    /// Position in map pixels including the layer offsets
+    pub position: Vector2,
+    /// Rotation in degrees clockwise around `position`
+    pub rotation: f32,
+    pub visible: bool,
+    /// Tile of tile objects
+    pub tile: Option<TiledTile>,
+    pub shape: TiledShape,
+    /// Custom properties as text
+    pub properties: BTreeMap<String, String>,
+}
+
+/// Layer of objects
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiledObjectLayer {
+    pub name: String,
+    pub visible: bool,
+    pub opacity: f32,
+    pub offset: Vector2,
+    pub objects: Vec<TiledObject>,
+}
+
+/// Tile or object layer. Group layers are flattened into their children and image layers are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TiledLayer {
+    Tiles(TiledTileLayer),
+    Objects(TiledObjectLayer),
+}
+
+impl TiledLayer {
+    pub fn name(&self) -> &str {
+        match self {
+            TiledLayer::Tiles(layer) => return &layer.name,
+            TiledLayer::Objects(layer) => return &layer.name,
+        }
+    }
+}
+
+/// Orthogonal map made with the Tiled editor, loaded from a `.tmx` (XML) or `.tmj` (JSON) file
+#[derive(Debug, Clone, PartialEq)]
+pub struct TiledMap {
+    /// Size in tiles (ignored by infinite maps)
+    pub width: i32,
+    pub height: i32,
+    pub tile_width: i32,
+    pub tile_height: i32,
+    pub infinite: bool,
+    pub tilesets: Vec<TiledTileset>,
+    /// Layers from bottom to top
+    pub layers: Vec<TiledLayer>,
+    /// Custom map properties as text
+    pub properties: BTreeMap<String, String>,
+}
+
+impl TiledMap {
+    /// Load a map, its external tilesets and the tileset textures
+    pub fn load(filename: &str) -> Result<Self, String> {
+        let text =
+            std::fs::read_to_string(filename).map_err(|e| format!("tiled: failed to read {}: {}", filename, e))?;
+        let directory = Path::new(filename).parent().unwrap_or(Path::new(""));
+        let mut map = match Path::new(filename).extension().and_then(|extension| extension.to_str()) {
+            Some("tmj" | "json") => tmj::parse_map(&text, directory)?,
+            _ => tmx::parse_map(&text, directory)?,
+        };
+
+        for index in 0..map.tilesets.len() {
+            let image = &map.tilesets[index].image;
+            if image.is_empty() {
+                continue;
+            }
+            let texture = Texture2D::load(image);
+            if !texture.is_valid() {
+                let error = format!("tiled: failed to load {}", image);
+                map.unload();
+                return Err(error);
+            }
+            map.tilesets[index].texture = Some(texture);
+        }
+        return Ok(map);
+    }
+
+    /// Unload the tileset textures
+    pub fn unload(&self) {
+        for texture in self.tilesets.iter().filter_map(|tileset| tileset.texture) {
+            texture.unload();
+        }
+    }
+
+    pub fn layer(&self, name: &str) -> Option<&TiledLayer> {
+        return self.layers.iter().find(|layer| layer.name() == name);
+    }
+
+    pub fn tile_layer(&self, name: &str) -> Option<&TiledTileLayer> {
+        match self.layer(name) {
+            Some(TiledLayer::Tiles(layer)) => return Some(layer),
+            _ => return None,
+        }
+    }
+
+    pub fn object_layer(&self, name: &str) -> Option<&TiledObjectLayer> {
+        match self.layer(name) {
+            Some(TiledLayer::Objects(layer)) => return Some(layer),
+            _ => return None,
+        }
+    }
+
+    /// Tileset holding the tile with global id `gid`
+    pub fn tileset(&self, gid: u32) -> Option<&TiledTileset> {
+        return self.tilesets.iter().rev().find(|tileset| tileset.first_gid <= gid);
+    }
+
+    /// Draw the visible tile layers in the world area `view`, usually `camera.visible_area(viewport)`. Call between
+    /// `camera.begin_mode()` and `camera.end_mode()`.
+    pub fn draw(&self, view: Rectangle) {
+        for layer in &self.layers {
+            if let TiledLayer::Tiles(layer) = layer {
+                self.draw_layer(layer, view);
+            }
+        }
+    }
+
+    /// Draw the tiles of `layer` in the world area `view`
+    pub fn draw_layer(&self, layer: &TiledTileLayer, view: Rectangle) {
+        if !layer.visible || layer.opacity <= 0.0 {
+            return;
+        }
+
+        let (min_x, min_y) = (view.x - layer.offset.x, view.y - layer.offset.y);
+        let (max_x, max_y) = (min_x + view.width, min_y + view.height);
+
+        // NOTE: Tiles larger than the map grid reach up and right of their cell
+        let (tile_width, tile_height) = (self.tile_width.max(1), self.tile_height.max(1));
+        let overdraw_x = self
+            .tilesets
+            .iter()
+            .map(|tileset| tileset.tile_width / tile_width)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let overdraw_y = self
+            .tilesets
+            .iter()
+            .map(|tileset| tileset.tile_height / tile_height)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let first_x = ((min_x / tile_width as f32).floor() as i32 - overdraw_x).max(layer.x);
+        let first_y = ((min_y / tile_height as f32).floor() as i32 - 1).max(layer.y);
+        let last_x = ((max_x / tile_width as f32).floor() as i32 + 1).min(layer.x + layer.width - 1);
+        let last_y = ((max_y / tile_height as f32).floor() as i32 + overdraw_y).min(layer.y + layer.height - 1);
+
+        let tint = Color::new(255, 255, 255, (layer.opacity.clamp(0.0, 1.0) * 255.0) as u8);
+        for y in first_y..=last_y {
+            for x in first_x..=last_x {
+                let Some(tile) = layer.tile(x, y) else {
+                    continue;
+                };
+                let Some(tileset) = self.tileset(tile.gid) else {
+                    continue;
+                };
+                let Some(texture) = tileset.texture else {
+                    continue;
+                };
+                let cell_x = layer.offset.x + (x * self.tile_width) as f32 + tileset.offset.x;
+                let cell_bottom = layer.offset.y + ((y + 1) * self.tile_height) as f32 + tileset.offset.y;
+                draw_tile(texture, tileset, tile, cell_x, cell_bottom, tint);
+            }
+        }
+    }
+}
+
+/// Draw a tile anchored at the bottom-left corner of its cell
+fn draw_tile(texture: Texture2D, tileset: &TiledTileset, tile: TiledTile, x: f32, bottom: f32, tint: Color) {
+    let mut source = tileset.source_rect(tile.gid);
+    let (width, height) = (source.width, source.height);
+    if !tile.flip_diagonal {
+        if tile.flip_horizontal {
+            source.width = -width;
+        }
+        if tile.flip_vertical {
+            source.height = -height;
+        }
+        texture.draw_rec(source, Vector2::new(x, bottom - height), tint);
+        return;
+    }
+
+    // NOTE: A diagonal flip is drawn as a 90 degree rotation of a mirrored tile, the size is transposed
+    if tile.flip_vertical {
+        source.width = -width;
+    }
+    if !tile.flip_horizontal {
+        source.height = -height;
+    }
+    let center = Vector2::new(x + height / 2.0, bottom - width / 2.0);
+    let dest = Rectangle::new(center.x, center.y, width, height);
+    texture.draw_pro(source, dest, Vector2::new(width / 2.0, height / 2.0), 90.0, tint);
+}
+
+/// Layer state inherited from the parent groups
+#[derive(Debug, Clone, Copy)]
+struct LayerParent {
+    visible: bool,
+    opacity: f32,
+    offset: Vector2,
+}
+
+impl LayerParent {
+    const ROOT: LayerParent = LayerParent {
+        visible: true,
+        opacity: 1.0,
+        offset: Vector2 { x: 0.0, y: 0.0 },
+    };
+
+    fn child(&self, visible: bool, opacity: f32, offset: Vector2) -> Self {
+        return Self {
+            visible: self.visible && visible,
+            opacity: self.opacity * opacity,
+            offset: self.offset + offset,
+        };
+    }
+}
+
+/// Region of an infinite map layer
+struct Chunk {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    tiles: Vec<u32>,
+}
+
+/// Layer area and tiles covering every chunk
+fn merge_chunks(chunks: Vec<Chunk>) -> Result<(i32, i32, i32, i32, Vec<u32>), String> {
+    let too_large = || "tiled: layer is too large".to_string();
+    for chunk in &chunks {
+        check_tiles(&chunk.tiles, tile_count(chunk.width, chunk.height)?)?;
+    }
+    if chunks.is_empty() {
+        return Ok((0, 0, 0, 0, Vec::new()));
+    }
+    let left = chunks.iter().map(|chunk| chunk.x).min().unwrap();
+    let top = chunks.iter().map(|chunk| chunk.y).min().unwrap();
+    let right = chunks
+        .iter()
+        .map(|chunk| chunk.x as i64 + chunk.width as i64)
+        .max()
+        .unwrap();
+    let bottom = chunks
+        .iter()
+        .map(|chunk| chunk.y as i64 + chunk.height as i64)
+        .max()
+        .unwrap();
+    let width = i32::try_from(right - left as i64).map_err(|_| too_large())?;
+    let height = i32::try_from(bottom - top as i64).map_err(|_| too_large())?;
+
+    // NOTE: Chunks far apart span a huge sparse area, fail instead of aborting on allocation
+    let count = tile_count(width, height)?;
+    let mut tiles = Vec::new();
+    tiles.try_reserve_exact(count).map_err(|_| too_large())?;
+    tiles.resize(count, 0);
+    for chunk in chunks {
+        let (chunk_width, column) = (chunk.width as usize, (chunk.x - left) as usize);
+        for row in 0..chunk.height as usize {
+            let start = row * chunk_width;
+            let target = ((chunk.y - top) as usize + row) * width as usize + column;
+            tiles[target..target + chunk_width].copy_from_slice(&chunk.tiles[start..start + chunk_width]);
+        }
+    }
+    return Ok((left, top, width, height, tiles));
+}
+
+/// Number of tiles in a layer or chunk of the given size
+fn tile_count(width: i32, height: i32) -> Result<usize, String> {
+    if width < 0 || height < 0 {
+        return Err(format!("tiled: invalid layer size {}x{}", width, height));
+    }
+    return (width as usize)
+        .checked_mul(height as usize)
+        .ok_or_else(|| "tiled: layer is too large".to_string());
+}
+
+/// Check that layer or chunk data holds exactly `count` tiles
+fn check_tiles(tiles: &[u32], count: usize) -> Result<(), String> {
+    if tiles.len() != count {
+        return Err(format!(
+            "tiled: layer data has {} tiles instead of {}",
+            tiles.len(),
+            count
+        ));
+    }
+    return Ok(());
+}
+
+/// Decode layer data in CSV or base64 with optional zlib or gzip compression
+fn decode_tiles(
+    data: &str,
+    encoding: Option<&str>,
+    compression: Option<&str>,
+    count: usize,
+) -> Result<Vec<u32>, String> {
+    let tiles = match encoding {
+        Some("csv") => {
+            let mut tiles = Vec::with_capacity(count.min(data.len()));
+            for value in data.split(',').map(str::trim).filter(|value| !value.is_empty()) {
+                tiles.push(
+                    value
+                        .parse::<u32>()
+                        .map_err(|_| format!("tiled: invalid tile \"{}\"", value))?,
+                );
+            }
+            tiles
+        }
+        Some("base64") => {
+            let text: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+            let bytes = decode_data_base64(&text);
+            let bytes = match compression {
+                None | Some("") => bytes,
+                // NOTE: DecompressData expects raw DEFLATE, the zlib and gzip headers are skipped
+                Some("zlib") => decompress_data(bytes.get(2..).unwrap_or_default()),
+                Some("gzip") => decompress_data(gzip_payload(&bytes)?),
+                Some(other) => return Err(format!("tiled: unsupported compression \"{}\"", other)),
+            };
+            bytes
+                .chunks_exact(4)
+                .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+                .collect()
+        }
+        Some(other) => return Err(format!("tiled: unsupported encoding \"{}\"", other)),
+        None => return Err("tiled: missing layer data encoding".to_string()),
+    };
+    check_tiles(&tiles, count)?;
+    return Ok(tiles);
+}
+
+/// DEFLATE data of a gzip member
+fn gzip_payload(bytes: &[u8]) -> Result<&[u8], String> {
+    let invalid = || "tiled: invalid gzip data".to_string();
+    if bytes.len() < 10 || bytes[0] != 0x1f || bytes[1] != 0x8b {
+        return Err(invalid());
+    }
+    let flags = bytes[3];
+    let mut position = 10;
+    if flags & 4 != 0 {
+        let length = u16::from_le_bytes([
+            *bytes.get(position).ok_or_else(invalid)?,
+            *bytes.get(position + 1).ok_or_else(invalid)?,
+        ]);
+        position += 2 + length as usize;
+    }
+    // Zero terminated file name and comment
+    for flag in [8, 16] {
+        if flags & flag != 0 {
+            position += bytes
+                .get(position..)
+                .ok_or_else(invalid)?
+                .iter()
+                .position(|&byte| byte == 0)
+                .ok_or_else(invalid)?
+                + 1;
+        }
+    }
+    if flags & 2 != 0 {
+        position += 2;
+    }
+    return bytes.get(position..).ok_or_else(invalid);
+}
+
+/// Resolve a path found in a file against the file directory
+fn resolve(directory: &Path, path: &str) -> String {
+    return directory.join(path).to_string_lossy().into_owned();
+}
+
+/// Number of columns of a tileset image, for files written before the columns were stored
+fn image_columns(image_width: i32, tile_width: i32, margin: i32, spacing: i32) -> i32 {
+    return ((image_width - margin * 2 + spacing) / (tile_width + spacing).max(1)).max(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compress_data, encode_data_base64};
+
+    const TILES: [u32; 6] = [1, 2, 3, 0x8000_0004, 5, 0];
+
+    fn bytes(tiles: &[u32]) -> Vec<u8> {
+        return tiles.iter().flat_map(|tile| tile.to_le_bytes()).collect();
+    }
+
+    fn chunk(x: i32, y: i32, width: i32, height: i32, tiles: Vec<u32>) -> Chunk {
+        return Chunk {
+            x,
+            y,
+            width,
+            height,
+            tiles,
+        };
+    }
+
+    #[test]
+    fn decode_csv() {
+        let tiles = decode_tiles("1,2,3,\n2147483652, 5 ,0\n", Some("csv"), None, 6);
+        assert_eq!(tiles, Ok(TILES.to_vec()));
+
+        assert!(decode_tiles("1,2,3", Some("csv"), None, 6).is_err());
+        assert!(decode_tiles("1,x,3", Some("csv"), None, 3).is_err());
+        assert!(decode_tiles("1,-2,3", Some("csv"), None, 3).is_err());
+    }
+
+    #[test]
+    fn decode_base64() {
+        // NOTE: Tiled wraps the encoded data in whitespace
+        let text = format!("\n   {}\n", encode_data_base64(&bytes(&TILES)));
+        assert_eq!(decode_tiles(&text, Some("base64"), None, 6), Ok(TILES.to_vec()));
+        assert_eq!(decode_tiles(&text, Some("base64"), Some(""), 6), Ok(TILES.to_vec()));
+        assert!(decode_tiles(&text, Some("base64"), None, 5).is_err());
+        assert!(decode_tiles("AAAAA", Some("base64"), None, 1).is_err());
+        assert!(decode_tiles(&text, Some("base64"), Some("zstd"), 6).is_err());
+        assert!(decode_tiles(&text, Some("xml"), None, 6).is_err());
+        assert!(decode_tiles(&text, None, None, 6).is_err());
+    }
+
+    #[test]
+    fn decode_compressed() {
+        let deflate = compress_data(&bytes(&TILES));
+
+        // zlib: two header bytes, DEFLATE data and the adler32 checksum
+        let zlib = [&[0x78, 0x9c], deflate.as_slice(), &[0; 4]].concat();
+        let text = encode_data_base64(&zlib);
+        assert_eq!(decode_tiles(&text, Some("base64"), Some("zlib"), 6), Ok(TILES.to_vec()));
+
+        // gzip: ten header bytes, a file name, DEFLATE data, crc32 and size
+        let header = [0x1f, 0x8b, 8, 8, 0, 0, 0, 0, 0, 3];
+        let gzip = [&header, b"map.bin\0".as_slice(), deflate.as_slice(), &[0; 8]].concat();
+        let text = encode_data_base64(&gzip);
+        assert_eq!(decode_tiles(&text, Some("base64"), Some("gzip"), 6), Ok(TILES.to_vec()));
+
+        let text = encode_data_base64(&[&header[..2], deflate.as_slice()].concat());
+        assert!(decode_tiles(&text, Some("base64"), Some("gzip"), 6).is_err());
+    }
+
+    #[test]
+    fn gzip_headers() {
+        let payload = [1, 2, 3];
+        let gzip = |flags: u8, extra: &[u8]| [&[0x1f, 0x8b, 8, flags, 0, 0, 0, 0, 0, 3], extra, &payload].concat();
+
+        assert_eq!(gzip_payload(&gzip(0, &[])), Ok(payload.as_slice()));
+        assert_eq!(gzip_payload(&gzip(4, &[2, 0, 9, 9])), Ok(payload.as_slice()));
+        assert_eq!(gzip_payload(&gzip(8 | 16, b"name\0comment\0")), Ok(payload.as_slice()));
+        assert_eq!(gzip_payload(&gzip(2, &[0, 0])), Ok(payload.as_slice()));
+
+        assert!(gzip_payload(&[0x1f, 0x8b, 8]).is_err());
+        assert!(gzip_payload(&[0; 16]).is_err());
+        assert!(gzip_payload(&[0x1f, 0x8b, 8, 4, 0, 0, 0, 0, 0, 3, 0xff, 0xff]).is_err());
+        assert!(gzip_payload(&[0x1f, 0x8b, 8, 8, 0, 0, 0, 0, 0, 3, b'a']).is_err());
+    }
+
+    #[test]
+    fn merge() {
+        let chunks = vec![chunk(2, -2, 2, 2, vec![1, 2, 3, 4]), chunk(0, 0, 2, 1, vec![5, 6])];
+        let (x, y, width, height, tiles) = merge_chunks(chunks).unwrap();
+        assert_eq!((x, y, width, height), (0, -2, 4, 3));
+        assert_eq!(tiles, [0, 0, 1, 2, 0, 0, 3, 4, 5, 6, 0, 0]);
+
+        assert_eq!(merge_chunks(Vec::new()), Ok((0, 0, 0, 0, Vec::new())));
+    }
+
+    #[test]
+    fn merge_errors() {
+        assert!(merge_chunks(vec![chunk(0, 0, 2, 2, vec![1, 2, 3])]).is_err());
+        assert!(merge_chunks(vec![chunk(0, 0, 2, 1, vec![1, 2, 3])]).is_err());
+        assert!(merge_chunks(vec![chunk(0, 0, -2, -1, vec![1, 2])]).is_err());
+        assert!(merge_chunks(vec![chunk(0, 0, -2, 0, Vec::new())]).is_err());
+
+        // Chunks at both ends of the coordinate range
+        let far = vec![chunk(i32::MIN, 0, 1, 1, vec![1]), chunk(i32::MAX - 1, 0, 1, 1, vec![2])];
+        assert!(merge_chunks(far).is_err());
+    }
+
+    #[test]
+    fn counts() {
+        assert_eq!(tile_count(3, 2), Ok(6));
+        assert_eq!(tile_count(0, 5), Ok(0));
+        assert!(tile_count(-1, 5).is_err());
+        assert!(tile_count(5, -1).is_err());
+        assert_eq!(check_tiles(&[1, 2], 2), Ok(()));
+        assert!(check_tiles(&[1, 2], 3).is_err());
+    }
+}
@@ -0,0 +1,293 @@
+use super::{
+    Chunk, LayerParent, TiledLayer, TiledMap, TiledObject, TiledObjectLayer, TiledShape, TiledTile, TiledTileLayer,
+    TiledTileset, check_tiles, decode_tiles, image_columns, merge_chunks, resolve, tile_count, tmx,
+};
+use crate::json::Json;
+use crate::{Rectangle, Vector2};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub(super) fn parse_map(text: &str, directory: &Path) -> Result<TiledMap, String> {
+    let root = Json::parse(text)?;
+    let orientation = string(&root, "orientation").unwrap_or("orthogonal");
+    if orientation != "orthogonal" {
+        return Err(format!("tiled: unsupported {} orientation", orientation));
+    }
+
+    let mut map = TiledMap {
+        width: int(&root, "width", 0),
+        height: int(&root, "height", 0),
+        tile_width: int(&root, "tilewidth", 0),
+        tile_height: int(&root, "tileheight", 0),
+        infinite: root.get("infinite").and_then(Json::as_bool).unwrap_or(false),
+        tilesets: Vec::new(),
+        layers: Vec::new(),
+        properties: properties(&root),
+    };
+    for value in array(&root, "tilesets") {
+        let first_gid = int(value, "firstgid", 1) as u32;
+        let tileset = match string(value, "source") {
+            Some(source) if source.ends_with(".tsx") => tmx::load_tileset(&resolve(directory, source), first_gid)?,
+            Some(source) => load_tileset(&resolve(directory, source), first_gid)?,
+            None => parse_tileset(value, first_gid, directory),
+        };
+        map.tilesets.push(tileset);
+    }
+    map.tilesets.sort_by_key(|tileset| tileset.first_gid);
+    parse_layers(array(&root, "layers"), LayerParent::ROOT, &mut map.layers)?;
+    return Ok(map);
+}
+
+/// Load an external `.tsj` tileset
+fn load_tileset(filename: &str, first_gid: u32) -> Result<TiledTileset, String> {
+    let text = std::fs::read_to_string(filename).map_err(|e| format!("tiled: failed to read {}: {}", filename, e))?;
+    let root = Json::parse(&text)?;
+    let directory = Path::new(filename).parent().unwrap_or(Path::new(""));
+    return Ok(parse_tileset(&root, first_gid, directory));
+}
+
+fn parse_tileset(value: &Json, first_gid: u32, directory: &Path) -> TiledTileset {
+    let tile_width = int(value, "tilewidth", 0);
+    let spacing = int(value, "spacing", 0);
+    let margin = int(value, "margin", 0);
+    let columns = match value.get("columns").and_then(Json::as_i64) {
+        Some(columns) => columns as i32,
+        None => image_columns(int(value, "imagewidth", 0), tile_width, margin, spacing),
+    };
+    let offset = value.get("tileoffset");
+    return TiledTileset {
+        first_gid,
+        name: string(value, "name").unwrap_or_default().to_string(),
+        tile_width,
+        tile_height: int(value, "tileheight", 0),
+        spacing,
+        margin,
+        columns,
+        tile_count: int(value, "tilecount", 0) as u32,
+        offset: offset
+            .map(|offset| Vector2::new(float(offset, "x", 0.0), float(offset, "y", 0.0)))
+            .unwrap_or(Vector2::zero()),
+        image: string(value, "image")
+            .map(|image| resolve(directory, image))
+            .unwrap_or_default(),
+        texture: None,
+    };
+}
+
+fn parse_layers(values: &[Json], inherited: LayerParent, layers: &mut Vec<TiledLayer>) -> Result<(), String> {
+    for value in values {
+        let state = inherited.child(
+            value.get("visible").and_then(Json::as_bool).unwrap_or(true),
+            float(value, "opacity", 1.0),
+            Vector2::new(float(value, "offsetx", 0.0), float(value, "offsety", 0.0)),
+        );
+        match string(value, "type") {
+            Some("tilelayer") => layers.push(TiledLayer::Tiles(parse_tile_layer(value, state)?)),
+            Some("objectgroup") => layers.push(TiledLayer::Objects(parse_object_layer(value, state))),
+            Some("group") => parse_layers(array(value, "layers"), state, layers)?,
+            _ => {}
+        }
+    }
+    return Ok(());
+}
+
+fn parse_tile_layer(value: &Json, state: LayerParent) -> Result<TiledTileLayer, String> {
+    let encoding = string(value, "encoding");
+    let compression = string(value, "compression");
+    let read = |data: Option<&Json>, count: usize| match data {
+        Some(Json::Array(tiles)) => {
+            let tiles: Vec<u32> = tiles.iter().map(|tile| tile.as_f64().unwrap_or(0.0) as u32).collect();
+            check_tiles(&tiles, count)?;
+            return Ok(tiles);
+        }
+        Some(Json::String(text)) => return decode_tiles(text, encoding.or(Some("base64")), compression, count),
+        _ => return Err("tiled: tile layer without data".to_string()),
+    };
+
+    let (x, y, width, height, tiles) = match value.get("chunks").and_then(Json::as_array) {
+        Some(chunks) => {
+            let mut merged = Vec::with_capacity(chunks.len());
+            for chunk in chunks {
+                let (width, height) = (int(chunk, "width", 0), int(chunk, "height", 0));
+                let tiles = read(chunk.get("data"), tile_count(width, height)?)?;
+                merged.push(Chunk {
+                    x: int(chunk, "x", 0),
+                    y: int(chunk, "y", 0),
+                    width,
+                    height,
+                    tiles,
+                });
+            }
+            merge_chunks(merged)?
+        }
+        None => {
+            let (width, height) = (int(value, "width", 0), int(value, "height", 0));
+            let count = tile_count(width, height)?;
+            (0, 0, width, height, read(value.get("data"), count)?)
+        }
+    };
+
+    return Ok(TiledTileLayer {
+        name: string(value, "name").unwrap_or_default().to_string(),
+        visible: state.visible,
+        opacity: state.opacity,
+        offset: state.offset,
+        x,
+        y,
+        width,
+        height,
+        tiles,
+    });
+}
+
+fn parse_object_layer(value: &Json, state: LayerParent) -> TiledObjectLayer {
+    return TiledObjectLayer {
+        name: string(value, "name").unwrap_or_default().to_string(),
+        visible: state.visible,
+        opacity: state.opacity,
+        offset: state.offset,
+        objects: array(value, "objects")
+            .iter()
+            .map(|object| parse_object(object, state.offset))
+            .collect(),
+    };
+}
+
+fn parse_object(value: &Json, offset: Vector2) -> TiledObject {
+    let position = offset + Vector2::new(float(value, "x", 0.0), float(value, "y", 0.0));
+    let (width, height) = (float(value, "width", 0.0), float(value, "height", 0.0));
+    let tile = value
+        .get("gid")
+        .and_then(Json::as_f64)
+        .and_then(|gid| TiledTile::from_raw(gid as u32));
+    let flag = |name: &str| value.get(name).and_then(Json::as_bool).unwrap_or(false);
+    let points = |name: &str| {
+        let points = value.get(name)?.as_array()?;
+        return Some(
+            points
+                .iter()
+                .map(|point| position + Vector2::new(float(point, "x", 0.0), float(point, "y", 0.0)))
+                .collect(),
+        );
+    };
+
+    let shape = if tile.is_some() {
+        // NOTE: Tile objects are anchored at their bottom-left corner
+        TiledShape::Rectangle(Rectangle::new(position.x, position.y - height, width, height))
+    } else if flag("ellipse") {
+        TiledShape::Ellipse(Rectangle::new(position.x, position.y, width, height))
+    } else if flag("point") {
+        TiledShape::Point(position)
+    } else if let Some(points) = points("polygon") {
+        TiledShape::Polygon(points)
+    } else if let Some(points) = points("polyline") {
+        TiledShape::Polyline(points)
+    } else {
+        TiledShape::Rectangle(Rectangle::new(position.x, position.y, width, height))
+    };
+
+    return TiledObject {
+        id: int(value, "id", 0) as u32,
+        name: string(value, "name").unwrap_or_default().to_string(),
+        class: string(value, "class")
+            .or(string(value, "type"))
+            .unwrap_or_default()
+            .to_string(),
+        position,
+        rotation: float(value, "rotation", 0.0),
+        visible: value.get("visible").and_then(Json::as_bool).unwrap_or(true),
+        tile,
+        shape,
+        properties: properties(value),
+    };
+}
+
+/// Custom properties as text
+fn properties(value: &Json) -> BTreeMap<String, String> {
+    let mut properties = BTreeMap::new();
+    for property in array(value, "properties") {
+        let Some(name) = string(property, "name") else {
+            continue;
+        };
+        let text = match property.get("value") {
+            Some(Json::String(text)) => text.clone(),
+            Some(Json::Number(number)) => number.to_string(),
+            Some(Json::Bool(flag)) => flag.to_string(),
+            _ => String::new(),
+        };
+        properties.insert(name.to_string(), text);
+    }
+    return properties;
+}
+
+fn array<'a>(value: &'a Json, key: &str) -> &'a [Json] {
+    return value.get(key).and_then(Json::as_array).unwrap_or_default();
+}
+
+fn string<'a>(value: &'a Json, key: &str) -> Option<&'a str> {
+    return value.get(key).and_then(Json::as_str);
+}
+
+fn int(value: &Json, key: &str, default: i32) -> i32 {
+    return value
+        .get(key)
+        .and_then(Json::as_i64)
+        .map(|value| value as i32)
+        .unwrap_or(default);
+}
+
+fn float(value: &Json, key: &str, default: f32) -> f32 {
+    return value
+        .get(key)
+        .and_then(Json::as_f64)
+        .map(|value| value as f32)
+        .unwrap_or(default);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_layer(layer: &str) -> Result<TiledTileLayer, String> {
+        let text = format!(
+            r#"{{"orientation": "orthogonal", "width": 2, "height": 2, "tilewidth": 8, "tileheight": 8,
+               "layers": [{{"type": "tilelayer", "name": "ground", {}}}]}}"#,
+            layer
+        );
+        let mut map = parse_map(&text, Path::new(""))?;
+        return match map.layers.pop() {
+            Some(TiledLayer::Tiles(layer)) => Ok(layer),
+            _ => Err("no tile layer".to_string()),
+        };
+    }
+
+    #[test]
+    fn arrays() {
+        let layer = parse_layer(r#""width": 2, "height": 2, "data": [1, 0, 3, 2147483652]"#).unwrap();
+        assert_eq!(layer.raw_tiles(), [1, 0, 3, 0x8000_0004]);
+
+        let error = parse_layer(r#""width": 2, "height": 2, "data": [1, 0, 3]"#).unwrap_err();
+        assert_eq!(error, "tiled: layer data has 3 tiles instead of 4");
+        assert!(parse_layer(r#""width": 2, "height": 2"#).is_err());
+    }
+
+    #[test]
+    fn chunks() {
+        let layer = parse_layer(
+            r#""chunks": [{"x": 1, "y": 1, "width": 1, "height": 1, "data": [7]},
+                          {"x": 0, "y": 0, "width": 2, "height": 1, "data": [5, 6]}]"#,
+        )
+        .unwrap();
+        assert_eq!((layer.x, layer.y, layer.width, layer.height), (0, 0, 2, 2));
+        assert_eq!(layer.raw_tiles(), [5, 6, 0, 7]);
+
+        assert!(parse_layer(r#""chunks": [{"x": 0, "y": 0, "width": 2, "height": 2, "data": [1, 2]}]"#).is_err());
+    }
+
+    #[test]
+    fn invalid_sizes() {
+        assert!(parse_layer(r#""width": -2, "height": -2, "data": [1, 2, 3, 4]"#).is_err());
+        assert!(parse_layer(r#""width": 0, "height": -3, "data": []"#).is_err());
+        assert!(parse_layer(r#""chunks": [{"x": 0, "y": 0, "width": -1, "height": -1, "data": [1]}]"#).is_err());
+    }
+}
@@ -0,0 +1,313 @@
+use super::{
+    Chunk, LayerParent, TiledLayer, TiledMap, TiledObject, TiledObjectLayer, TiledShape, TiledTile, TiledTileLayer,
+    TiledTileset, check_tiles, decode_tiles, image_columns, merge_chunks, resolve, tile_count,
+};
+use crate::xml::XmlElement;
+use crate::{Rectangle, Vector2};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub(super) fn parse_map(text: &str, directory: &Path) -> Result<TiledMap, String> {
+    let root = XmlElement::parse(text)?;
+    if root.name != "map" {
+        return Err("tiled: missing <map> element".to_string());
+    }
+    let orientation = root.attribute("orientation").unwrap_or("orthogonal");
+    if orientation != "orthogonal" {
+        return Err(format!("tiled: unsupported {} orientation", orientation));
+    }
+
+    let mut map = TiledMap {
+        width: int(&root, "width", 0),
+        height: int(&root, "height", 0),
+        tile_width: int(&root, "tilewidth", 0),
+        tile_height: int(&root, "tileheight", 0),
+        infinite: int(&root, "infinite", 0) != 0,
+        tilesets: Vec::new(),
+        layers: Vec::new(),
+        properties: properties(&root),
+    };
+    for element in root.children_named("tileset") {
+        let first_gid = int(element, "firstgid", 1) as u32;
+        let tileset = match element.attribute("source") {
+            Some(source) => load_tileset(&resolve(directory, source), first_gid)?,
+            None => parse_tileset(element, first_gid, directory),
+        };
+        map.tilesets.push(tileset);
+    }
+    map.tilesets.sort_by_key(|tileset| tileset.first_gid);
+    parse_layers(&root, LayerParent::ROOT, &mut map.layers)?;
+    return Ok(map);
+}
+
+/// Load an external `.tsx` tileset
+pub(super) fn load_tileset(filename: &str, first_gid: u32) -> Result<TiledTileset, String> {
+    let text = std::fs::read_to_string(filename).map_err(|e| format!("tiled: failed to read {}: {}", filename, e))?;
+    let root = XmlElement::parse(&text)?;
+    if root.name != "tileset" {
+        return Err(format!("tiled: missing <tileset> element in {}", filename));
+    }
+    let directory = Path::new(filename).parent().unwrap_or(Path::new(""));
+    return Ok(parse_tileset(&root, first_gid, directory));
+}
+
+fn parse_tileset(element: &XmlElement, first_gid: u32, directory: &Path) -> TiledTileset {
+    let tile_width = int(element, "tilewidth", 0);
+    let spacing = int(element, "spacing", 0);
+    let margin = int(element, "margin", 0);
+    let image = element.child("image");
+    let columns = match element.number("columns") {
+        Some(columns) => columns as i32,
+        None => image_columns(
+            image.map(|image| int(image, "width", 0)).unwrap_or(0),
+            tile_width,
+            margin,
+            spacing,
+        ),
+    };
+    let offset = element.child("tileoffset");
+    return TiledTileset {
+        first_gid,
+        name: element.attribute("name").unwrap_or_default().to_string(),
+        tile_width,
+        tile_height: int(element, "tileheight", 0),
+        spacing,
+        margin,
+        columns,
+        tile_count: int(element, "tilecount", 0) as u32,
+        offset: offset
+            .map(|offset| Vector2::new(float(offset, "x", 0.0), float(offset, "y", 0.0)))
+            .unwrap_or(Vector2::zero()),
+        image: image
+            .and_then(|image| image.attribute("source"))
+            .map(|source| resolve(directory, source))
+            .unwrap_or_default(),
+        texture: None,
+    };
+}
+
+fn parse_layers(parent: &XmlElement, inherited: LayerParent, layers: &mut Vec<TiledLayer>) -> Result<(), String> {
+    for element in &parent.children {
+        let state = inherited.child(
+            int(element, "visible", 1) != 0,
+            float(element, "opacity", 1.0),
+            Vector2::new(float(element, "offsetx", 0.0), float(element, "offsety", 0.0)),
+        );
+        match element.name.as_str() {
+            "layer" => layers.push(TiledLayer::Tiles(parse_tile_layer(element, state)?)),
+            "objectgroup" => layers.push(TiledLayer::Objects(parse_object_layer(element, state))),
+            "group" => parse_layers(element, state, layers)?,
+            _ => {}
+        }
+    }
+    return Ok(());
+}
+
+fn parse_tile_layer(element: &XmlElement, state: LayerParent) -> Result<TiledTileLayer, String> {
+    let data = element.child("data").ok_or("tiled: tile layer without data")?;
+    let encoding = data.attribute("encoding");
+    let compression = data.attribute("compression");
+    let read = |element: &XmlElement, count: usize| {
+        // NOTE: Data without encoding lists every tile as a <tile> element
+        if encoding.is_none() {
+            let tiles: Vec<u32> = element
+                .children_named("tile")
+                .map(|tile| tile.number("gid").map(|gid| gid as u32).unwrap_or(0))
+                .collect();
+            check_tiles(&tiles, count)?;
+            return Ok(tiles);
+        }
+        return decode_tiles(&element.text, encoding, compression, count);
+    };
+
+    let chunks: Vec<&XmlElement> = data.children_named("chunk").collect();
+    let (x, y, width, height, tiles) = if chunks.is_empty() {
+        let (width, height) = (int(element, "width", 0), int(element, "height", 0));
+        let count = tile_count(width, height)?;
+        (0, 0, width, height, read(data, count)?)
+    } else {
+        let mut merged = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            let (width, height) = (int(chunk, "width", 0), int(chunk, "height", 0));
+            let tiles = read(chunk, tile_count(width, height)?)?;
+            merged.push(Chunk {
+                x: int(chunk, "x", 0),
+                y: int(chunk, "y", 0),
+                width,
+                height,
+                tiles,
+            });
+        }
+        merge_chunks(merged)?
+    };
+
+    return Ok(TiledTileLayer {
+        name: element.attribute("name").unwrap_or_default().to_string(),
+        visible: state.visible,
+        opacity: state.opacity,
+        offset: state.offset,
+        x,
+        y,
+        width,
+        height,
+        tiles,
+    });
+}
+
+fn parse_object_layer(element: &XmlElement, state: LayerParent) -> TiledObjectLayer {
+    return TiledObjectLayer {
+        name: element.attribute("name").unwrap_or_default().to_string(),
+        visible: state.visible,
+        opacity: state.opacity,
+        offset: state.offset,
+        objects: element
+            .children_named("object")
+            .map(|object| parse_object(object, state.offset))
+            .collect(),
+    };
+}
+
+fn parse_object(element: &XmlElement, offset: Vector2) -> TiledObject {
+    let position = offset + Vector2::new(float(element, "x", 0.0), float(element, "y", 0.0));
+    let (width, height) = (float(element, "width", 0.0), float(element, "height", 0.0));
+    let tile = element.number("gid").and_then(|gid| TiledTile::from_raw(gid as u32));
+    let points = |name: &str| {
+        element
+            .child(name)
+            .map(|shape| parse_points(shape.attribute("points").unwrap_or_default(), position))
+    };
+
+    let shape = if tile.is_some() {
+        // NOTE: Tile objects are anchored at their bottom-left corner
+        TiledShape::Rectangle(Rectangle::new(position.x, position.y - height, width, height))
+    } else if element.child("ellipse").is_some() {
+        TiledShape::Ellipse(Rectangle::new(position.x, position.y, width, height))
+    } else if element.child("point").is_some() {
+        TiledShape::Point(position)
+    } else if let Some(points) = points("polygon") {
+        TiledShape::Polygon(points)
+    } else if let Some(points) = points("polyline") {
+        TiledShape::Polyline(points)
+    } else {
+        TiledShape::Rectangle(Rectangle::new(position.x, position.y, width, height))
+    };
+
+    return TiledObject {
+        id: int(element, "id", 0) as u32,
+        name: element.attribute("name").unwrap_or_default().to_string(),
+        class: element
+            .attribute("class")
+            .or(element.attribute("type"))
+            .unwrap_or_default()
+            .to_string(),
+        position,
+        rotation: float(element, "rotation", 0.0),
+        visible: int(element, "visible", 1) != 0,
+        tile,
+        shape,
+        properties: properties(element),
+    };
+}
+
+/// `"x,y x,y ..."` points relative to `origin`
+fn parse_points(text: &str, origin: Vector2) -> Vec<Vector2> {
+    return text
+        .split_whitespace()
+        .filter_map(|point| {
+            let (x, y) = point.split_once(',')?;
+            return Some(origin + Vector2::new(x.trim().parse().ok()?, y.trim().parse().ok()?));
+        })
+        .collect();
+}
+
+/// Custom properties as text, multiline strings are stored as the element text
+fn properties(element: &XmlElement) -> BTreeMap<String, String> {
+    let mut properties = BTreeMap::new();
+    for property in element
+        .child("properties")
+        .iter()
+        .flat_map(|properties| properties.children_named("property"))
+    {
+        let Some(name) = property.attribute("name") else {
+            continue;
+        };
+        let value = property
+            .attribute("value")
+            .map(str::to_string)
+            .unwrap_or_else(|| property.text.clone());
+        properties.insert(name.to_string(), value);
+    }
+    return properties;
+}
+
+fn int(element: &XmlElement, name: &str, default: i32) -> i32 {
+    return element.number(name).map(|value| value as i32).unwrap_or(default);
+}
+
+fn float(element: &XmlElement, name: &str, default: f32) -> f32 {
+    return element.number(name).map(|value| value as f32).unwrap_or(default);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_layer(attributes: &str, data: &str) -> Result<TiledTileLayer, String> {
+        let text = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<map orientation="orthogonal" width="2" height="2" tilewidth="8" tileheight="8">
+ <layer name="ground" {}>
+  {}
+ </layer>
+</map>"#,
+            attributes, data
+        );
+        let mut map = parse_map(&text, Path::new(""))?;
+        return match map.layers.pop() {
+            Some(TiledLayer::Tiles(layer)) => Ok(layer),
+            _ => Err("no tile layer".to_string()),
+        };
+    }
+
+    #[test]
+    fn tile_elements() {
+        let data = r#"<data><tile gid="1"/><tile/><tile gid="3"/><tile gid="2147483652"/></data>"#;
+        let layer = parse_layer(r#"width="2" height="2""#, data).unwrap();
+        assert_eq!(layer.raw_tiles(), [1, 0, 3, 0x8000_0004]);
+        assert_eq!(
+            layer.tile(1, 1).map(|tile| (tile.gid, tile.flip_horizontal)),
+            Some((4, true))
+        );
+        assert_eq!(layer.tile(0, 1).map(|tile| tile.gid), Some(3));
+        assert_eq!(layer.tile(1, 0), None);
+        assert_eq!(layer.tile(2, 0), None);
+
+        let error = parse_layer(r#"width="2" height="2""#, r#"<data><tile gid="1"/></data>"#).unwrap_err();
+        assert_eq!(error, "tiled: layer data has 1 tiles instead of 4");
+    }
+
+    #[test]
+    fn chunks() {
+        let data = r#"<data encoding="csv">
+   <chunk x="-2" y="0" width="2" height="1">1,2</chunk>
+   <chunk x="0" y="-1" width="1" height="2">3,
+4</chunk>
+  </data>"#;
+        let layer = parse_layer(r#"width="2" height="2""#, data).unwrap();
+        assert_eq!((layer.x, layer.y, layer.width, layer.height), (-2, -1, 3, 2));
+        assert_eq!(layer.raw_tiles(), [0, 0, 3, 1, 2, 4]);
+        assert_eq!(layer.tile(-1, 0).map(|tile| tile.gid), Some(2));
+
+        let data = r#"<data><chunk x="0" y="0" width="2" height="1"><tile gid="1"/></chunk></data>"#;
+        assert!(parse_layer("", data).is_err());
+    }
+
+    #[test]
+    fn invalid_sizes() {
+        assert!(parse_layer(r#"width="-2" height="-2""#, r#"<data encoding="csv">1,2,3,4</data>"#).is_err());
+        assert!(parse_layer(r#"width="-1" height="0""#, "<data/>").is_err());
+        let data = r#"<data encoding="csv"><chunk x="0" y="0" width="-1" height="-1">1</chunk></data>"#;
+        assert!(parse_layer("", data).is_err());
+        assert!(parse_layer(r#"width="1" height="1""#, "").is_err());
+    }
+}
@@ -0,0 +1,628 @@
+use crate::{
+    Color, Rectangle, RenderTexture2D, Texture2D, Vector2, begin_texture_mode, clear_background, end_texture_mode,
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// Neighbour cell offsets: north, north-east, east, south-east, south, south-west, west, north-west
+const NEIGHBOURS: [(i32, i32); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Tile cycling through other tiles of the tileset
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileAnimation {
+    /// Tile id and duration in seconds of every frame
+    pub frames: Vec<(u32, f32)>,
+}
+
+impl TileAnimation {
+    pub fn new(frames: Vec<(u32, f32)>) -> Self {
+        return Self { frames };
+    }
+
+    /// Tile shown `time` seconds after the animation started, looping
+    pub fn tile_at(&self, time: f32) -> u32 {
+        let duration: f32 = self.frames.iter().map(|(_, duration)| duration).sum();
+        let Some(&(first, _)) = self.frames.first() else {
+            return TileMap::EMPTY;
+        };
+        if duration <= 0.0 {
+            return first;
+        }
+        let mut time = time.rem_euclid(duration);
+        for &(tile, duration) in &self.frames {
+            if time < duration {
+                return tile;
+            }
+            time -= duration;
+        }
+        return first;
+    }
+}
+
+/// Tileset image cut in a regular grid, tile ids start at 1 for the top-left tile
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileSet {
+    pub texture: Texture2D,
+    pub tile_width: i32,
+    pub tile_height: i32,
+    pub margin: i32,
+    pub spacing: i32,
+    columns: i32,
+    animations: HashMap<u32, TileAnimation>,
+}
+
+impl TileSet {
+    pub fn new(texture: Texture2D, tile_width: i32, tile_height: i32) -> Self {
+        return Self::from_grid(texture, tile_width, tile_height, 0, 0);
+    }
+
+    /// Tiles of `tile_width`x`tile_height` with `margin` pixels around the image and `spacing` pixels between tiles
+    pub fn from_grid(texture: Texture2D, tile_width: i32, tile_height: i32, margin: i32, spacing: i32) -> Self {
+        let columns = ((texture.width() - margin * 2 + spacing) / (tile_width + spacing).max(1)).max(1);
+        return Self {
+            texture,
+            tile_width,
+            tile_height,
+            margin,
+            spacing,
+            columns,
+            animations: HashMap::new(),
+        };
+    }
+
+    /// Area of the image holding `tile`
+    pub fn source_rect(&self, tile: u32) -> Rectangle {
+        let index = tile.saturating_sub(1) as i32;
+        let x = self.margin + (index % self.columns) * (self.tile_width + self.spacing);
+        let y = self.margin + (index / self.columns) * (self.tile_height + self.spacing);
+        return Rectangle::new(x as f32, y as f32, self.tile_width as f32, self.tile_height as f32);
+    }
+
+    /// Animate every cell holding `tile`
+    pub fn set_animation(&mut self, tile: u32, animation: TileAnimation) {
+        self.animations.insert(tile, animation);
+    }
+
+    pub fn remove_animation(&mut self, tile: u32) {
+        self.animations.remove(&tile);
+    }
+
+    pub fn animation(&self, tile: u32) -> Option<&TileAnimation> {
+        return self.animations.get(&tile);
+    }
+}
+
+/// Tile selection from the terrain of the neighbour cells
+#[derive(Debug, Clone, PartialEq)]
+pub enum AutotileRule {
+    /// Tile for every 4-neighbour mask of cells with the same terrain (north 1, east 2, south 4, west 8)
+    Bitmask4([u32; 16]),
+    /// Tile for 8-neighbour masks of cells with the same terrain (north 1, north-east 2, east 4, south-east 8,
+    /// south 16, south-west 32, west 64, north-west 128). Corners only count when both adjacent edges match,
+    /// which leaves 47 masks. Masks without a tile use `fallback`.
+    Bitmask8 { tiles: BTreeMap<u8, u32>, fallback: u32 },
+    /// Tiles with a Wang id: the terrain expected at the top, top-right, right, bottom-right, bottom, bottom-left,
+    /// left and top-left neighbours, 0 matches any terrain. The tile matching the neighbours best is used.
+    Wang(Vec<([u32; 8], u32)>),
+}
+
+impl AutotileRule {
+    /// Tile for a cell of `terrain` surrounded by `neighbours` terrains
+    fn resolve(&self, terrain: u32, neighbours: [u32; 8]) -> u32 {
+        let matches = neighbours.map(|neighbour| neighbour == terrain);
+        match self {
+            AutotileRule::Bitmask4(tiles) => {
+                let mask = (matches[0] as usize)
+                    | (matches[2] as usize) << 1
+                    | (matches[4] as usize) << 2
+                    | (matches[6] as usize) << 3;
+                return tiles[mask];
+            }
+            AutotileRule::Bitmask8 { tiles, fallback } => {
+                let mut mask = 0u8;
+                for (i, &matching) in matches.iter().enumerate() {
+                    // NOTE: Odd directions are corners, kept only when both neighbouring edges match
+                    let counts = if i % 2 == 0 {
+                        matching
+                    } else {
+                        matching && matches[i - 1] && matches[(i + 1) % 8]
+                    };
+                    if counts {
+                        mask |= 1 << i;
+                    }
+                }
+                return tiles.get(&mask).copied().unwrap_or(*fallback);
+            }
+            AutotileRule::Wang(tiles) => {
+                let score = |id: &[u32; 8]| -> i32 {
+                    return id
+                        .iter()
+                        .zip(neighbours)
+                        .map(|(&expected, actual)| match expected {
+                            0 => 0,
+                            _ if expected == actual => 1,
+                            _ => -1,
+                        })
+                        .sum();
+                };
+                let mut best: Option<(i32, u32)> = None;
+                for (id, tile) in tiles {
+                    let score = score(id);
+                    if best.is_none_or(|(best_score, _)| score > best_score) {
+                        best = Some((score, *tile));
+                    }
+                }
+                return best.map(|(_, tile)| tile).unwrap_or(TileMap::EMPTY);
+            }
+        }
+    }
+}
+
+/// Layer of a tile map
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileMapLayer {
+    pub name: String,
+    pub visible: bool,
+}
+
+/// One layer of a chunk with its baked static tiles
+#[derive(Debug)]
+struct ChunkLayer {
+    /// Tiles row by row
+    tiles: Vec<u32>,
+    /// Painted terrain, 0 where tiles were set directly
+    terrains: Vec<u32>,
+    /// Baked static tiles, `None` when the layer only holds empty or animated cells
+    texture: Option<RenderTexture2D>,
+    dirty: bool,
+    /// Local cell and tile of the animated cells, drawn over the baked texture
+    animated: Vec<(i32, i32, u32)>,
+}
+
+impl ChunkLayer {
+    fn new(cells: usize) -> Self {
+        return Self {
+            tiles: vec![TileMap::EMPTY; cells],
+            terrains: vec![0; cells],
+            texture: None,
+            dirty: true,
+            animated: Vec::new(),
+        };
+    }
+
+    fn unload(&mut self) {
+        if let Some(texture) = self.texture.take() {
+            texture.unload();
+        }
+        self.dirty = true;
+    }
+}
+
+/// Square region of the map
+#[derive(Debug)]
+struct Chunk {
+    layers: Vec<ChunkLayer>,
+}
+
+/// Unbounded tile map split into chunks.
+///
+/// The static tiles of every chunk layer are baked into a render texture, re-baked by `update()` when the layer
+/// changes. Layers are drawn in order, each with its animated tiles over its baked chunks, so animated tiles stay
+/// below the layers above them.
+#[derive(Debug)]
+pub struct TileMap {
+    tileset: TileSet,
+    chunk_size: i32,
+    layers: Vec<TileMapLayer>,
+    chunks: HashMap<(i32, i32), Chunk>,
+    rules: BTreeMap<u32, AutotileRule>,
+    time: f32,
+}
+
+impl TileMap {
+    /// Tile id of an empty cell
+    pub const EMPTY: u32 = 0;
+
+    /// Map of `chunk_size`x`chunk_size` tile chunks with one layer
+    pub fn new(tileset: TileSet, chunk_size: i32) -> Self {
+        return Self {
+            tileset,
+            chunk_size: chunk_size.max(1),
+            layers: vec![TileMapLayer {
+                name: String::new(),
+                visible: true,
+            }],
+            chunks: HashMap::new(),
+            rules: BTreeMap::new(),
+            time: 0.0,
+        };
+    }
+
+    pub fn tileset(&self) -> &TileSet {
+        return &self.tileset;
+    }
+
+    /// Mutable tileset, every chunk is re-baked since tile animations may change
+    pub fn tileset_mut(&mut self) -> &mut TileSet {
+        self.invalidate();
+        return &mut self.tileset;
+    }
+
+    pub fn chunk_size(&self) -> i32 {
+        return self.chunk_size;
+    }
+
+    // Layers
+
+    /// Add a layer on top of the others, returns its index
+    pub fn add_layer(&mut self, name: &str) -> usize {
+        self.layers.push(TileMapLayer {
+            name: name.to_string(),
+            visible: true,
+        });
+        let cells = (self.chunk_size * self.chunk_size) as usize;
+        for chunk in self.chunks.values_mut() {
+            chunk.layers.push(ChunkLayer::new(cells));
+        }
+        return self.layers.len() - 1;
+    }
+
+    pub fn layers(&self) -> &[TileMapLayer] {
+        return &self.layers;
+    }
+
+    pub fn layer_index(&self, name: &str) -> Option<usize> {
+        return self.layers.iter().position(|layer| layer.name == name);
+    }
+
+    /// Show or hide a layer, hidden layers stay baked
+    pub fn set_layer_visible(&mut self, layer: usize, visible: bool) {
+        if let Some(entry) = self.layers.get_mut(layer) {
+            entry.visible = visible;
+        }
+    }
+
+    // Tiles
+
+    /// Tile at cell `x`, `y` of `layer`
+    pub fn tile(&self, layer: usize, x: i32, y: i32) -> u32 {
+        let (key, index) = self.locate(x, y);
+        return self
+            .chunks
+            .get(&key)
+            .and_then(|chunk| chunk.layers.get(layer))
+            .map(|layer| layer.tiles[index])
+            .unwrap_or(Self::EMPTY);
+    }
+
+    /// Set a tile, clearing the painted terrain of the cell
+    pub fn set_tile(&mut self, layer: usize, x: i32, y: i32, tile: u32) {
+        self.write(layer, x, y, tile, 0);
+    }
+
+    /// Terrain painted at cell `x`, `y` of `layer`, 0 if none
+    pub fn terrain(&self, layer: usize, x: i32, y: i32) -> u32 {
+        let (key, index) = self.locate(x, y);
+        return self
+            .chunks
+            .get(&key)
+            .and_then(|chunk| chunk.layers.get(layer))
+            .map(|layer| layer.terrains[index])
+            .unwrap_or(0);
+    }
+
+    /// Autotiling rule for cells painted with `terrain`
+    pub fn set_autotile(&mut self, terrain: u32, rule: AutotileRule) {
+        self.rules.insert(terrain, rule);
+    }
+
+    /// Paint a terrain (0 to erase) and update the tiles of the cell and its neighbours with the autotiling rules
+    pub fn paint(&mut self, layer: usize, x: i32, y: i32, terrain: u32) {
+        if layer >= self.layers.len() {
+            return;
+        }
+        let tile = if terrain == 0 {
+            Self::EMPTY
+        } else {
+            self.tile(layer, x, y)
+        };
+        self.write(layer, x, y, tile, terrain);
+        self.autotile(layer, x, y);
+        for (dx, dy) in NEIGHBOURS {
+            self.autotile(layer, x + dx, y + dy);
+        }
+    }
+
+    /// Resolve the tile of a painted cell from its neighbours
+    fn autotile(&mut self, layer: usize, x: i32, y: i32) {
+        let terrain = self.terrain(layer, x, y);
+        let Some(rule) = self.rules.get(&terrain) else {
+            return;
+        };
+        let neighbours = NEIGHBOURS.map(|(dx, dy)| self.terrain(layer, x + dx, y + dy));
+        let tile = rule.resolve(terrain, neighbours);
+        self.write(layer, x, y, tile, terrain);
+    }
+
+    /// Chunk key and cell index of cell `x`, `y`
+    fn locate(&self, x: i32, y: i32) -> ((i32, i32), usize) {
+        let size = self.chunk_size;
+        let key = (x.div_euclid(size), y.div_euclid(size));
+        return (key, (y.rem_euclid(size) * size + x.rem_euclid(size)) as usize);
+    }
+
+    fn write(&mut self, layer: usize, x: i32, y: i32, tile: u32, terrain: u32) {
+        if layer >= self.layers.len() {
+            return;
+        }
+        let (key, index) = self.locate(x, y);
+        let cells = (self.chunk_size * self.chunk_size) as usize;
+        let layer_count = self.layers.len();
+        let chunk = self.chunks.entry(key).or_insert_with(|| Chunk {
+            layers: (0..layer_count).map(|_| ChunkLayer::new(cells)).collect(),
+        });
+        let entry = &mut chunk.layers[layer];
+        if entry.tiles[index] != tile || entry.terrains[index] != terrain {
+            entry.tiles[index] = tile;
+            entry.terrains[index] = terrain;
+            entry.dirty = true;
+        }
+    }
+
+    // Rendering
+
+    /// Re-bake every chunk the next time it is in view
+    pub fn invalidate(&mut self) {
+        for layer in self.chunks.values_mut().flat_map(|chunk| chunk.layers.iter_mut()) {
+            layer.dirty = true;
+        }
+    }
+
+    /// Release the baked chunk textures, they are baked again when in view
+    pub fn unload(&mut self) {
+        for layer in self.chunks.values_mut().flat_map(|chunk| chunk.layers.iter_mut()) {
+            layer.unload();
+        }
+    }
+
+    /// Advance the tile animations and bake the changed chunks in the world area `view`, usually
+    /// `camera.visible_area(viewport)`. Call outside of any camera or texture mode.
+    pub fn update(&mut self, delta_time: f32, view: Rectangle) {
+        self.time += delta_time;
+        for key in self.chunks_in_view(view) {
+            for layer in 0..self.layers.len() {
+                if self.chunks[&key].layers[layer].dirty {
+                    self.bake(key, layer);
+                }
+            }
+        }
+    }
+
+    /// Draw the visible layers of the chunks in the world area `view`, call between `camera.begin_mode()` and
+    /// `camera.end_mode()`
+    pub fn draw(&self, view: Rectangle) {
+        let (chunk_width, chunk_height) = self.chunk_pixel_size();
+        let (tile_width, tile_height) = (self.tileset.tile_width as f32, self.tileset.tile_height as f32);
+        let keys = self.chunks_in_view(view);
+        for (layer, entry) in self.layers.iter().enumerate() {
+            if !entry.visible {
+                continue;
+            }
+            for key in &keys {
+                let chunk = &self.chunks[key].layers[layer];
+                let position = Vector2::new(key.0 as f32 * chunk_width, key.1 as f32 * chunk_height);
+                if let Some(texture) = chunk.texture {
+                    // NOTE: Render textures are stored upside down
+                    let source = Rectangle::new(0.0, 0.0, chunk_width, -chunk_height);
+                    texture.texture().draw_rec(source, position, Color::WHITE);
+                }
+                for &(x, y, tile) in &chunk.animated {
+                    let Some(animation) = self.tileset.animation(tile) else {
+                        continue;
+                    };
+                    let source = self.tileset.source_rect(animation.tile_at(self.time));
+                    let cell = position + Vector2::new(x as f32 * tile_width, y as f32 * tile_height);
+                    self.tileset.texture.draw_rec(source, cell, Color::WHITE);
+                }
+            }
+        }
+    }
+
+    fn chunk_pixel_size(&self) -> (f32, f32) {
+        return (
+            (self.chunk_size * self.tileset.tile_width) as f32,
+            (self.chunk_size * self.tileset.tile_height) as f32,
+        );
+    }
+
+    /// Keys of the existing chunks overlapping the world area `view`
+    fn chunks_in_view(&self, view: Rectangle) -> Vec<(i32, i32)> {
+        let (chunk_width, chunk_height) = self.chunk_pixel_size();
+        let first_x = (view.x / chunk_width).floor() as i32;
+        let first_y = (view.y / chunk_height).floor() as i32;
+        let last_x = ((view.x + view.width) / chunk_width).floor() as i32;
+        let last_y = ((view.y + view.height) / chunk_height).floor() as i32;
+
+        // NOTE: Zoomed out views may span more chunk cells than there are chunks
+        let cells = (last_x - first_x + 1) as i64 * (last_y - first_y + 1) as i64;
+        if cells > self.chunks.len() as i64 {
+            return self
+                .chunks
+                .keys()
+                .filter(|&&(x, y)| x >= first_x && x <= last_x && y >= first_y && y <= last_y)
+                .copied()
+                .collect();
+        }
+        let mut keys = Vec::new();
+        for y in first_y..=last_y {
+            for x in first_x..=last_x {
+                if self.chunks.contains_key(&(x, y)) {
+                    keys.push((x, y));
+                }
+            }
+        }
+        return keys;
+    }
+
+    /// Draw the static tiles of a chunk layer into its texture, released when the layer has none
+    fn bake(&mut self, key: (i32, i32), layer: usize) {
+        let (chunk_width, chunk_height) = self.chunk_pixel_size();
+        let size = self.chunk_size;
+        let tileset = &self.tileset;
+        let Some(chunk) = self.chunks.get_mut(&key).and_then(|chunk| chunk.layers.get_mut(layer)) else {
+            return;
+        };
+
+        chunk.animated.clear();
+        let mut cells = Vec::new();
+        for (index, &tile) in chunk.tiles.iter().enumerate() {
+            if tile == Self::EMPTY {
+                continue;
+            }
+            let (x, y) = (index as i32 % size, index as i32 / size);
+            if tileset.animation(tile).is_some() {
+                chunk.animated.push((x, y, tile));
+            } else {
+                cells.push((x, y, tile));
+            }
+        }
+        if cells.is_empty() {
+            chunk.unload();
+            chunk.dirty = false;
+            return;
+        }
+
+        let texture = *chunk
+            .texture
+            .get_or_insert_with(|| RenderTexture2D::load(chunk_width as i32, chunk_height as i32));
+        begin_texture_mode(texture);
+        clear_background(Color::BLANK);
+        for (x, y, tile) in cells {
+            let position = Vector2::new((x * tileset.tile_width) as f32, (y * tileset.tile_height) as f32);
+            tileset
+                .texture
+                .draw_rec(tileset.source_rect(tile), position, Color::WHITE);
+        }
+        end_texture_mode();
+        chunk.dirty = false;
+    }
+}
+
+impl Drop for TileMap {
+    fn drop(&mut self) {
+        self.unload();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PixelFormat, Texture};
+
+    fn tile_map(chunk_size: i32) -> TileMap {
+        let format = PixelFormat::UncompressedR8g8b8a8;
+        let texture = Texture2D(Texture {
+            id: 0,
+            width: 64,
+            height: 64,
+            mipmaps: 1,
+            format,
+        });
+        return TileMap::new(TileSet::new(texture, 16, 16), chunk_size);
+    }
+
+    /// Tile 100 + mask for every 4-neighbour mask
+    fn bitmask4() -> AutotileRule {
+        return AutotileRule::Bitmask4(std::array::from_fn(|mask| 100 + mask as u32));
+    }
+
+    #[test]
+    fn bitmask4_ignores_corners() {
+        // North and south match, east is empty, west is another terrain, corners match
+        assert_eq!(bitmask4().resolve(1, [1, 1, 0, 1, 1, 1, 2, 1]), 105);
+        assert_eq!(bitmask4().resolve(1, [1; 8]), 115);
+        assert_eq!(bitmask4().resolve(1, [0; 8]), 100);
+    }
+
+    #[test]
+    fn bitmask8_corners_need_both_edges() {
+        let tiles = BTreeMap::from([(255, 50), (7, 51), (4, 52)]);
+        let rule = AutotileRule::Bitmask8 { tiles, fallback: 9 };
+        assert_eq!(rule.resolve(1, [1; 8]), 50);
+        // North, north-east and east
+        assert_eq!(rule.resolve(1, [1, 1, 1, 0, 0, 0, 0, 0]), 51);
+        // The north-east corner does not count without the north edge
+        assert_eq!(rule.resolve(1, [0, 1, 1, 0, 0, 0, 0, 0]), 52);
+        // Mask 1 (north only) has no tile
+        assert_eq!(rule.resolve(1, [1, 1, 0, 0, 0, 0, 0, 0]), 9);
+    }
+
+    #[test]
+    fn wang_picks_the_best_match() {
+        let rule = AutotileRule::Wang(vec![
+            ([1, 0, 0, 0, 0, 0, 0, 0], 10),
+            ([1, 0, 1, 0, 0, 0, 0, 0], 11),
+            ([2, 0, 0, 0, 0, 0, 0, 0], 12),
+        ]);
+        assert_eq!(rule.resolve(1, [1, 0, 1, 0, 0, 0, 0, 0]), 11);
+        // Mismatches count against a tile, ties keep the first tile
+        assert_eq!(rule.resolve(1, [1, 0, 3, 0, 0, 0, 0, 0]), 10);
+        assert_eq!(rule.resolve(1, [2, 0, 0, 0, 0, 0, 0, 0]), 12);
+        assert_eq!(rule.resolve(1, [0; 8]), 10);
+        assert_eq!(AutotileRule::Wang(Vec::new()).resolve(1, [1; 8]), TileMap::EMPTY);
+    }
+
+    #[test]
+    fn paint_updates_neighbours() {
+        let mut map = tile_map(2);
+        map.set_autotile(1, bitmask4());
+
+        map.paint(0, 0, 0, 1);
+        assert_eq!(map.tile(0, 0, 0), 100);
+
+        // East neighbour, then west neighbour in another chunk
+        map.paint(0, 1, 0, 1);
+        assert_eq!((map.tile(0, 0, 0), map.tile(0, 1, 0)), (102, 108));
+        map.paint(0, -1, 0, 1);
+        assert_eq!((map.tile(0, -1, 0), map.tile(0, 0, 0)), (102, 110));
+
+        // Erasing re-autotiles the neighbours
+        map.paint(0, 0, 0, 0);
+        assert_eq!((map.tile(0, 0, 0), map.terrain(0, 0, 0)), (TileMap::EMPTY, 0));
+        assert_eq!((map.tile(0, -1, 0), map.tile(0, 1, 0)), (100, 100));
+
+        // Tiles set directly clear the terrain and layers autotile separately
+        let layer = map.add_layer("top");
+        map.paint(layer, 0, 0, 1);
+        assert_eq!((map.tile(layer, 0, 0), map.tile(0, 1, 0)), (100, 100));
+        map.set_tile(0, 1, 0, 7);
+        assert_eq!((map.tile(0, 1, 0), map.terrain(0, 1, 0)), (7, 0));
+        map.paint(0, 2, 0, 1);
+        assert_eq!(map.tile(0, 2, 0), 100);
+    }
+
+    #[test]
+    fn chunks_in_view() {
+        // Chunks of 4x4 tiles of 16 pixels
+        let mut map = tile_map(4);
+        for (x, y) in [(0, 0), (4, 0), (-1, -1), (20, 20)] {
+            map.set_tile(0, x, y, 1);
+        }
+        map.set_tile(3, 100, 100, 1);
+        assert_eq!(map.chunks.len(), 4);
+
+        assert_eq!(
+            map.chunks_in_view(Rectangle::new(0.0, 0.0, 100.0, 10.0)),
+            [(0, 0), (1, 0)]
+        );
+        assert_eq!(
+            map.chunks_in_view(Rectangle::new(-10.0, -10.0, 20.0, 20.0)),
+            [(-1, -1), (0, 0)]
+        );
+        assert!(map.chunks_in_view(Rectangle::new(200.0, 0.0, 50.0, 50.0)).is_empty());
+
+        // Views larger than the map only look at the existing chunks
+        let mut keys = map.chunks_in_view(Rectangle::new(-1e6, -1e6, 2e6, 2e6));
+        keys.sort();
+        assert_eq!(keys, [(-1, -1), (0, 0), (1, 0), (5, 5)]);
+    }
+}
@@ -0,0 +1,922 @@
+use crate::{Color, Rectangle, Vector2, Vector3, color_lerp, get_frame_time};
+use std::cell::RefCell;
+use std::f32::consts::PI;
+use std::rc::Rc;
+
+/// Easing curves, the Robert Penner set plus CSS style cubic bezier curves
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    SineIn,
+    SineOut,
+    SineInOut,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// Cubic bezier from (0, 0) to (1, 1) with control points (x1, y1) and (x2, y2), x values in 0.0..=1.0
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Eased progress for `t` between 0.0 and 1.0, back and elastic curves overshoot that range
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        const BACK: f32 = 1.70158;
+        const BACK_IN_OUT: f32 = BACK * 1.525;
+        const ELASTIC: f32 = 2.0 * PI / 3.0;
+        const ELASTIC_IN_OUT: f32 = 2.0 * PI / 4.5;
+        match *self {
+            Easing::Linear => return t,
+            Easing::SineIn => return 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => return (t * PI / 2.0).sin(),
+            Easing::SineInOut => return -((PI * t).cos() - 1.0) / 2.0,
+            Easing::QuadIn => return power_in(t, 2),
+            Easing::QuadOut => return power_out(t, 2),
+            Easing::QuadInOut => return power_in_out(t, 2),
+            Easing::CubicIn => return power_in(t, 3),
+            Easing::CubicOut => return power_out(t, 3),
+            Easing::CubicInOut => return power_in_out(t, 3),
+            Easing::QuartIn => return power_in(t, 4),
+            Easing::QuartOut => return power_out(t, 4),
+            Easing::QuartInOut => return power_in_out(t, 4),
+            Easing::QuintIn => return power_in(t, 5),
+            Easing::QuintOut => return power_out(t, 5),
+            Easing::QuintInOut => return power_in_out(t, 5),
+            Easing::ExpoIn => return if t == 0.0 { 0.0 } else { 2f32.powf(10.0 * t - 10.0) },
+            Easing::ExpoOut => return if t == 1.0 { 1.0 } else { 1.0 - 2f32.powf(-10.0 * t) },
+            Easing::ExpoInOut => {
+                if t == 0.0 || t == 1.0 {
+                    return t;
+                }
+                if t < 0.5 {
+                    return 2f32.powf(20.0 * t - 10.0) / 2.0;
+                }
+                return (2.0 - 2f32.powf(-20.0 * t + 10.0)) / 2.0;
+            }
+            Easing::CircIn => return 1.0 - (1.0 - t * t).sqrt(),
+            Easing::CircOut => return (1.0 - (t - 1.0) * (t - 1.0)).sqrt(),
+            Easing::CircInOut => {
+                if t < 0.5 {
+                    return (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) / 2.0;
+                }
+                return ((1.0 - (-2.0 * t + 2.0).powi(2)).sqrt() + 1.0) / 2.0;
+            }
+            Easing::BackIn => return (BACK + 1.0) * t * t * t - BACK * t * t,
+            Easing::BackOut => return 1.0 + (BACK + 1.0) * (t - 1.0).powi(3) + BACK * (t - 1.0).powi(2),
+            Easing::BackInOut => {
+                if t < 0.5 {
+                    return (2.0 * t).powi(2) * ((BACK_IN_OUT + 1.0) * 2.0 * t - BACK_IN_OUT) / 2.0;
+                }
+                return ((2.0 * t - 2.0).powi(2) * ((BACK_IN_OUT + 1.0) * (2.0 * t - 2.0) + BACK_IN_OUT) + 2.0) / 2.0;
+            }
+            Easing::ElasticIn => {
+                if t == 0.0 || t == 1.0 {
+                    return t;
+                }
+                return -2f32.powf(10.0 * t - 10.0) * ((10.0 * t - 10.75) * ELASTIC).sin();
+            }
+            Easing::ElasticOut => {
+                if t == 0.0 || t == 1.0 {
+                    return t;
+                }
+                return 2f32.powf(-10.0 * t) * ((10.0 * t - 0.75) * ELASTIC).sin() + 1.0;
+            }
+            Easing::ElasticInOut => {
+                if t == 0.0 || t == 1.0 {
+                    return t;
+                }
+                let wave = ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin();
+                if t < 0.5 {
+                    return -(2f32.powf(20.0 * t - 10.0) * wave) / 2.0;
+                }
+                return 2f32.powf(-20.0 * t + 10.0) * wave / 2.0 + 1.0;
+            }
+            Easing::BounceIn => return 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => return bounce_out(t),
+            Easing::BounceInOut => {
+                if t < 0.5 {
+                    return (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0;
+                }
+                return (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0;
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) => return cubic_bezier(t, x1, y1, x2, y2),
+        }
+    }
+}
+
+fn power_in(t: f32, power: i32) -> f32 {
+    return t.powi(power);
+}
+
+fn power_out(t: f32, power: i32) -> f32 {
+    return 1.0 - (1.0 - t).powi(power);
+}
+
+fn power_in_out(t: f32, power: i32) -> f32 {
+    if t < 0.5 {
+        return 2f32.powi(power - 1) * t.powi(power);
+    }
+    return 1.0 - (-2.0 * t + 2.0).powi(power) / 2.0;
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        return N * t * t;
+    }
+    if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        return N * t * t + 0.75;
+    }
+    if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        return N * t * t + 0.9375;
+    }
+    let t = t - 2.625 / D;
+    return N * t * t + 0.984375;
+}
+
+/// Bezier y value where the curve x equals `x`
+fn cubic_bezier(x: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+    let curve = |s: f32, p1: f32, p2: f32| -> f32 {
+        let inverse = 1.0 - s;
+        return 3.0 * inverse * inverse * s * p1 + 3.0 * inverse * s * s * p2 + s * s * s;
+    };
+    let slope = |s: f32, p1: f32, p2: f32| -> f32 {
+        let inverse = 1.0 - s;
+        return 3.0 * inverse * inverse * p1 + 6.0 * inverse * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2);
+    };
+
+    // NOTE: Newton iterations converge quickly on most curves, bisection handles flat slopes
+    let mut s = x;
+    for _ in 0..8 {
+        let error = curve(s, x1, x2) - x;
+        if error.abs() < 1e-6 {
+            return curve(s, y1, y2);
+        }
+        let derivative = slope(s, x1, x2);
+        if derivative.abs() < 1e-6 {
+            break;
+        }
+        s = (s - error / derivative).clamp(0.0, 1.0);
+    }
+    let (mut low, mut high) = (0.0, 1.0);
+    s = x;
+    for _ in 0..32 {
+        let value = curve(s, x1, x2);
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+        if value < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    return curve(s, y1, y2);
+}
+
+/// Values that can be interpolated by tweens
+pub trait Lerp: Clone {
+    /// Value between `self` (0.0) and `other` (1.0) at `amount`
+    fn lerp(&self, other: &Self, amount: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, amount: f32) -> Self {
+        return self + (other - self) * amount;
+    }
+}
+
+impl Lerp for Vector2 {
+    fn lerp(&self, other: &Self, amount: f32) -> Self {
+        return Vector2::lerp(self, *other, amount);
+    }
+}
+
+impl Lerp for Vector3 {
+    fn lerp(&self, other: &Self, amount: f32) -> Self {
+        return Vector3::lerp(self, *other, amount);
+    }
+}
+
+impl Lerp for Color {
+    /// NOTE: Overshooting easings are clamped by `color_lerp()`
+    fn lerp(&self, other: &Self, amount: f32) -> Self {
+        return color_lerp(*self, *other, amount);
+    }
+}
+
+impl Lerp for Rectangle {
+    fn lerp(&self, other: &Self, amount: f32) -> Self {
+        return Rectangle::new(
+            self.x.lerp(&other.x, amount),
+            self.y.lerp(&other.y, amount),
+            self.width.lerp(&other.width, amount),
+            self.height.lerp(&other.height, amount),
+        );
+    }
+}
+
+/// Animation advanced by a `Tweener`, a `TweenSequence` or a `TweenGroup`
+pub trait Tweenable {
+    /// Advance by `delta_time` seconds, returns the time left over once finished
+    fn advance(&mut self, delta_time: f32) -> f32;
+
+    fn is_finished(&self) -> bool;
+
+    /// Restart from the beginning
+    fn reset(&mut self);
+}
+
+/// Value shared with the tweens bound to it, readable while a `Tweener`, a `TweenSequence` or a `TweenGroup` owns them.
+/// Clones refer to the same value.
+#[derive(Debug, Clone, Default)]
+pub struct TweenValue<T>(Rc<RefCell<T>>);
+
+impl<T: Clone> TweenValue<T> {
+    pub fn new(value: T) -> Self {
+        return Self(Rc::new(RefCell::new(value)));
+    }
+
+    /// Value written by the last bound tween that advanced
+    pub fn get(&self) -> T {
+        return self.0.borrow().clone();
+    }
+
+    pub fn set(&self, value: T) {
+        *self.0.borrow_mut() = value;
+    }
+}
+
+type TweenUpdateCallback<T> = Box<dyn FnMut(&T)>;
+type TweenCompleteCallback = Box<dyn FnMut()>;
+
+/// Interpolation between two values over time
+pub struct Tween<T: Lerp> {
+    from: T,
+    to: T,
+    duration: f32,
+    easing: Easing,
+    delay: f32,
+    /// Extra plays after the first one, `None` to repeat forever
+    repeat: Option<u32>,
+    yoyo: bool,
+    elapsed: f32,
+    finished: bool,
+    target: Option<TweenValue<T>>,
+    on_update: Option<TweenUpdateCallback<T>>,
+    on_complete: Option<TweenCompleteCallback>,
+}
+
+impl<T: Lerp + std::fmt::Debug> std::fmt::Debug for Tween<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f
+            .debug_struct("Tween")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("duration", &self.duration)
+            .field("easing", &self.easing)
+            .field("delay", &self.delay)
+            .field("repeat", &self.repeat)
+            .field("yoyo", &self.yoyo)
+            .field("elapsed", &self.elapsed)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive();
+    }
+}
+
+impl<T: Lerp> Tween<T> {
+    /// Linear tween from `from` to `to` over `duration` seconds, played once
+    pub fn new(from: T, to: T, duration: f32) -> Self {
+        return Self {
+            from,
+            to,
+            duration: duration.max(0.0),
+            easing: Easing::Linear,
+            delay: 0.0,
+            repeat: Some(0),
+            yoyo: false,
+            elapsed: 0.0,
+            finished: false,
+            target: None,
+            on_update: None,
+            on_complete: None,
+        };
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        return self;
+    }
+
+    /// Wait `delay` seconds before starting, only once when repeating
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay.max(0.0);
+        return self;
+    }
+
+    /// Play `count` more times after the first play
+    pub fn repeat(mut self, count: u32) -> Self {
+        self.repeat = Some(count);
+        return self;
+    }
+
+    pub fn repeat_forever(mut self) -> Self {
+        self.repeat = None;
+        return self;
+    }
+
+    /// Play every other repetition backwards
+    pub fn yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        return self;
+    }
+
+    /// Write the current value to `target` every time the tween advances
+    pub fn bind(mut self, target: &TweenValue<T>) -> Self {
+        self.target = Some(target.clone());
+        return self;
+    }
+
+    /// Call `callback` with the current value every time the tween advances
+    pub fn on_update(mut self, callback: impl FnMut(&T) + 'static) -> Self {
+        self.on_update = Some(Box::new(callback));
+        return self;
+    }
+
+    /// Call `callback` once the last repetition finishes
+    pub fn on_complete(mut self, callback: impl FnMut() + 'static) -> Self {
+        self.on_complete = Some(Box::new(callback));
+        return self;
+    }
+
+    /// Total length in seconds including the delay, `None` when repeating forever
+    pub fn total_duration(&self) -> Option<f32> {
+        return self
+            .repeat
+            .map(|repeat| self.delay + self.duration * (repeat + 1) as f32);
+    }
+
+    /// Value at the current time
+    pub fn value(&self) -> T {
+        let time = self.elapsed - self.delay;
+        if time <= 0.0 {
+            return self.from.clone();
+        }
+
+        let (cycle, progress) = match self.total_duration() {
+            Some(total) if self.elapsed >= total => {
+                // NOTE: Finished tweens rest at the end of their last cycle
+                (self.repeat.unwrap_or(0), 1.0)
+            }
+            _ if self.duration <= 0.0 => (0, 1.0),
+            _ => {
+                let cycle = (time / self.duration).floor();
+                (cycle as u32, (time - cycle * self.duration) / self.duration)
+            }
+        };
+        let progress = if self.yoyo && cycle % 2 == 1 {
+            1.0 - progress
+        } else {
+            progress
+        };
+        return self.from.lerp(&self.to, self.easing.apply(progress));
+    }
+
+    /// Advance by the frame time
+    pub fn update(&mut self) {
+        self.advance(get_frame_time());
+    }
+}
+
+impl<T: Lerp> Tweenable for Tween<T> {
+    fn advance(&mut self, delta_time: f32) -> f32 {
+        if self.finished {
+            return delta_time;
+        }
+        self.elapsed += delta_time;
+        let leftover = match self.total_duration() {
+            Some(total) if self.elapsed >= total => {
+                self.finished = true;
+                self.elapsed - total
+            }
+            _ => 0.0,
+        };
+
+        if self.on_update.is_some() || self.target.is_some() {
+            let value = self.value();
+            if let Some(callback) = &mut self.on_update {
+                callback(&value);
+            }
+            if let Some(target) = &self.target {
+                target.set(value);
+            }
+        }
+        if self.finished
+            && let Some(callback) = &mut self.on_complete
+        {
+            callback();
+        }
+        return leftover;
+    }
+
+    fn is_finished(&self) -> bool {
+        return self.finished;
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.finished = false;
+    }
+}
+
+/// Pause in a sequence
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TweenDelay {
+    duration: f32,
+    elapsed: f32,
+}
+
+impl TweenDelay {
+    pub fn new(duration: f32) -> Self {
+        return Self { duration, elapsed: 0.0 };
+    }
+}
+
+impl Tweenable for TweenDelay {
+    fn advance(&mut self, delta_time: f32) -> f32 {
+        self.elapsed += delta_time;
+        return (self.elapsed - self.duration).max(0.0);
+    }
+
+    fn is_finished(&self) -> bool {
+        return self.elapsed >= self.duration;
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+/// Animations played one after the other
+#[derive(Default)]
+pub struct TweenSequence {
+    steps: Vec<Box<dyn Tweenable>>,
+    current: usize,
+}
+
+impl std::fmt::Debug for TweenSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f
+            .debug_struct("TweenSequence")
+            .field("steps", &self.steps.len())
+            .field("current", &self.current)
+            .finish();
+    }
+}
+
+impl TweenSequence {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn then(mut self, step: impl Tweenable + 'static) -> Self {
+        self.steps.push(Box::new(step));
+        return self;
+    }
+
+    /// Wait `duration` seconds before the next step
+    pub fn wait(self, duration: f32) -> Self {
+        return self.then(TweenDelay::new(duration));
+    }
+}
+
+impl Tweenable for TweenSequence {
+    fn advance(&mut self, delta_time: f32) -> f32 {
+        let mut remaining = delta_time;
+        while let Some(step) = self.steps.get_mut(self.current) {
+            remaining = step.advance(remaining);
+            if !step.is_finished() {
+                return 0.0;
+            }
+            // NOTE: Time left over by a finished step carries into the next one
+            self.current += 1;
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+        return if self.is_finished() { remaining } else { 0.0 };
+    }
+
+    fn is_finished(&self) -> bool {
+        return self.current >= self.steps.len();
+    }
+
+    fn reset(&mut self) {
+        self.current = 0;
+        for step in &mut self.steps {
+            step.reset();
+        }
+    }
+}
+
+/// Animations played at the same time, finished when all of them are
+#[derive(Default)]
+pub struct TweenGroup {
+    members: Vec<Box<dyn Tweenable>>,
+}
+
+impl std::fmt::Debug for TweenGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f
+            .debug_struct("TweenGroup")
+            .field("members", &self.members.len())
+            .finish();
+    }
+}
+
+impl TweenGroup {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn with(mut self, member: impl Tweenable + 'static) -> Self {
+        self.members.push(Box::new(member));
+        return self;
+    }
+}
+
+impl Tweenable for TweenGroup {
+    fn advance(&mut self, delta_time: f32) -> f32 {
+        let mut leftover = delta_time;
+        for member in &mut self.members {
+            if !member.is_finished() {
+                leftover = leftover.min(member.advance(delta_time));
+            }
+        }
+        return if self.is_finished() { leftover } else { 0.0 };
+    }
+
+    fn is_finished(&self) -> bool {
+        return self.members.iter().all(|member| member.is_finished());
+    }
+
+    fn reset(&mut self) {
+        for member in &mut self.members {
+            member.reset();
+        }
+    }
+}
+
+/// Handle of an animation played by a `Tweener`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TweenId(u64);
+
+/// Plays animations until they finish
+pub struct Tweener {
+    animations: Vec<(TweenId, Box<dyn Tweenable>)>,
+    next_id: u64,
+    pub paused: bool,
+    /// Multiplier applied to the frame time
+    pub speed: f32,
+}
+
+impl std::fmt::Debug for Tweener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f
+            .debug_struct("Tweener")
+            .field("animations", &self.animations.len())
+            .field("paused", &self.paused)
+            .field("speed", &self.speed)
+            .finish_non_exhaustive();
+    }
+}
+
+impl Default for Tweener {
+    fn default() -> Self {
+        return Self {
+            animations: Vec::new(),
+            next_id: 0,
+            paused: false,
+            speed: 1.0,
+        };
+    }
+}
+
+impl Tweener {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Start playing an animation
+    pub fn add(&mut self, animation: impl Tweenable + 'static) -> TweenId {
+        let id = TweenId(self.next_id);
+        self.next_id += 1;
+        self.animations.push((id, Box::new(animation)));
+        return id;
+    }
+
+    /// Stop an animation, returns false if it already finished
+    pub fn remove(&mut self, id: TweenId) -> bool {
+        let count = self.animations.len();
+        self.animations.retain(|(animation, _)| *animation != id);
+        return self.animations.len() != count;
+    }
+
+    pub fn is_playing(&self, id: TweenId) -> bool {
+        return self.animations.iter().any(|(animation, _)| *animation == id);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.animations.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.animations.is_empty();
+    }
+
+    pub fn clear(&mut self) {
+        self.animations.clear();
+    }
+
+    /// Advance by the frame time
+    pub fn update(&mut self) {
+        self.advance(get_frame_time());
+    }
+
+    /// Advance by `delta_time` seconds and drop the finished animations
+    pub fn advance(&mut self, delta_time: f32) {
+        if self.paused {
+            return;
+        }
+        for (_, animation) in &mut self.animations {
+            animation.advance(delta_time * self.speed);
+        }
+        self.animations.retain(|(_, animation)| !animation.is_finished());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const EASINGS: [Easing; 33] = [
+        Easing::Linear,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::QuartIn,
+        Easing::QuartOut,
+        Easing::QuartInOut,
+        Easing::QuintIn,
+        Easing::QuintOut,
+        Easing::QuintInOut,
+        Easing::ExpoIn,
+        Easing::ExpoOut,
+        Easing::ExpoInOut,
+        Easing::CircIn,
+        Easing::CircOut,
+        Easing::CircInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+        Easing::CubicBezier(0.25, 0.1, 0.25, 1.0),
+        Easing::CubicBezier(0.68, -0.6, 0.32, 1.6),
+    ];
+
+    fn assert_near(value: f32, expected: f32) {
+        assert!((value - expected).abs() < 1e-4, "{} is not {}", value, expected);
+    }
+
+    #[test]
+    fn easing_endpoints() {
+        for easing in EASINGS {
+            assert_near(easing.apply(0.0), 0.0);
+            assert_near(easing.apply(1.0), 1.0);
+            // Progress outside 0.0..=1.0 is clamped
+            assert_near(easing.apply(-1.0), 0.0);
+            assert_near(easing.apply(2.0), 1.0);
+        }
+        for easing in [
+            Easing::SineInOut,
+            Easing::QuadInOut,
+            Easing::CubicInOut,
+            Easing::CircInOut,
+        ] {
+            assert_near(easing.apply(0.5), 0.5);
+        }
+        assert_near(Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.3), 0.3);
+    }
+
+    #[test]
+    fn easing_monotonic() {
+        let overshooting = |easing: &Easing| {
+            return matches!(
+                easing,
+                Easing::BackIn
+                    | Easing::BackOut
+                    | Easing::BackInOut
+                    | Easing::ElasticIn
+                    | Easing::ElasticOut
+                    | Easing::ElasticInOut
+                    | Easing::BounceIn
+                    | Easing::BounceOut
+                    | Easing::BounceInOut
+            ) || matches!(easing, Easing::CubicBezier(_, y1, _, y2) if *y1 < 0.0 || *y2 > 1.0);
+        };
+        for easing in EASINGS.iter().filter(|easing| !overshooting(easing)) {
+            let mut previous = easing.apply(0.0);
+            for step in 1..=1000 {
+                let value = easing.apply(step as f32 / 1000.0);
+                assert!(value >= previous - 1e-5, "{:?} decreases at step {}", easing, step);
+                assert!((-1e-5..=1.0 + 1e-5).contains(&value), "{:?} leaves 0.0..=1.0", easing);
+                previous = value;
+            }
+        }
+
+        // Overshooting curves leave the range on the expected side
+        assert!(Easing::BackIn.apply(0.2) < 0.0);
+        assert!(Easing::BackOut.apply(0.8) > 1.0);
+        assert!(Easing::ElasticOut.apply(0.1) > 1.0);
+        assert!(Easing::CubicBezier(0.68, -0.6, 0.32, 1.6).apply(0.1) < 0.0);
+    }
+
+    #[test]
+    fn tween_timing() {
+        let mut tween = Tween::new(0.0, 10.0, 1.0).delay(0.5).repeat(1).yoyo(true);
+        assert_eq!(tween.total_duration(), Some(2.5));
+        assert_eq!(tween.advance(0.25), 0.0);
+        assert_eq!(tween.value(), 0.0);
+        tween.advance(0.5);
+        assert_near(tween.value(), 2.5);
+        tween.advance(1.0);
+        assert_near(tween.value(), 7.5);
+        assert!(!tween.is_finished());
+        assert_near(tween.advance(1.0), 0.25);
+        assert!(tween.is_finished());
+        assert_eq!(tween.value(), 0.0);
+        assert_eq!(tween.advance(0.5), 0.5);
+
+        tween.reset();
+        assert!(!tween.is_finished());
+        assert_eq!(tween.value(), 0.0);
+
+        let forever = Tween::new(0.0, 1.0, 1.0).repeat_forever();
+        assert_eq!(forever.total_duration(), None);
+        let mut instant = Tween::new(0.0, 1.0, 0.0);
+        assert_eq!(instant.advance(0.25), 0.25);
+        assert_eq!(instant.value(), 1.0);
+    }
+
+    #[test]
+    fn callbacks() {
+        let updates = Rc::new(Cell::new(0));
+        let completions = Rc::new(Cell::new(0));
+        let (update_count, complete_count) = (updates.clone(), completions.clone());
+        let mut tween = Tween::new(0.0, 1.0, 1.0)
+            .on_update(move |_| update_count.set(update_count.get() + 1))
+            .on_complete(move || complete_count.set(complete_count.get() + 1));
+        for _ in 0..6 {
+            tween.advance(0.25);
+        }
+        assert_eq!(updates.get(), 4);
+        assert_eq!(completions.get(), 1);
+    }
+
+    #[test]
+    fn sequence_timing() {
+        let value = TweenValue::new(-1.0);
+        let mut sequence = TweenSequence::new()
+            .then(Tween::new(0.0, 10.0, 1.0).bind(&value))
+            .wait(0.5)
+            .then(Tween::new(10.0, 20.0, 1.0).bind(&value));
+
+        assert_eq!(value.get(), -1.0);
+        assert_eq!(sequence.advance(0.75), 0.0);
+        assert_near(value.get(), 7.5);
+        // The first tween finishes and the delay takes the time left over
+        sequence.advance(0.5);
+        assert_eq!(value.get(), 10.0);
+        sequence.advance(0.5);
+        assert_near(value.get(), 12.5);
+        assert!(!sequence.is_finished());
+        assert_near(sequence.advance(1.0), 0.25);
+        assert!(sequence.is_finished());
+        assert_eq!(value.get(), 20.0);
+
+        sequence.reset();
+        assert!(!sequence.is_finished());
+        sequence.advance(0.5);
+        assert_near(value.get(), 5.0);
+
+        // A single step longer than several steps runs through all of them
+        let mut sequence = TweenSequence::new()
+            .then(Tween::new(0.0, 1.0, 0.5))
+            .wait(0.25)
+            .then(Tween::new(0.0, 1.0, 0.5).bind(&value));
+        assert_near(sequence.advance(2.0), 0.75);
+        assert_eq!(value.get(), 1.0);
+        assert!(TweenSequence::new().is_finished());
+    }
+
+    #[test]
+    fn group_timing() {
+        let (short, long) = (TweenValue::new(0.0), TweenValue::new(0.0));
+        let mut group = TweenGroup::new()
+            .with(Tween::new(0.0, 1.0, 1.0).bind(&short))
+            .with(Tween::new(0.0, 1.0, 2.0).bind(&long));
+
+        assert_eq!(group.advance(1.5), 0.0);
+        assert_eq!(short.get(), 1.0);
+        assert_near(long.get(), 0.75);
+        assert!(!group.is_finished());
+        // Left over time is measured from the end of the longest member
+        assert_near(group.advance(1.0), 0.5);
+        assert!(group.is_finished());
+        assert_eq!(long.get(), 1.0);
+
+        group.reset();
+        assert!(!group.is_finished());
+    }
+
+    #[test]
+    fn tweener_values() {
+        let mut tweener = Tweener::new();
+        let position = TweenValue::new(Vector2::zero());
+        let alpha = TweenValue::default();
+        let id = tweener.add(Tween::new(Vector2::zero(), Vector2::new(8.0, 4.0), 1.0).bind(&position));
+        tweener.add(
+            TweenSequence::new()
+                .wait(0.5)
+                .then(Tween::new(1.0, 0.0, 1.0).bind(&alpha)),
+        );
+        assert_eq!(tweener.len(), 2);
+
+        tweener.advance(0.5);
+        assert_eq!(position.get(), Vector2::new(4.0, 2.0));
+        assert_eq!(alpha.get(), 0.0);
+
+        tweener.paused = true;
+        tweener.advance(1.0);
+        assert_eq!(position.get(), Vector2::new(4.0, 2.0));
+
+        tweener.paused = false;
+        tweener.speed = 0.5;
+        tweener.advance(0.5);
+        assert_eq!(position.get(), Vector2::new(6.0, 3.0));
+        assert_near(alpha.get(), 0.75);
+
+        tweener.speed = 1.0;
+        tweener.advance(0.5);
+        // Values stay readable once the finished animations are dropped
+        assert!(!tweener.is_playing(id));
+        assert_eq!(tweener.len(), 1);
+        assert_eq!(position.get(), Vector2::new(8.0, 4.0));
+        assert_near(alpha.get(), 0.25);
+
+        assert!(!tweener.remove(id));
+        tweener.clear();
+        assert!(tweener.is_empty());
+    }
+}
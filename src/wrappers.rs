@@ -1,8 +1,8 @@
 use crate::bindings::{AudioCallback, GetFPS, GetFrameTime, GetTime, LoadFileDataCallback, LoadFileTextCallback, SaveFileDataCallback, SaveFileTextCallback, SetTargetFPS, TraceLogCallback};
-use crate::{bindings, AudioStream, AutomationEvent, AutomationEventList, BlendMode, BoundingBox, Camera, Camera2D, Camera3D, CameraMode, Color, ConfigFlag, CubemapLayout, FilePathList, Font, FontType, Gamepad, GamepadAxis, GamepadButton, GestureFlag, GlyphInfo, Image, KeyboardKey, Material, MaterialMapIndex, Matrix, Mesh, Model, ModelAnimation, Monitor, MouseButton, MouseCursor, Music, NPatchInfo, PixelFormat, Ray, RayCollision, Rectangle, RenderTexture2D, Shader, ShaderLocation, ShaderUniformDataType, Sound, Texture2D, TextureCubemap, TextureFilter, TextureWrap, TraceLogLevel, Vector2, Vector3, Vector4, VrDeviceInfo, VrStereoConfig, Wave};
+use crate::{bindings, detach_borrowed_textures, forget_borrowed_textures, mark_texture_borrowed, AudioStream, AutomationEvent, AutomationEventList, BlendMode, BoundingBox, Camera, Camera2D, Camera3D, CameraMode, Color, ConfigFlag, CubemapLayout, FilePathList, Font, FontType, Gamepad, GamepadAxis, GamepadButton, GestureFlag, GlyphInfo, Image, KeyboardKey, Material, MaterialMapIndex, Matrix, Mesh, Model, ModelAnimation, Monitor, MouseButton, MouseCursor, Music, NPatchInfo, PixelFormat, Ray, RayCollision, Rectangle, RenderTexture2D, Shader, ShaderLocation, ShaderUniformDataType, Sound, Texture2D, TextureCubemap, TextureFilter, TextureWrap, TraceLogLevel, Vector2, Vector3, Vector4, VrDeviceInfo, VrStereoConfig, Wave};
 use std::ffi::{c_char, c_double, c_uchar, c_uint, CStr, CString};
 use std::os::raw::{c_float, c_int, c_void};
-use std::slice;
+use std::{ptr, slice};
 
 // ---------------------------------------------------------------------------------
 // Window related wrappers
@@ -559,6 +559,30 @@ pub fn get_fps() -> i32 {
     return unsafe { GetFPS() };
 }
 
+/// Call `frame` once per frame until the window should close.
+/// NOTE: `frame` is `'static` like on wasm32, where this function never returns
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_main_loop<F: FnMut() + 'static>(mut frame: F) {
+    while !window_should_close() {
+        frame();
+    }
+}
+
+/// Call `frame` once per frame until the window should close.
+/// NOTE: On wasm32 the browser drives the loop through `emscripten_set_main_loop_arg()` and this function never
+/// returns, values owned by the caller's stack are not dropped
+#[cfg(target_arch = "wasm32")]
+pub fn run_main_loop<F: FnMut() + 'static>(frame: F) {
+    unsafe extern "C" fn trampoline<F: FnMut()>(arg: *mut c_void) {
+        let frame = unsafe { &mut *(arg as *mut F) };
+        frame();
+    }
+
+    // NOTE: The closure is leaked, the main loop keeps running until the page is closed
+    let arg = Box::into_raw(Box::new(frame)) as *mut c_void;
+    unsafe { bindings::emscripten_set_main_loop_arg(Some(trampoline::<F>), arg, 0, 1) };
+}
+
 // ---------------------------------------------------------------------------------
 // Custom frame control wrappers
 // ---------------------------------------------------------------------------------
@@ -1014,7 +1038,8 @@ pub fn decompress_data(comp_data: &[u8]) -> Vec<u8> {
             comp_data.len() as c_int,
             &mut data_size as *mut c_int,
         );
-        if ptr.is_null() {
+        // NOTE: When nothing was decompressed the buffer was already released by realloc(ptr, 0)
+        if ptr.is_null() || data_size <= 0 {
             return Vec::new();
         }
         let slice = slice::from_raw_parts(ptr, data_size as usize);
@@ -1036,9 +1061,10 @@ pub fn encode_data_base64(data: &[u8]) -> String {
         if c_str_ptr.is_null() {
             return String::new();
         }
-        // outputSize includes the null terminator, so slice length is output_size - 1
-        let slice = slice::from_raw_parts(c_str_ptr as *const u8, (output_size - 1) as usize);
-        let s = String::from_utf8_lossy(slice).into_owned();
+        // NOTE: raylib 5.5 does not count (nor always write) a null terminator in outputSize
+        let slice = slice::from_raw_parts(c_str_ptr as *const u8, output_size as usize);
+        let length = slice.iter().position(|&byte| byte == 0).unwrap_or(slice.len());
+        let s = String::from_utf8_lossy(&slice[..length]).into_owned();
         bindings::MemFree(c_str_ptr as *mut c_void);
         s
     }
@@ -1046,7 +1072,13 @@ pub fn encode_data_base64(data: &[u8]) -> String {
 
 /// Decode Base64 string
 pub fn decode_data_base64(text: &str) -> Vec<u8> {
-    let c_text = CString::new(text).unwrap();
+    // NOTE: DecodeDataBase64 reads whole 4 character groups, shorter input would be read past its end
+    if !text.len().is_multiple_of(4) {
+        return Vec::new();
+    }
+    let Ok(c_text) = CString::new(text) else {
+        return Vec::new();
+    };
     let mut output_size: c_int = 0;
     unsafe {
         let ptr = bindings::DecodeDataBase64(c_text.as_ptr(), &mut output_size as *mut c_int);
@@ -3662,12 +3694,16 @@ pub fn draw_grid(slices: i32, spacing: f32) {
 /// Load model from files (meshes and materials)
 pub fn load_model(filename: &str) -> Model {
     let c_filename = CString::new(filename).unwrap();
-    unsafe { bindings::LoadModel(c_filename.as_ptr()) }
+    let model = unsafe { bindings::LoadModel(c_filename.as_ptr()) };
+    forget_borrowed_textures(model.materials());
+    return model;
 }
 
 /// Load model from generated mesh (default material)
 pub fn load_model_from_mesh(mesh: Mesh) -> Model {
-    unsafe { bindings::LoadModelFromMesh(mesh) }
+    let model = unsafe { bindings::LoadModelFromMesh(mesh) };
+    forget_borrowed_textures(model.materials());
+    return model;
 }
 
 /// Check if a model is valid (loaded in GPU, VAO/VBOs)
@@ -3676,7 +3712,12 @@ pub fn is_model_valid(model: Model) -> bool {
 }
 
 /// Unload model (including meshes) from memory (RAM and/or VRAM)
+/// NOTE: Textures attached to the model materials with `set_material_texture` are not unloaded.
 pub fn unload_model(model: Model) {
+    if !model.materials.is_null() {
+        let materials = unsafe { slice::from_raw_parts(model.materials, model.material_count as usize) };
+        materials.iter().for_each(detach_borrowed_textures);
+    }
     unsafe { bindings::UnloadModel(model) }
 }
 
@@ -3981,7 +4022,9 @@ pub fn load_materials(filename: &str) -> Vec<Material> {
             return Vec::new();
         }
         let slice = slice::from_raw_parts(materials_ptr, count as usize);
-        let vec = slice.to_vec();
+        forget_borrowed_textures(slice);
+        // NOTE: The materials are moved out of the array, their shaders must not be dropped twice
+        let vec = slice.iter().map(|material| ptr::read(material)).collect();
         bindings::MemFree(materials_ptr as *mut c_void); // Free the *array*, not the materials
         return vec;
     }
@@ -3989,7 +4032,9 @@ pub fn load_materials(filename: &str) -> Vec<Material> {
 
 /// Load default material (Supports: DIFFUSE, SPECULAR, NORMAL maps)
 pub fn load_material_default() -> Material {
-    unsafe { bindings::LoadMaterialDefault() }
+    let material = unsafe { bindings::LoadMaterialDefault() };
+    forget_borrowed_textures(slice::from_ref(&material));
+    return material;
 }
 
 /// Check if a material is valid (shader assigned, map textures loaded in GPU)
@@ -3998,17 +4043,21 @@ pub fn is_material_valid(material: Material) -> bool {
 }
 
 /// Unload material from GPU memory (VRAM)
+/// NOTE: Textures attached with `set_material_texture` are owned by the caller and are not unloaded.
 pub fn unload_material(material: Material) {
+    detach_borrowed_textures(&material);
     unsafe { bindings::UnloadMaterial(material) }
 }
 
 /// Set texture for a material map type
+/// NOTE: The material does not take ownership of the texture.
 pub fn set_material_texture(
     material: &mut Material,
     map_type: MaterialMapIndex,
     texture: Texture2D,
 ) {
     unsafe { bindings::SetMaterialTexture(material as *mut Material, map_type as c_int, texture) }
+    mark_texture_borrowed(material, map_type);
 }
 
 /// Set material for a mesh
@@ -4025,7 +4074,8 @@ pub fn set_model_mesh_material(model: &mut Model, mesh_id: i32, material_id: i32
 // Model animations loading/unloading wrappers
 
 /// Load model animations from file
-/// NOTE: This wrapper allocates a `Vec<ModelAnimation>` and frees the C-pointer.
+/// NOTE: This wrapper allocates a `Vec<ModelAnimation>` and frees the C array only,
+/// every animation keeps its data and must be unloaded with `unload_model_animation`.
 pub fn load_model_animations(filename: &str) -> Vec<ModelAnimation> {
     let c_filename = CString::new(filename).unwrap();
     let mut count: c_int = 0;
@@ -4037,7 +4087,7 @@ pub fn load_model_animations(filename: &str) -> Vec<ModelAnimation> {
         }
         let slice = slice::from_raw_parts_mut(animations_ptr, count as usize);
         let vec = slice.to_vec();
-        bindings::UnloadModelAnimations(animations_ptr, count);
+        bindings::MemFree(animations_ptr as *mut c_void);
         vec
     }
 }
@@ -4057,6 +4107,13 @@ pub fn unload_model_animation(anim: ModelAnimation) {
     unsafe { bindings::UnloadModelAnimation(anim) }
 }
 
+/// Unload animation array data
+pub fn unload_model_animations(animations: Vec<ModelAnimation>) {
+    for anim in animations {
+        unload_model_animation(anim);
+    }
+}
+
 /// Check model animation skeleton match
 pub fn is_model_animation_valid(model: Model, anim: ModelAnimation) -> bool {
     unsafe { bindings::IsModelAnimationValid(model, anim) }
@@ -4522,4 +4579,4 @@ pub fn attach_audio_mixed_processor(processor: AudioCallback) {
 /// Detach audio stream processor from the entire audio pipeline
 pub fn detach_audio_mixed_processor(processor: AudioCallback) {
     unsafe { bindings::DetachAudioMixedProcessor(processor) }
-}
\ No newline at end of file
+}
@@ -0,0 +1,292 @@
+//! Minimal XML reader used by the asset loaders, without namespaces or DTD support
+
+/// XML element with its attributes, child elements and concatenated text content
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct XmlElement {
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<XmlElement>,
+    pub(crate) text: String,
+}
+
+impl XmlElement {
+    /// Parse a document and return its root element
+    pub(crate) fn parse(text: &str) -> Result<XmlElement, String> {
+        let mut parser = Parser { text, position: 0 };
+        parser.skip_misc()?;
+        let root = parser.element()?;
+        parser.skip_misc()?;
+        if parser.position < text.len() {
+            return Err(parser.error("trailing characters"));
+        }
+        return Ok(root);
+    }
+
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        return self
+            .attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str());
+    }
+
+    /// Attribute parsed as a number, `None` if missing or invalid
+    pub(crate) fn number(&self, name: &str) -> Option<f64> {
+        return self.attribute(name)?.trim().parse().ok();
+    }
+
+    /// First child element named `name`
+    pub(crate) fn child(&self, name: &str) -> Option<&XmlElement> {
+        return self.children.iter().find(|child| child.name == name);
+    }
+
+    /// Child elements named `name`
+    pub(crate) fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        return self.children.iter().filter(move |child| child.name == name);
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> String {
+        return format!("xml: {} at offset {}", message, self.position);
+    }
+
+    fn rest(&self) -> &str {
+        return &self.text[self.position..];
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skip up to and including `end`
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        match self.rest().find(end) {
+            Some(offset) => {
+                self.position += offset + end.len();
+                return Ok(());
+            }
+            None => return Err(self.error(&format!("missing '{}'", end))),
+        }
+    }
+
+    /// Skip whitespace, comments, processing instructions and doctype declarations
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '=' | '<'))
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("expected a name"));
+        }
+        let name = rest[..length].to_string();
+        self.position += length;
+        return Ok(name);
+    }
+
+    fn element(&mut self) -> Result<XmlElement, String> {
+        if !self.rest().starts_with('<') {
+            return Err(self.error("expected '<'"));
+        }
+        self.position += 1;
+        let mut element = XmlElement {
+            name: self.name()?,
+            ..Default::default()
+        };
+
+        // Attributes
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let key = self.name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.error("expected '='"));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+            let Some(quote) = self.rest().chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+                return Err(self.error("expected a quoted value"));
+            };
+            self.position += 1;
+            let Some(length) = self.rest().find(quote) else {
+                return Err(self.error("unterminated attribute value"));
+            };
+            let value = unescape(&self.rest()[..length]);
+            self.position += length + 1;
+            element.attributes.push((key, value));
+        }
+
+        // Content
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(self.error(&format!("unterminated element '{}'", element.name)));
+            }
+            if rest.starts_with("</") {
+                self.position += 2;
+                let name = self.name()?;
+                if name != element.name {
+                    return Err(self.error(&format!("mismatched closing tag '{}'", name)));
+                }
+                self.skip_past(">")?;
+                return Ok(element);
+            }
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += 9;
+                let start = self.position;
+                self.skip_past("]]>")?;
+                element.text.push_str(&self.text[start..self.position - 3]);
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                element.children.push(self.element()?);
+            } else {
+                let length = rest.find('<').unwrap_or(rest.len());
+                element.text.push_str(&unescape(&rest[..length]));
+                self.position += length;
+            }
+        }
+    }
+}
+
+/// Replace the predefined entities and character references
+fn unescape(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|decimal| decimal.parse().ok()),
+                };
+                code.and_then(char::from_u32)
+            }
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    return out;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_document() {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE map>
+<!-- comment -->
+<map version='1.10' width=" 4 " name="a &amp; b">
+  <tileset firstgid="1"/>
+  <layer id="2"><data encoding="csv">1,2,<!-- skipped -->3</data></layer>
+  <text><![CDATA[<raw> & text]]></text>
+  <tileset firstgid="5"></tileset>
+</map>
+<!-- trailing comment -->
+"#;
+        let root = XmlElement::parse(text).unwrap();
+        assert_eq!(root.name, "map");
+        assert_eq!(root.attribute("version"), Some("1.10"));
+        assert_eq!(root.attribute("name"), Some("a & b"));
+        assert_eq!(root.attribute("missing"), None);
+        assert_eq!(root.number("width"), Some(4.0));
+        assert_eq!(root.number("name"), None);
+        assert_eq!(root.children.len(), 4);
+
+        let first_gids: Vec<f64> = root
+            .children_named("tileset")
+            .filter_map(|tileset| tileset.number("firstgid"))
+            .collect();
+        assert_eq!(first_gids, [1.0, 5.0]);
+
+        let data = root.child("layer").and_then(|layer| layer.child("data")).unwrap();
+        assert_eq!(data.attribute("encoding"), Some("csv"));
+        assert_eq!(data.text, "1,2,3");
+        assert_eq!(root.child("text").unwrap().text, "<raw> & text");
+        assert_eq!(root.child("missing"), None);
+    }
+
+    #[test]
+    fn entities() {
+        assert_eq!(unescape("plain"), "plain");
+        assert_eq!(unescape("&lt;&gt;&amp;&quot;&apos;"), "<>&\"'");
+        assert_eq!(unescape("&#65;&#x42;&#X43;"), "ABC");
+        assert_eq!(unescape("&unknown; & &#xzz;"), "&unknown; & &#xzz;");
+        assert_eq!(unescape("end &amp"), "end &amp");
+
+        let root = XmlElement::parse("<a title=\"&#x3c;b&#62;\">x &lt; y</a>").unwrap();
+        assert_eq!(root.attribute("title"), Some("<b>"));
+        assert_eq!(root.text, "x < y");
+    }
+
+    #[test]
+    fn errors() {
+        let error = |text: &str| return XmlElement::parse(text).unwrap_err();
+        assert_eq!(error("<a></b>"), "xml: mismatched closing tag 'b' at offset 6");
+        assert_eq!(error("<a>"), "xml: unterminated element 'a' at offset 3");
+        assert_eq!(error("<a x=1/>"), "xml: expected a quoted value at offset 5");
+        assert_eq!(error("<a x/>"), "xml: expected '=' at offset 4");
+        assert_eq!(error("<a x='1/>"), "xml: unterminated attribute value at offset 6");
+        assert_eq!(error("<a/><b/>"), "xml: trailing characters at offset 4");
+        assert_eq!(error("text"), "xml: expected '<' at offset 0");
+        assert_eq!(error("<>"), "xml: expected a name at offset 1");
+        assert_eq!(error("<a><!-- open</a>"), "xml: missing '-->' at offset 3");
+        assert_eq!(error(""), "xml: expected '<' at offset 0");
+    }
+}